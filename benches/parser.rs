@@ -0,0 +1,50 @@
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+const KITCHEN_SINK_QUERY: &str = include_str!("../parser/tests/executables/kitchen-sink.graphql");
+const KITCHEN_SINK_SCHEMA: &str = include_str!("../parser/tests/services/kitchen-sink.graphql");
+
+/// A query with many nested fields, arguments, fragments and variables.
+fn large_query() -> String {
+    let mut query = String::from("query Large($id: ID!, $first: Int = 10) {\n");
+    for i in 0..200 {
+        query.push_str(&format!(
+            "  field{i}: node(id: $id, filter: {{ name: \"item {i}\", tags: [A, B, C], score: {i}.5 }}) @include(if: true) {{\n    id\n    ... on User {{ name friends(first: $first) {{ edges {{ node {{ id ...UserFields }} }} }} }}\n  }}\n"
+        ));
+    }
+    query.push_str("}\n\nfragment UserFields on User {\n  id\n  name\n}\n");
+    query
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let large_query = large_query();
+
+    let mut group = c.benchmark_group("parse_query");
+    for (name, query) in [
+        ("kitchen-sink", KITCHEN_SINK_QUERY),
+        ("large", large_query.as_str()),
+    ] {
+        group.bench_with_input(
+            BenchmarkId::new("recursive descent", name),
+            query,
+            |b, query| b.iter(|| async_graphql_parser::parse_query(black_box(query)).unwrap()),
+        );
+        group.bench_with_input(BenchmarkId::new("pest", name), query, |b, query| {
+            b.iter(|| async_graphql_parser_pest::parse_query(black_box(query)).unwrap())
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("parse_schema");
+    group.bench_function("recursive descent", |b| {
+        b.iter(|| async_graphql_parser::parse_schema(black_box(KITCHEN_SINK_SCHEMA)).unwrap())
+    });
+    group.bench_function("pest", |b| {
+        b.iter(|| async_graphql_parser_pest::parse_schema(black_box(KITCHEN_SINK_SCHEMA)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
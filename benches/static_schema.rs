@@ -429,6 +429,22 @@ pub const Q: &str = r#"
 }
 "#;
 
+#[cfg(feature = "validation_cache")]
+#[derive(Default)]
+struct MemoryValidationCache(std::sync::Mutex<HashMap<ValidationCacheKey, ValidatedDocument>>);
+
+#[cfg(feature = "validation_cache")]
+#[async_trait::async_trait]
+impl ValidationCacheStorage for MemoryValidationCache {
+    async fn get(&self, key: &ValidationCacheKey) -> Option<ValidatedDocument> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+
+    async fn set(&self, key: ValidationCacheKey, document: ValidatedDocument) {
+        self.0.lock().unwrap().insert(key, document);
+    }
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("Static Schema", |b| {
         let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
@@ -439,6 +455,31 @@ fn criterion_benchmark(c: &mut Criterion) {
                 schema.execute(Q).await.into_result().unwrap();
             });
     });
+
+    #[cfg(feature = "validation_cache")]
+    c.bench_function("Static Schema (validation cache)", |b| {
+        let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+            .data(StarWars::new())
+            .validation_cache(MemoryValidationCache::default())
+            .finish();
+        b.to_async(tokio::runtime::Runtime::new().unwrap())
+            .iter(|| async {
+                schema.execute(Q).await.into_result().unwrap();
+            });
+    });
+
+    #[cfg(feature = "validation_cache")]
+    c.bench_function("Static Schema (compiled operations)", |b| {
+        let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+            .data(StarWars::new())
+            .validation_cache(MemoryValidationCache::default())
+            .enable_compiled_operations()
+            .finish();
+        b.to_async(tokio::runtime::Runtime::new().unwrap())
+            .iter(|| async {
+                schema.execute(Q).await.into_result().unwrap();
+            });
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
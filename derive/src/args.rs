@@ -6,7 +6,8 @@ use darling::{
     util::{Ignored, SpannedValue},
 };
 use heck::{ToLowerCamelCase, ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
-use quote::format_ident;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
 use syn::{
     Attribute, Expr, GenericParam, Generics, Ident, Lit, LitBool, LitStr, Meta, Path, Type,
     Visibility,
@@ -21,6 +22,12 @@ pub struct CacheControl {
     private: bool,
     pub no_cache: bool,
     pub max_age: usize,
+    pub s_max_age: usize,
+    pub stale_while_revalidate: usize,
+    pub stale_if_error: usize,
+    pub immutable: bool,
+    pub no_store: bool,
+    pub inherit_max_age: bool,
 }
 
 impl Default for CacheControl {
@@ -30,6 +37,12 @@ impl Default for CacheControl {
             private: false,
             no_cache: false,
             max_age: 0,
+            s_max_age: 0,
+            stale_while_revalidate: 0,
+            stale_if_error: 0,
+            immutable: false,
+            no_store: false,
+            inherit_max_age: false,
         }
     }
 }
@@ -38,6 +51,33 @@ impl CacheControl {
     pub fn is_public(&self) -> bool {
         !self.private && self.public
     }
+
+    pub fn to_token_stream(&self, crate_name: &Path) -> TokenStream {
+        let public = self.is_public();
+        let max_age = if self.no_cache {
+            -1
+        } else {
+            self.max_age as i32
+        };
+        let s_max_age = self.s_max_age as i32;
+        let stale_while_revalidate = self.stale_while_revalidate as i32;
+        let stale_if_error = self.stale_if_error as i32;
+        let immutable = self.immutable;
+        let no_store = self.no_store;
+        let inherit_max_age = self.inherit_max_age;
+        quote! {
+            #crate_name::CacheControl {
+                public: #public,
+                max_age: #max_age,
+                s_max_age: #s_max_age,
+                stale_while_revalidate: #stale_while_revalidate,
+                stale_if_error: #stale_if_error,
+                immutable: #immutable,
+                no_store: #no_store,
+                inherit_max_age: #inherit_max_age,
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -124,6 +164,59 @@ pub struct ConcreteType {
     pub bounds: GenericParamList,
 }
 
+#[derive(FromMeta)]
+pub struct ComputedProjection {
+    pub field: String,
+    pub requires: String,
+}
+
+#[derive(FromMeta, Default)]
+#[darling(default)]
+struct ObjectProjectionArgs {
+    #[darling(multiple)]
+    computed: Vec<ComputedProjection>,
+}
+
+/// `#[graphql(projection)]` or `#[graphql(projection(computed(...)))]`
+#[derive(Default)]
+pub struct ObjectProjection {
+    pub computed: Vec<ComputedProjection>,
+}
+
+impl FromMeta for ObjectProjection {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let args = ObjectProjectionArgs::from_list(items)?;
+        Ok(Self {
+            computed: args.computed,
+        })
+    }
+}
+
+/// `join` or `join = "name"`
+pub struct ProjectionJoin(pub Option<String>);
+
+impl FromMeta for ProjectionJoin {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self(None))
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(Self(Some(value.to_string())))
+    }
+}
+
+#[derive(FromMeta, Default)]
+#[darling(default)]
+pub struct FieldProjection {
+    pub column: Option<String>,
+    pub join: Option<ProjectionJoin>,
+    pub skip: bool,
+}
+
 #[derive(Debug, Clone, Default)]
 pub enum Deprecation {
     #[default]
@@ -176,6 +269,27 @@ impl FromMeta for Resolvability {
     }
 }
 
+/// Validators that check an input object as a whole, written either as
+/// `validator = "path"` or `validator(custom = "expr", custom = "expr")`.
+#[derive(Default, Clone)]
+pub struct InputObjectValidators(pub Vec<Expr>);
+
+impl FromMeta for InputObjectValidators {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct Items {
+            #[darling(default, multiple)]
+            custom: Vec<Expr>,
+        }
+
+        Ok(InputObjectValidators(Items::from_list(items)?.custom))
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        Ok(InputObjectValidators(vec![Expr::from_expr(expr)?]))
+    }
+}
+
 #[derive(FromField)]
 #[darling(attributes(graphql), forward_attrs(doc))]
 pub struct SimpleObjectField {
@@ -239,6 +353,8 @@ pub struct SimpleObjectField {
     pub requires_scopes: Vec<String>,
     #[darling(default)]
     pub semantic_non_null: Option<bool>,
+    #[darling(default)]
+    pub projection: FieldProjection,
 }
 
 #[derive(FromDeriveInput)]
@@ -296,6 +412,8 @@ pub struct SimpleObject {
     pub crate_path: Option<Path>,
     #[darling(default)]
     pub semantic_non_null: bool,
+    #[darling(default)]
+    pub projection: Option<ObjectProjection>,
 }
 
 #[derive(FromMeta, Default)]
@@ -560,7 +678,7 @@ pub struct InputObject {
     #[darling(default, multiple, rename = "concrete")]
     pub concretes: Vec<ConcreteType>,
     #[darling(default)]
-    pub validator: Option<Expr>,
+    pub validator: InputObjectValidators,
     // for SimpleObject
     #[darling(default)]
     pub complex: bool,
@@ -1061,7 +1179,12 @@ pub struct Directive {
 #[darling(rename_all = "PascalCase")]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum DirectiveLocation {
+    Query,
+    Mutation,
     Field,
+    FragmentSpread,
+    InlineFragment,
+    VariableDefinition,
 }
 
 #[derive(FromMeta, Default)]
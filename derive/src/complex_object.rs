@@ -17,6 +17,7 @@ use crate::{
         get_type_path_and_name, parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs,
         visible_fn,
     },
+    validators::create_argument_async_validators,
 };
 
 pub fn generate(
@@ -82,8 +83,7 @@ pub fn generate(
                                 },
                                 FnArg::Receiver(_) => None,
                             })
-                            .collect::<Result<Vec<Ident>, Error>>()?
-                            .into_iter(),
+                            .collect::<Result<Vec<Ident>, Error>>()?,
                     );
 
                     let new_block = match with {
@@ -198,20 +198,8 @@ pub fn generate(
                 }
                 None => quote! { ::std::option::Option::None },
             };
-            let cache_control = {
-                let public = method_args.cache_control.is_public();
-                let max_age = if method_args.cache_control.no_cache {
-                    -1
-                } else {
-                    method_args.cache_control.max_age as i32
-                };
-                quote! {
-                    #crate_name::CacheControl {
-                        public: #public,
-                        max_age: #max_age,
-                    }
-                }
-            };
+            let cache_control = method_args.cache_control.to_token_stream(&crate_name);
+            let guarded = method_args.guard.is_some() || object_args.guard.is_some();
 
             let args = extract_input_args::<args::Argument>(&crate_name, method)?;
             let mut schema_args = Vec::new();
@@ -259,7 +247,7 @@ pub fn generate(
                     })
                     .unwrap_or_else(|| quote! {::std::option::Option::None});
 
-                let visible = visible_fn(visible);
+                let visible = visible_fn(&crate_name, visible);
                 let tags = tags
                     .iter()
                     .map(|tag| quote!(::std::string::ToString::to_string(#tag)))
@@ -308,6 +296,9 @@ pub fn generate(
                     Some(quote!(.map_err(|err| err.into_server_error(__pos)))),
                 )?;
 
+                let async_validators =
+                    create_argument_async_validators(&crate_name, validator, &ident.ident, &name);
+
                 let mut non_mut_ident = ident.clone();
                 non_mut_ident.mutability = None;
                 get_params.push(quote! {
@@ -315,6 +306,7 @@ pub fn generate(
                     let (__pos, mut #non_mut_ident) = ctx.param_value::<#ty>(#name, #default)?;
                     #process_with
                     #validators
+                    #async_validators
                     #[allow(non_snake_case)]
                     let #ident = #non_mut_ident;
                 });
@@ -331,7 +323,7 @@ pub fn generate(
                 }
             };
             let schema_ty = ty.value_type(object_args.internal);
-            let visible = visible_fn(&method_args.visible);
+            let visible = visible_fn(&crate_name, &method_args.visible);
 
             let complexity = if let Some(complexity) = &method_args.complexity {
                 let (variables, expr) = parse_complexity_expr(complexity.clone())?;
@@ -367,10 +359,10 @@ pub fn generate(
                     }
                 }
                 quote! {
-                    Some(|__ctx, __variables_definition, __field, child_complexity| {
+                    Some(#crate_name::registry::complexity_fn(|__ctx, __variables_definition, __field, child_complexity| {
                         #(#parse_args)*
                         Ok(#expr)
-                    })
+                    }))
                 }
             } else {
                 quote! { ::std::option::Option::None }
@@ -397,6 +389,7 @@ pub fn generate(
                     ty: <#schema_ty as #crate_name::OutputType>::create_type_info(registry),
                     deprecation: #field_deprecation,
                     cache_control: #cache_control,
+                    guarded: #guarded,
                     external: #external,
                     provides: #provides,
                     requires: #requires,
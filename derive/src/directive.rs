@@ -29,7 +29,7 @@ pub fn generate(
     let desc = get_rustdoc(&item_fn.attrs)?
         .map(|s| quote!(::std::option::Option::Some(::std::string::ToString::to_string(#s))))
         .unwrap_or_else(|| quote!(::std::option::Option::None));
-    let visible = visible_fn(&directive_args.visible);
+    let visible = visible_fn(&crate_name, &directive_args.visible);
     let repeatable = directive_args.repeatable;
 
     let mut get_params = Vec::new();
@@ -66,6 +66,17 @@ pub fn generate(
             ..
         } = parse_graphql_attrs::<args::Argument>(&arg_attrs)?.unwrap_or_default();
 
+        if validator
+            .as_ref()
+            .is_some_and(|validator| validator.has_async())
+        {
+            return Err(Error::new_spanned(
+                &arg_ident,
+                "`custom_async` validators are not supported on directive arguments",
+            )
+            .into());
+        }
+
         let name = name.clone().unwrap_or_else(|| {
             directive_args
                 .rename_args
@@ -86,7 +97,7 @@ pub fn generate(
                 }
             })
             .unwrap_or_else(|| quote! {::std::option::Option::None});
-        let visible = visible_fn(&visible);
+        let visible = visible_fn(&crate_name, &visible);
         let deprecation = gen_deprecation(&deprecation, &crate_name);
 
         schema_args.push(quote! {
@@ -99,7 +99,7 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
             }
         });
 
-        let visible = visible_fn(&variant.visible);
+        let visible = visible_fn(&crate_name, &variant.visible);
         schema_enum_items.push(quote! {
             enum_items.insert(::std::string::ToString::to_string(#gql_item_name), #crate_name::registry::MetaEnumValue {
                 name: ::std::string::ToString::to_string(#gql_item_name),
@@ -172,7 +172,7 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
         None
     };
 
-    let visible = visible_fn(&enum_args.visible);
+    let visible = visible_fn(&crate_name, &enum_args.visible);
     let expanded = quote! {
         #[allow(clippy::all, clippy::pedantic)]
         impl #crate_name::resolver_utils::EnumType for #ident {
@@ -63,6 +63,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
     let mut schema_fields = Vec::new();
     let mut flatten_fields = Vec::new();
     let mut federation_fields = Vec::new();
+    let mut validate_async_fields = Vec::new();
 
     for field in &s.fields {
         let ident = field.ident.as_ref().unwrap();
@@ -100,15 +101,39 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             None => Default::default(),
         };
 
+        let map_err = if field.flatten {
+            quote!(.map_err(#crate_name::InputValueError::propagate))
+        } else {
+            quote!(.map_err(|err| #crate_name::InputValueError::propagate_field(err, #name)))
+        };
         let validators = field
             .validator
             .clone()
             .unwrap_or_default()
-            .create_validators(
-                &crate_name,
-                quote!(&#ident),
-                Some(quote!(.map_err(#crate_name::InputValueError::propagate))),
-            )?;
+            .create_validators(&crate_name, quote!(&#ident), Some(map_err.clone()))?;
+
+        let async_map_err = if field.flatten {
+            quote!(#crate_name::InputValueError::propagate(err))
+        } else {
+            quote!(#crate_name::InputValueError::propagate_field(err, #name))
+        };
+        if let Some(validator) = field.validator.as_ref().filter(|v| v.has_async()) {
+            let async_validators =
+                validator.create_async_validators(&crate_name, quote!(&self.#ident), Some(map_err));
+            validate_async_fields.push(quote! {
+                __futures.push(#crate_name::futures_util::FutureExt::boxed(async move {
+                    #async_validators
+                    ::std::result::Result::Ok(())
+                }));
+            });
+        }
+        validate_async_fields.push(quote! {
+            if let ::std::option::Option::Some(__fut) = #crate_name::InputType::validate_async(&self.#ident, ctx) {
+                __futures.push(#crate_name::futures_util::FutureExt::boxed(async move {
+                    __fut.await.map_err(|err| #async_map_err)
+                }));
+            }
+        });
 
         if field.flatten {
             flatten_fields.push((ident, ty));
@@ -165,7 +190,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                         ::std::option::Option::Some(value) => {
                             #[allow(unused_mut)]
                             let mut #ident = #crate_name::InputType::parse(::std::option::Option::Some(::std::clone::Clone::clone(&value)))
-                                .map_err(#crate_name::InputValueError::propagate)?;
+                                .map_err(|err| #crate_name::InputValueError::propagate_field(err, #name))?;
                             #process_with
                             #ident
 
@@ -179,7 +204,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             get_fields.push(quote! {
                 #[allow(non_snake_case, unused_mut)]
                 let mut #ident: #ty = #crate_name::InputType::parse(obj.get(#name).cloned())
-                    .map_err(#crate_name::InputValueError::propagate)?;
+                    .map_err(|err| #crate_name::InputValueError::propagate_field(err, #name))?;
                 #process_with
                 #validators
             });
@@ -194,7 +219,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
 
         fields.push(ident);
 
-        let visible = visible_fn(&field.visible);
+        let visible = visible_fn(&crate_name, &field.visible);
         let deprecation = gen_deprecation(&field.deprecation, &crate_name);
 
         schema_fields.push(quote! {
@@ -221,7 +246,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         .into());
     }
 
-    let visible = visible_fn(&object_args.visible);
+    let visible = visible_fn(&crate_name, &object_args.visible);
 
     let get_federation_fields = {
         let fields = federation_fields.into_iter().map(|(ty, name)| {
@@ -242,8 +267,31 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
 
     let obj_validator = object_args
         .validator
-        .as_ref()
-        .map(|expr| quote! { #crate_name::CustomValidator::check(&#expr, &obj)?; });
+        .0
+        .iter()
+        .map(|expr| {
+            quote! {
+                #crate_name::CustomValidator::check(&(#expr), &obj)
+                    .map_err(#crate_name::InputValueError::with_input_path)?;
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let validate_async_body = quote! {
+        let mut __futures: ::std::vec::Vec<
+            #crate_name::futures_util::future::BoxFuture<'a, ::std::result::Result<(), #crate_name::InputValueError<Self>>>,
+        > = ::std::vec::Vec::new();
+        #(#validate_async_fields)*
+        if __futures.is_empty() {
+            return ::std::option::Option::None;
+        }
+        ::std::option::Option::Some(#crate_name::futures_util::FutureExt::boxed(async move {
+            for __fut in __futures {
+                __fut.await?;
+            }
+            ::std::result::Result::Ok(())
+        }))
+    };
 
     let expanded = if object_args.concretes.is_empty() {
         quote! {
@@ -277,7 +325,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                     if let ::std::option::Option::Some(#crate_name::Value::Object(obj)) = value {
                         #(#get_fields)*
                         let obj = Self { #(#fields),* };
-                        #obj_validator
+                        #(#obj_validator)*
                         ::std::result::Result::Ok(obj)
                     } else {
                         ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value.unwrap_or_default()))
@@ -297,6 +345,13 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                 fn as_raw_value(&self) -> ::std::option::Option<&Self::RawValueType> {
                     ::std::option::Option::Some(self)
                 }
+
+                fn validate_async<'a>(
+                    &'a self,
+                    ctx: &'a #crate_name::Context<'_>,
+                ) -> ::std::option::Option<#crate_name::futures_util::future::BoxFuture<'a, ::std::result::Result<(), #crate_name::InputValueError<Self>>>> {
+                    #validate_async_body
+                }
             }
 
             impl #crate_name::InputObjectType for #ident {}
@@ -329,7 +384,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                     if let ::std::option::Option::Some(#crate_name::Value::Object(obj)) = value {
                         #(#get_fields)*
                         let obj = Self { #(#fields),* };
-                        #obj_validator
+                        #(#obj_validator)*
                         ::std::result::Result::Ok(obj)
                     } else {
                         ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value.unwrap_or_default()))
@@ -345,6 +400,13 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                 fn __internal_federation_fields() -> ::std::option::Option<::std::string::String> where Self: #crate_name::InputType {
                     #get_federation_fields
                 }
+
+                fn __internal_validate_async<'a>(
+                    &'a self,
+                    ctx: &'a #crate_name::Context<'_>,
+                ) -> ::std::option::Option<#crate_name::futures_util::future::BoxFuture<'a, ::std::result::Result<(), #crate_name::InputValueError<Self>>>> where Self: #crate_name::InputType {
+                    #validate_async_body
+                }
             }
         });
 
@@ -388,6 +450,13 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                     fn as_raw_value(&self) -> ::std::option::Option<&Self::RawValueType> {
                         ::std::option::Option::Some(self)
                     }
+
+                    fn validate_async<'a>(
+                        &'a self,
+                        ctx: &'a #crate_name::Context<'_>,
+                    ) -> ::std::option::Option<#crate_name::futures_util::future::BoxFuture<'a, ::std::result::Result<(), #crate_name::InputValueError<Self>>>> {
+                        self.__internal_validate_async(ctx)
+                    }
                 }
 
                 impl #def_bounds #crate_name::InputObjectType for #concrete_type {}
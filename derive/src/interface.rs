@@ -258,7 +258,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                     }
                 })
                 .unwrap_or_else(|| quote! {::std::option::Option::None});
-            let visible = visible_fn(visible);
+            let visible = visible_fn(&crate_name, visible);
             let tags = tags
                 .iter()
                 .map(|tag| quote!(::std::string::ToString::to_string(#tag)))
@@ -317,7 +317,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
             }
         });
 
-        let visible = visible_fn(visible);
+        let visible = visible_fn(&crate_name, visible);
         let tags = tags
             .iter()
             .map(|tag| quote!(::std::string::ToString::to_string(#tag)))
@@ -351,6 +351,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                 ty: <#schema_ty as #crate_name::OutputType>::create_type_info(registry),
                 deprecation: #deprecation,
                 cache_control: ::std::default::Default::default(),
+                guarded: false,
                 external: #external,
                 provides: #provides,
                 requires: #requires,
@@ -391,7 +392,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         }
     };
 
-    let visible = visible_fn(&interface_args.visible);
+    let visible = visible_fn(&crate_name, &interface_args.visible);
     let expanded = quote! {
         #(#type_into_impls)*
 
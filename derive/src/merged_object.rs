@@ -81,7 +81,7 @@ pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream
         obj
     };
 
-    let visible = visible_fn(&object_args.visible);
+    let visible = visible_fn(&crate_name, &object_args.visible);
     let resolve_container = if object_args.serial {
         quote! { #crate_name::resolver_utils::resolve_container_serial(ctx, self).await }
     } else {
@@ -17,7 +17,7 @@ use crate::{
         get_type_path_and_name, parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs,
         visible_fn,
     },
-    validators::Validators,
+    validators::{Validators, create_argument_async_validators},
 };
 
 pub fn generate(
@@ -131,8 +131,7 @@ pub fn generate(
                                 },
                                 FnArg::Receiver(_) => None,
                             })
-                            .collect::<Result<Vec<Ident>, Error>>()?
-                            .into_iter(),
+                            .collect::<Result<Vec<Ident>, Error>>()?,
                     );
 
                     let new_block = match with {
@@ -331,6 +330,7 @@ pub fn generate(
                     .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
                     .unwrap_or_else(|| quote! {::std::option::Option::None});
                 let field_deprecation = gen_deprecation(&method_args.deprecation, &crate_name);
+                let guarded = method_args.guard.is_some() || object_args.guard.is_some();
                 let external = method_args.external;
                 let shareable = method_args.shareable;
                 let inaccessible = method_args.inaccessible;
@@ -372,20 +372,7 @@ pub fn generate(
                     }
                     None => quote! { ::std::option::Option::None },
                 };
-                let cache_control = {
-                    let public = method_args.cache_control.is_public();
-                    let max_age = if method_args.cache_control.no_cache {
-                        -1
-                    } else {
-                        method_args.cache_control.max_age as i32
-                    };
-                    quote! {
-                        #crate_name::CacheControl {
-                            public: #public,
-                            max_age: #max_age,
-                        }
-                    }
-                };
+                let cache_control = method_args.cache_control.to_token_stream(&crate_name);
 
                 let args = extract_input_args::<args::Argument>(&crate_name, method)?;
                 let mut schema_args = Vec::new();
@@ -432,7 +419,7 @@ pub fn generate(
                         })
                         .unwrap_or_else(|| quote! {::std::option::Option::None});
 
-                    let visible = visible_fn(visible);
+                    let visible = visible_fn(&crate_name, visible);
                     let tags = tags
                         .iter()
                         .map(|tag| quote!(::std::string::ToString::to_string(#tag)))
@@ -480,7 +467,7 @@ pub fn generate(
                     }
                 };
                 let schema_ty = ty.value_type(object_args.internal);
-                let visible = visible_fn(&method_args.visible);
+                let visible = visible_fn(&crate_name, &method_args.visible);
 
                 let complexity = if let Some(complexity) = &method_args.complexity {
                     let (variables, expr) = parse_complexity_expr(complexity.clone())?;
@@ -516,10 +503,10 @@ pub fn generate(
                         }
                     }
                     quote! {
-                        ::std::option::Option::Some(|__ctx, __variables_definition, __field, child_complexity| {
+                        ::std::option::Option::Some(#crate_name::registry::complexity_fn(|__ctx, __variables_definition, __field, child_complexity| {
                             #(#parse_args)*
                             ::std::result::Result::Ok(#expr)
-                        })
+                        }))
                     }
                 } else {
                     quote! { ::std::option::Option::None }
@@ -546,6 +533,7 @@ pub fn generate(
                         ty: <#schema_ty as #crate_name::OutputType>::create_type_info(registry),
                         deprecation: #field_deprecation,
                         cache_control: #cache_control,
+                        guarded: #guarded,
                         external: #external,
                         provides: #provides,
                         requires: #requires,
@@ -604,20 +592,7 @@ pub fn generate(
         }
     }
 
-    let cache_control = {
-        let public = object_args.cache_control.is_public();
-        let max_age = if object_args.cache_control.no_cache {
-            -1
-        } else {
-            object_args.cache_control.max_age as i32
-        };
-        quote! {
-            #crate_name::CacheControl {
-                public: #public,
-                max_age: #max_age,
-            }
-        }
-    };
+    let cache_control = object_args.cache_control.to_token_stream(&crate_name);
 
     find_entities.sort_by(|(a, _), (b, _)| b.cmp(a));
     let find_entities_iter = find_entities.iter().map(|(_, code)| code);
@@ -644,7 +619,7 @@ pub fn generate(
         }
     };
 
-    let visible = visible_fn(&object_args.visible);
+    let visible = visible_fn(&crate_name, &object_args.visible);
     let resolve_container = if object_args.serial {
         quote! { #crate_name::resolver_utils::resolve_container_serial(ctx, self).await }
     } else {
@@ -1014,12 +989,14 @@ fn generate_parameter_extraction(
     let mut non_mut_ident = ident.clone();
     non_mut_ident.mutability = None;
     let ident = &ident.ident;
+    let async_validators = create_argument_async_validators(crate_name, validator, ident, name);
     Ok(quote! {
         #[allow(non_snake_case, unused_variables, unused_mut)]
         // Todo: if there are no processors we can drop the mut.
         let (__pos, mut #non_mut_ident) = ctx.param_value::<#ty>(#name, #default)?;
         #process_with
         #validators
+        #async_validators
         #[allow(non_snake_case, unused_variables)]
         let #ident = #non_mut_ident;
     })
@@ -109,7 +109,7 @@ pub fn generate(object_args: &args::OneofObject) -> GeneratorResult<TokenStream>
             enum_names.push(enum_name);
 
             let secret = variant.secret;
-            let visible = visible_fn(&variant.visible);
+            let visible = visible_fn(&crate_name, &variant.visible);
             let deprecation = gen_deprecation(&variant.deprecation, &crate_name);
 
             schema_fields.push(quote! {
@@ -127,6 +127,18 @@ pub fn generate(object_args: &args::OneofObject) -> GeneratorResult<TokenStream>
                 });
             });
 
+            if variant
+                .validator
+                .as_ref()
+                .is_some_and(|validator| validator.has_async())
+            {
+                return Err(Error::new_spanned(
+                    enum_name,
+                    "`custom_async` validators are not supported on oneof objects",
+                )
+                .into());
+            }
+
             let validators = variant
                 .validator
                 .clone()
@@ -155,7 +167,7 @@ pub fn generate(object_args: &args::OneofObject) -> GeneratorResult<TokenStream>
         }
     }
 
-    let visible = visible_fn(&object_args.visible);
+    let visible = visible_fn(&crate_name, &object_args.visible);
     let expanded = if object_args.concretes.is_empty() {
         quote! {
             impl #crate_name::InputType for #ident {
@@ -38,7 +38,7 @@ pub fn generate(
     let self_ty = &item_impl.self_ty;
     let generic = &item_impl.generics;
     let where_clause = &item_impl.generics.where_clause;
-    let visible = visible_fn(&scalar_args.visible);
+    let visible = visible_fn(&crate_name, &scalar_args.visible);
     let inaccessible = scalar_args.inaccessible;
     let tags = scalar_args
         .tags
@@ -84,6 +84,8 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
     let mut getters = Vec::new();
     let mut resolvers = Vec::new();
     let mut schema_fields = Vec::new();
+    let mut projection_arms = Vec::new();
+    let mut flatten_projections = Vec::new();
 
     let mut processed_fields: Vec<SimpleObjectFieldGenerator> = vec![];
 
@@ -195,22 +197,35 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             field.owned
         };
 
-        let cache_control = {
-            let public = field.cache_control.is_public();
-            let max_age = if field.cache_control.no_cache {
-                -1
+        if object_args.projection.is_some() {
+            let column = field
+                .projection
+                .column
+                .clone()
+                .unwrap_or_else(|| base_ident.unraw().to_string());
+            if field.flatten {
+                flatten_projections.push(quote! {
+                    <#ty as #crate_name::Projectable>::project(projection, fields);
+                });
+            } else if field.projection.skip {
+                projection_arms.push(quote! { #field_name => {} });
+            } else if let (Some(join), None) = (&field.projection.join, derived) {
+                let join_name = join.0.clone().unwrap_or(column);
+                projection_arms.push(quote! {
+                    #field_name => <#ty as #crate_name::Projectable>::project(
+                        projection.join_mut(#join_name),
+                        &field.selection_set().collect::<::std::vec::Vec<_>>(),
+                    ),
+                });
             } else {
-                field.cache_control.max_age as i32
-            };
-            quote! {
-            #crate_name::CacheControl {
-                        public: #public,
-                        max_age: #max_age,
-                    }
-                }
-        };
+                projection_arms.push(quote! { #field_name => projection.add_column(#column), });
+            }
+        }
+
+        let cache_control = field.cache_control.to_token_stream(&crate_name);
+        let guarded = field.guard.is_some() || object_args.guard.is_some();
 
-        let visible = visible_fn(&field.visible);
+        let visible = visible_fn(&crate_name, &field.visible);
         let directives = gen_directive_calls(
             &crate_name,
             &field.directives,
@@ -220,9 +235,9 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         let complexity = if let Some(complexity) = &field.complexity {
             let (_, expr) = parse_complexity_expr(complexity.clone())?;
             quote! {
-                ::std::option::Option::Some(|__ctx, __variables_definition, __field, child_complexity| {
+                ::std::option::Option::Some(#crate_name::registry::complexity_fn(|__ctx, __variables_definition, __field, child_complexity| {
                     ::std::result::Result::Ok(#expr)
-                })
+                }))
             }
         } else {
             quote! { ::std::option::Option::None }
@@ -245,6 +260,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     ty: <#ty as #crate_name::OutputType>::create_type_info(registry),
                     deprecation: #field_deprecation,
                     cache_control: #cache_control,
+                    guarded: #guarded,
                     external: #external,
                     provides: #provides,
                     requires: #requires,
@@ -340,20 +356,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         .into());
     }
 
-    let cache_control = {
-        let public = object_args.cache_control.is_public();
-        let max_age = if object_args.cache_control.no_cache {
-            -1
-        } else {
-            object_args.cache_control.max_age as i32
-        };
-        quote! {
-            #crate_name::CacheControl {
-                public: #public,
-                max_age: #max_age,
-            }
-        }
-    };
+    let cache_control = object_args.cache_control.to_token_stream(&crate_name);
 
     let keys = match &object_args.resolvability {
         Resolvability::Resolvable => quote!(::std::option::Option::None),
@@ -389,7 +392,41 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         }
     };
 
-    let visible = visible_fn(&object_args.visible);
+    let visible = visible_fn(&crate_name, &object_args.visible);
+
+    let projectable = match &object_args.projection {
+        Some(projection) => {
+            for computed in &projection.computed {
+                let field_name = &computed.field;
+                let columns = computed
+                    .requires
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|column| !column.is_empty());
+                projection_arms.push(quote! {
+                    #field_name => {
+                        #(projection.add_column(#columns);)*
+                    }
+                });
+            }
+
+            quote! {
+                #[allow(clippy::all, clippy::pedantic)]
+                impl #impl_generics #crate_name::Projectable for #ident #ty_generics #where_clause {
+                    fn project(projection: &mut #crate_name::Projection, fields: &[#crate_name::SelectionField<'_>]) {
+                        for field in fields {
+                            match field.name() {
+                                #(#projection_arms)*
+                                _ => {}
+                            }
+                        }
+                        #(#flatten_projections)*
+                    }
+                }
+            }
+        }
+        None => quote!(),
+    };
 
     let mut concat_complex_fields = quote!();
     let mut complex_resolver = quote!();
@@ -467,6 +504,8 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             }
 
             impl #impl_generics #crate_name::ObjectType for #ident #ty_generics #where_clause {}
+
+            #projectable
         }
     } else {
         let mut code = Vec::new();
@@ -581,6 +620,8 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             code.push(expanded);
         }
 
+        code.push(projectable);
+
         quote!(#(#code)*)
     };
 
@@ -14,6 +14,7 @@ use crate::{
         get_type_path_and_name, parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs,
         visible_fn,
     },
+    validators::create_argument_async_validators,
 };
 
 pub fn generate(
@@ -123,7 +124,7 @@ pub fn generate(
                     })
                     .unwrap_or_else(|| quote! {::std::option::Option::None});
 
-                let visible = visible_fn(arg_visible);
+                let visible = visible_fn(&crate_name, arg_visible);
                 let deprecation = gen_deprecation(deprecation, &crate_name);
 
                 schema_args.push(quote! {
@@ -162,6 +163,9 @@ pub fn generate(
                     Some(quote!(.map_err(|err| err.into_server_error(__pos)))),
                 )?;
 
+                let async_validators =
+                    create_argument_async_validators(&crate_name, validator, &ident.ident, &name);
+
                 let mut non_mut_ident = ident.clone();
                 non_mut_ident.mutability = None;
                 get_params.push(quote! {
@@ -169,6 +173,7 @@ pub fn generate(
                     let (__pos, mut #non_mut_ident) = ctx.param_value::<#ty>(#name, #default)?;
                     #process_with
                     #validators
+                    #async_validators
                     #[allow(non_snake_case)]
                     let #ident = #non_mut_ident;
                 });
@@ -222,7 +227,7 @@ pub fn generate(
                         .expect("invalid result type");
             }
 
-            let visible = visible_fn(&field.visible);
+            let visible = visible_fn(&crate_name, &field.visible);
             let complexity = if let Some(complexity) = &field.complexity {
                 let (variables, expr) = parse_complexity_expr(complexity.clone())?;
                 let mut parse_args = Vec::new();
@@ -257,10 +262,10 @@ pub fn generate(
                     }
                 }
                 quote! {
-                    Some(|__ctx, __variables_definition, __field, child_complexity| {
+                    Some(#crate_name::registry::complexity_fn(|__ctx, __variables_definition, __field, child_complexity| {
                         #(#parse_args)*
                         ::std::result::Result::Ok(#expr)
-                    })
+                    }))
                 }
             } else {
                 quote! { ::std::option::Option::None }
@@ -272,6 +277,7 @@ pub fn generate(
                 TypeDirectiveLocation::FieldDefinition,
             );
 
+            let guarded = field.guard.is_some() || subscription_args.guard.is_some();
             let semantic_nullability = if field
                 .semantic_non_null
                 .unwrap_or(subscription_args.semantic_non_null)
@@ -294,6 +300,7 @@ pub fn generate(
                     ty: <#output_ty as #crate_name::OutputType>::create_type_info(registry),
                     deprecation: #field_deprecation,
                     cache_control: ::std::default::Default::default(),
+                    guarded: #guarded,
                     external: false,
                     requires: ::std::option::Option::None,
                     provides: ::std::option::Option::None,
@@ -426,7 +433,7 @@ pub fn generate(
         .into());
     }
 
-    let visible = visible_fn(&subscription_args.visible);
+    let visible = visible_fn(&crate_name, &subscription_args.visible);
 
     let expanded = quote! {
         #item_impl
@@ -146,7 +146,7 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
         .into());
     }
 
-    let visible = visible_fn(&union_args.visible);
+    let visible = visible_fn(&crate_name, &union_args.visible);
 
     let get_introspection_typename = |lazy_types: Vec<LazyType>| {
         lazy_types.into_iter().map(|lazy| {
@@ -212,12 +212,14 @@ pub fn get_type_path_and_name(ty: &Type) -> GeneratorResult<(&Type, String)> {
     }
 }
 
-pub fn visible_fn(visible: &Option<Visible>) -> TokenStream {
+pub fn visible_fn(crate_name: &syn::Path, visible: &Option<Visible>) -> TokenStream {
     match visible {
         None | Some(Visible::None) => quote! { ::std::option::Option::None },
-        Some(Visible::HiddenAlways) => quote! { ::std::option::Option::Some(|_| false) },
+        Some(Visible::HiddenAlways) => {
+            quote! { ::std::option::Option::Some(#crate_name::registry::visible_fn(|_| false)) }
+        }
         Some(Visible::FnName(name)) => {
-            quote! { ::std::option::Option::Some(#name) }
+            quote! { ::std::option::Option::Some(#crate_name::registry::visible_fn(#name)) }
         }
     }
 }
@@ -31,7 +31,7 @@ impl ToTokens for Number {
 #[derive(Clone, Debug, PartialEq)]
 pub enum UuidVersionValidation {
     None,
-    Value(Lit),
+    Value(usize),
 }
 
 impl FromMeta for UuidVersionValidation {
@@ -40,7 +40,23 @@ impl FromMeta for UuidVersionValidation {
     }
 
     fn from_value(value: &Lit) -> darling::Result<Self> {
-        Ok(UuidVersionValidation::Value(value.clone()))
+        let version = match value {
+            Lit::Int(n) => n.base10_parse::<usize>()?,
+            Lit::Str(s) => {
+                let s = s.value();
+                s.strip_prefix('v')
+                    .unwrap_or(&s)
+                    .parse::<usize>()
+                    .map_err(|_| darling::Error::unknown_value(&s))?
+            }
+            _ => return Err(darling::Error::unexpected_lit_type(value)),
+        };
+        if !(1..=8).contains(&version) {
+            return Err(darling::Error::custom(
+                "UUID version must be between 1 and 8",
+            ));
+        }
+        Ok(UuidVersionValidation::Value(version))
     }
 }
 
@@ -78,6 +94,8 @@ pub struct Validators {
     uuid: Option<UuidVersionValidation>,
     #[darling(default, multiple)]
     custom: Vec<Expr>,
+    #[darling(default, multiple)]
+    custom_async: Vec<Expr>,
     #[darling(default)]
     list: bool,
 }
@@ -181,12 +199,12 @@ impl Validators {
             match version_validation {
                 UuidVersionValidation::None => {
                     elem_validators.push(quote! {
-                        #crate_name::validators::uuid(__raw_value, None)
+                        #crate_name::validators::uuid(__raw_value, ::std::option::Option::None)
                     });
                 }
                 UuidVersionValidation::Value(version) => {
                     elem_validators.push(quote! {
-                        #crate_name::validators::uuid(__raw_value, Some(#version))
+                        #crate_name::validators::uuid(__raw_value, ::std::option::Option::Some(#version))
                     });
                 }
             }
@@ -246,4 +264,64 @@ impl Validators {
 
         Ok(quote!(#(#codes)*))
     }
+
+    pub fn has_async(&self) -> bool {
+        !self.custom_async.is_empty()
+    }
+
+    /// Generates the `custom_async` validators, which must be expanded in an
+    /// async block with a `ctx: &Context<'_>` in scope.
+    pub fn create_async_validators(
+        &self,
+        crate_name: &syn::Path,
+        value: TokenStream,
+        map_err: Option<TokenStream>,
+    ) -> TokenStream {
+        let codes = self.custom_async.iter().map(|expr| {
+            if self.list {
+                quote! {
+                    if let ::std::option::Option::Some(value) = #crate_name::InputType::as_raw_value(#value) {
+                        for __item in value {
+                            if let ::std::option::Option::Some(__raw_value) = #crate_name::InputType::as_raw_value(__item) {
+                                #crate_name::AsyncCustomValidator::check(&(#expr), ctx, __raw_value).await #map_err ?;
+                            }
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    if let ::std::option::Option::Some(__raw_value) = #crate_name::InputType::as_raw_value(#value) {
+                        #crate_name::AsyncCustomValidator::check(&(#expr), ctx, __raw_value).await #map_err ?;
+                    }
+                }
+            }
+        });
+        quote!(#(#codes)*)
+    }
+}
+
+/// Generates the asynchronous validation of an argument: its own `custom_async`
+/// validators followed by those of the input objects it contains.
+pub fn create_argument_async_validators(
+    crate_name: &syn::Path,
+    validator: &Option<Validators>,
+    ident: &syn::Ident,
+    name: &str,
+) -> TokenStream {
+    let validators = validator
+        .as_ref()
+        .map(|validator| {
+            validator.create_async_validators(
+                crate_name,
+                quote!(&#ident),
+                Some(quote!(.map_err(|err| err.into_server_error(__pos)))),
+            )
+        })
+        .unwrap_or_default();
+    quote! {
+        #validators
+        if let ::std::option::Option::Some(__fut) = #crate_name::InputType::validate_async(&#ident, ctx) {
+            __fut.await.map_err(|err| err.prefix_input_path(#name).into_server_error(__pos))?;
+        }
+    }
 }
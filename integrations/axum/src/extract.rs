@@ -1,6 +1,10 @@
 use std::marker::PhantomData;
 
-use async_graphql::{ParseRequestError, futures_util::TryStreamExt, http::MultipartOptions};
+use async_graphql::{
+    ParseRequestError,
+    futures_util::TryStreamExt,
+    http::{MultipartOptions, ResponseEncoding},
+};
 use axum::{
     extract::{FromRequest, Request},
     http::{self, Method},
@@ -12,6 +16,7 @@ use tokio_util::compat::TokioAsyncReadCompatExt;
 pub struct GraphQLRequest<R = rejection::GraphQLRejection>(
     pub async_graphql::Request,
     PhantomData<R>,
+    ResponseEncoding,
 );
 
 impl<R> GraphQLRequest<R> {
@@ -20,6 +25,16 @@ impl<R> GraphQLRequest<R> {
     pub fn into_inner(self) -> async_graphql::Request {
         self.0
     }
+
+    /// Returns the response encoding negotiated from the `Accept` header of
+    /// the request.
+    ///
+    /// Pass it to [`GraphQLResponse::with_encoding`](crate::GraphQLResponse::with_encoding)
+    /// to answer in the encoding preferred by the client.
+    #[must_use]
+    pub fn response_encoding(&self) -> ResponseEncoding {
+        self.2
+    }
 }
 
 /// Rejection response types.
@@ -65,12 +80,12 @@ where
     type Rejection = R;
 
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let GraphQLBatchRequest(request, _, encoding) =
+            GraphQLBatchRequest::<R>::from_request(req, state).await?;
         Ok(GraphQLRequest(
-            GraphQLBatchRequest::<R>::from_request(req, state)
-                .await?
-                .0
-                .into_single()?,
+            request.into_single()?,
             PhantomData,
+            encoding,
         ))
     }
 }
@@ -79,6 +94,7 @@ where
 pub struct GraphQLBatchRequest<R = rejection::GraphQLRejection>(
     pub async_graphql::BatchRequest,
     PhantomData<R>,
+    ResponseEncoding,
 );
 
 impl<R> GraphQLBatchRequest<R> {
@@ -87,6 +103,13 @@ impl<R> GraphQLBatchRequest<R> {
     pub fn into_inner(self) -> async_graphql::BatchRequest {
         self.0
     }
+
+    /// Returns the response encoding negotiated from the `Accept` header of
+    /// the request.
+    #[must_use]
+    pub fn response_encoding(&self) -> ResponseEncoding {
+        self.2
+    }
 }
 
 impl<S, R> FromRequest<S> for GraphQLBatchRequest<R>
@@ -97,6 +120,12 @@ where
     type Rejection = R;
 
     async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let encoding = ResponseEncoding::from_accept(
+            req.headers()
+                .get(http::header::ACCEPT)
+                .and_then(|value| value.to_str().ok()),
+        );
+
         if req.method() == Method::GET {
             let uri = req.uri();
             let res = async_graphql::http::parse_query_string(uri.query().unwrap_or_default())
@@ -106,7 +135,11 @@ where
                         err
                     )))
                 });
-            Ok(Self(async_graphql::BatchRequest::Single(res?), PhantomData))
+            Ok(Self(
+                async_graphql::BatchRequest::Single(res?),
+                PhantomData,
+                encoding,
+            ))
         } else {
             let content_type = req
                 .headers()
@@ -126,6 +159,7 @@ where
                 )
                 .await?,
                 PhantomData,
+                encoding,
             ))
         }
     }
@@ -11,6 +11,6 @@ mod subscription;
 
 pub use extract::{GraphQLBatchRequest, GraphQLRequest, rejection};
 pub use query::GraphQL;
-pub use response::GraphQLResponse;
+pub use response::{GraphQLEncodedResponse, GraphQLResponse};
 #[cfg(not(target_arch = "wasm32"))]
 pub use subscription::{GraphQLProtocol, GraphQLSubscription, GraphQLWebSocket};
@@ -87,7 +87,10 @@ where
                         Ok(req) => req,
                         Err(err) => return Ok(err.into_response()),
                     };
-                Ok(GraphQLResponse(executor.execute_batch(req.0).await).into_response())
+                let encoding = req.response_encoding();
+                Ok(GraphQLResponse::from(executor.execute_batch(req.0).await)
+                    .with_encoding(encoding)
+                    .into_response())
             }
         })
     }
@@ -1,7 +1,8 @@
+use async_graphql::http::ResponseEncoding;
 use axum::{
     body::Body,
     http,
-    http::HeaderValue,
+    http::{HeaderValue, StatusCode},
     response::{IntoResponse, Response},
 };
 
@@ -9,8 +10,35 @@ use axum::{
 ///
 /// This contains a batch response, but since regular responses are a type of
 /// batch response it works for both.
+///
+/// The response is encoded as JSON, use [`GraphQLResponse::with_encoding`] to
+/// encode it in another format.
 pub struct GraphQLResponse(pub async_graphql::BatchResponse);
 
+impl GraphQLResponse {
+    /// Encode the response body with `encoding`, usually the one negotiated
+    /// by [`GraphQLRequest::response_encoding`](crate::GraphQLRequest::response_encoding).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// async fn handler(
+    ///     State(schema): State<MySchema>,
+    ///     req: GraphQLRequest,
+    /// ) -> GraphQLEncodedResponse {
+    ///     let encoding = req.response_encoding();
+    ///     GraphQLResponse::from(schema.execute(req.into_inner()).await).with_encoding(encoding)
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_encoding(self, encoding: ResponseEncoding) -> GraphQLEncodedResponse {
+        GraphQLEncodedResponse {
+            response: self.0,
+            encoding,
+        }
+    }
+}
+
 impl From<async_graphql::Response> for GraphQLResponse {
     fn from(resp: async_graphql::Response) -> Self {
         Self(resp.into())
@@ -25,21 +53,54 @@ impl From<async_graphql::BatchResponse> for GraphQLResponse {
 
 impl IntoResponse for GraphQLResponse {
     fn into_response(self) -> Response {
-        let body: Body = serde_json::to_string(&self.0).unwrap().into();
-        let mut resp = Response::new(body);
-        resp.headers_mut().insert(
-            http::header::CONTENT_TYPE,
-            HeaderValue::from_static("application/graphql-response+json"),
-        );
-        if self.0.is_ok()
-            && let Some(cache_control) = self.0.cache_control().value()
-            && let Ok(value) = HeaderValue::from_str(&cache_control)
-        {
-            resp.headers_mut()
-                .insert(http::header::CACHE_CONTROL, value);
-        }
+        encode(self.0, ResponseEncoding::Json, false)
+    }
+}
+
+/// Responder for a GraphQL response encoded in the format negotiated with
+/// the client, created by [`GraphQLResponse::with_encoding`].
+///
+/// Since the body depends on the `Accept` header of the request, the response
+/// has a `Vary: Accept` header.
+pub struct GraphQLEncodedResponse {
+    response: async_graphql::BatchResponse,
+    encoding: ResponseEncoding,
+}
 
-        resp.headers_mut().extend(self.0.http_headers());
-        resp
+impl IntoResponse for GraphQLEncodedResponse {
+    fn into_response(self) -> Response {
+        encode(self.response, self.encoding, true)
     }
 }
+
+fn encode(
+    response: async_graphql::BatchResponse,
+    encoding: ResponseEncoding,
+    negotiated: bool,
+) -> Response {
+    let body = match encoding.encode(&response) {
+        Ok(body) => body,
+        Err(err) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response();
+        }
+    };
+    let mut resp = Response::new(Body::from(body));
+    resp.headers_mut().insert(
+        http::header::CONTENT_TYPE,
+        HeaderValue::from_static(encoding.content_type()),
+    );
+    if negotiated {
+        resp.headers_mut()
+            .append(http::header::VARY, HeaderValue::from_static("accept"));
+    }
+    if response.is_ok()
+        && let Some(cache_control) = response.cache_control().value()
+        && let Ok(value) = HeaderValue::from_str(&cache_control)
+    {
+        resp.headers_mut()
+            .insert(http::header::CACHE_CONTROL, value);
+    }
+
+    resp.headers_mut().extend(response.http_headers());
+    resp
+}
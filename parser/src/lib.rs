@@ -1,8 +1,8 @@
 //! A parser for GraphQL. Used in the [`async-graphql`](https://crates.io/crates/async-graphql)
 //! crate.
 //!
-//! It uses the [pest](https://crates.io/crates/pest) crate to parse the input and then transforms
-//! it into Rust types.
+//! It is a hand-written recursive descent parser, which reads the input one
+//! token at a time and transforms it directly into Rust types.
 #![warn(missing_docs)]
 #![allow(clippy::unnecessary_wraps)]
 #![allow(clippy::upper_case_acronyms)]
@@ -13,9 +13,11 @@
 use std::fmt::{self, Display, Formatter};
 
 use async_graphql_value::Name;
-pub use parse::{parse_query, parse_schema};
-use pest::{RuleType, error::LineColLocation};
+pub use parse::{
+    ParseOptions, parse_query, parse_query_with_options, parse_schema, parse_schema_with_options,
+};
 pub use pos::{Pos, Positioned};
+pub use print::{PrintOptions, print_query, print_schema};
 use serde::{Serialize, Serializer};
 
 use crate::types::OperationType;
@@ -24,6 +26,7 @@ pub mod types;
 
 mod parse;
 mod pos;
+mod print;
 
 /// Parser error.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,7 +34,7 @@ mod pos;
 pub enum Error {
     /// A syntax error occurred.
     Syntax {
-        /// The message of the error, nicely formatted with newlines.
+        /// The message of the error, such as `expected Name, found "}"`.
         message: String,
         /// The start position of the error.
         start: Pos,
@@ -81,6 +84,29 @@ pub enum Error {
     MissingOperation,
     /// Recursion limit exceeded.
     RecursionLimitExceeded,
+    /// The document exceeds one of the limits of the [`ParseOptions`].
+    LimitExceeded {
+        /// The limit that was exceeded.
+        limit: ParseLimit,
+        /// The maximum allowed by the limit.
+        max: usize,
+        /// The position where the limit was exceeded, if present.
+        pos: Option<Pos>,
+    },
+}
+
+/// A limit of the [`ParseOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseLimit {
+    /// The number of tokens in the document.
+    Tokens,
+    /// The length of the document, in bytes.
+    DocumentLength,
+    /// The number of aliased fields in a selection set.
+    Aliases,
+    /// The number of fragment definitions in the document.
+    Fragments,
 }
 
 impl Error {
@@ -110,6 +136,8 @@ impl Error {
             }
             Self::MissingOperation => ErrorPositions::new_0(),
             Self::RecursionLimitExceeded => ErrorPositions::new_0(),
+            Self::LimitExceeded { pos: Some(pos), .. } => ErrorPositions::new_1(*pos),
+            Self::LimitExceeded { pos: None, .. } => ErrorPositions::new_0(),
         }
     }
 }
@@ -131,27 +159,24 @@ impl Display for Error {
             }
             Self::MissingOperation => f.write_str("document does not contain an operation"),
             Self::RecursionLimitExceeded => f.write_str("recursion limit exceeded."),
+            Self::LimitExceeded { limit, max, .. } => match limit {
+                ParseLimit::Tokens => write!(f, "document exceeds the maximum of {} tokens", max),
+                ParseLimit::DocumentLength => {
+                    write!(f, "document exceeds the maximum length of {} bytes", max)
+                }
+                ParseLimit::Aliases => {
+                    write!(f, "selection set exceeds the maximum of {} aliases", max)
+                }
+                ParseLimit::Fragments => {
+                    write!(f, "document exceeds the maximum of {} fragments", max)
+                }
+            },
         }
     }
 }
 
 impl std::error::Error for Error {}
 
-impl<R: RuleType> From<pest::error::Error<R>> for Error {
-    fn from(err: pest::error::Error<R>) -> Self {
-        let (start, end) = match err.line_col {
-            LineColLocation::Pos(at) => (at, None),
-            LineColLocation::Span(start, end) => (start, Some(end)),
-        };
-
-        Error::Syntax {
-            message: err.to_string(),
-            start: Pos::from(start),
-            end: end.map(Pos::from),
-        }
-    }
-}
-
 /// An alias for `Result<T, Error>`.
 pub type Result<T> = std::result::Result<T, Error>;
 
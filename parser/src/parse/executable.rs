@@ -1,26 +1,37 @@
 use super::*;
 
-const MAX_RECURSION_DEPTH: usize = 64;
-
-macro_rules! recursion_depth {
-    ($remaining_depth:ident) => {{
-        if $remaining_depth == 0 {
-            return Err(Error::RecursionLimitExceeded);
-        }
-        $remaining_depth - 1
-    }};
-}
-
 /// Parse a GraphQL query document.
 ///
 /// # Errors
 ///
 /// Fails if the query is not a valid GraphQL document.
 pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
-    let mut pc = PositionCalculator::new(input.as_ref());
+    parse_query_with_options(input, ParseOptions::default())
+}
 
-    let pairs = GraphQLParser::parse(Rule::executable_document, input.as_ref())?;
-    let items = parse_definition_items(exactly_one(pairs), &mut pc)?;
+/// Parse a GraphQL query document, with limits on its size.
+///
+/// ```
+/// use async_graphql_parser::{Error, ParseLimit, ParseOptions, parse_query_with_options};
+///
+/// let options = ParseOptions::new().max_aliases(1);
+/// assert!(parse_query_with_options("{ a: value }", options).is_ok());
+/// assert!(matches!(
+///     parse_query_with_options("{ a: value b: value }", options),
+///     Err(Error::LimitExceeded { limit: ParseLimit::Aliases, max: 1, .. })
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// Fails if the query is not a valid GraphQL document, or if it exceeds one of
+/// the limits.
+pub fn parse_query_with_options<T: AsRef<str>>(
+    input: T,
+    options: ParseOptions,
+) -> Result<ExecutableDocument> {
+    let mut parser = Parser::new(input.as_ref(), options)?;
+    let items = parser.parse_definition_items()?;
 
     let mut operations = None;
     let mut fragments: HashMap<_, Positioned<FragmentDefinition>> = HashMap::new();
@@ -51,7 +62,11 @@ pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
                             });
                         }
                         hash_map::Entry::Vacant(entry) => {
-                            entry.insert(Positioned::new(item.node.definition, item.pos));
+                            entry.insert(Positioned {
+                                pos: item.pos,
+                                end: item.end,
+                                node: item.node.definition,
+                            });
                         }
                     }
                 } else {
@@ -68,10 +83,11 @@ pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
                             });
                         }
                         None => {
-                            operations = Some(DocumentOperations::Single(Positioned::new(
-                                item.node.definition,
-                                item.pos,
-                            )));
+                            operations = Some(DocumentOperations::Single(Positioned {
+                                pos: item.pos,
+                                end: item.end,
+                                node: item.node.definition,
+                            }));
                         }
                     }
                 }
@@ -86,7 +102,11 @@ pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
                     });
                 }
                 hash_map::Entry::Vacant(entry) => {
-                    entry.insert(Positioned::new(item.node.definition, item.pos));
+                    entry.insert(Positioned {
+                        pos: item.pos,
+                        end: item.end,
+                        node: item.node.definition,
+                    });
                 }
             },
         }
@@ -98,321 +118,251 @@ pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
     })
 }
 
-fn parse_definition_items(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<DefinitionItem>> {
-    debug_assert_eq!(pair.as_rule(), Rule::executable_document);
-
-    Ok(pair
-        .into_inner()
-        .filter(|pair| pair.as_rule() != Rule::EOI)
-        .map(|pair| parse_definition_item(pair, pc))
-        .collect::<Result<_>>()?)
-}
-
 enum DefinitionItem {
     Operation(Positioned<OperationDefinitionItem>),
     Fragment(Positioned<FragmentDefinitionItem>),
 }
 
-fn parse_definition_item(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<DefinitionItem> {
-    debug_assert_eq!(pair.as_rule(), Rule::executable_definition);
-
-    let pair = exactly_one(pair.into_inner());
-    Ok(match pair.as_rule() {
-        Rule::operation_definition => {
-            DefinitionItem::Operation(parse_operation_definition_item(pair, pc)?)
-        }
-        Rule::fragment_definition => {
-            DefinitionItem::Fragment(parse_fragment_definition_item(pair, pc)?)
-        }
-        _ => unreachable!(),
-    })
-}
-
 struct OperationDefinitionItem {
     name: Option<Positioned<Name>>,
     definition: OperationDefinition,
 }
 
-fn parse_operation_definition_item(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<OperationDefinitionItem>> {
-    debug_assert_eq!(pair.as_rule(), Rule::operation_definition);
-
-    let pos = pc.step(&pair);
-    let pair = exactly_one(pair.into_inner());
-    Ok(Positioned::new(
-        match pair.as_rule() {
-            Rule::named_operation_definition => parse_named_operation_definition(pair, pc)?,
-            Rule::selection_set => OperationDefinitionItem {
-                name: None,
-                definition: OperationDefinition {
-                    ty: OperationType::Query,
-                    variable_definitions: Vec::new(),
-                    directives: Vec::new(),
-                    selection_set: parse_selection_set(pair, pc, MAX_RECURSION_DEPTH)?,
-                },
-            },
-            _ => unreachable!(),
-        },
-        pos,
-    ))
-}
-
-fn parse_named_operation_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<OperationDefinitionItem> {
-    debug_assert_eq!(pair.as_rule(), Rule::named_operation_definition);
-
-    let mut pairs = pair.into_inner();
-
-    let ty = parse_operation_type(pairs.next().unwrap(), pc)?;
-    let name = parse_if_rule(&mut pairs, Rule::name, |pair| parse_name(pair, pc))?;
-    let variable_definitions = parse_if_rule(&mut pairs, Rule::variable_definitions, |pair| {
-        parse_variable_definitions(pair, pc)
-    })?;
-    let directives = parse_opt_directives(&mut pairs, pc)?;
-    let selection_set = parse_selection_set(pairs.next().unwrap(), pc, MAX_RECURSION_DEPTH)?;
-
-    debug_assert_eq!(pairs.next(), None);
-
-    Ok(OperationDefinitionItem {
-        name,
-        definition: OperationDefinition {
-            ty: ty.node,
-            variable_definitions: variable_definitions.unwrap_or_default(),
-            directives,
-            selection_set,
-        },
-    })
-}
-
-fn parse_variable_definitions(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<Positioned<VariableDefinition>>> {
-    debug_assert_eq!(pair.as_rule(), Rule::variable_definitions);
-
-    pair.into_inner()
-        .map(|pair| parse_variable_definition(pair, pc))
-        .collect()
+struct FragmentDefinitionItem {
+    name: Positioned<Name>,
+    definition: FragmentDefinition,
 }
 
-fn parse_variable_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<VariableDefinition>> {
-    debug_assert_eq!(pair.as_rule(), Rule::variable_definition);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
+impl Parser<'_> {
+    fn parse_definition_items(&mut self) -> Result<Vec<DefinitionItem>> {
+        let mut items = Vec::new();
+        let mut fragments = 0;
 
-    let variable = parse_variable(pairs.next().unwrap(), pc)?;
-    let var_type = parse_type(pairs.next().unwrap(), pc)?;
-
-    let directives = parse_opt_directives(&mut pairs, pc)?;
-    let default_value = parse_if_rule(&mut pairs, Rule::default_value, |pair| {
-        parse_default_value(pair, pc)
-    })?;
-
-    debug_assert_eq!(pairs.next(), None);
-
-    Ok(Positioned::new(
-        VariableDefinition {
-            name: variable,
-            var_type,
-            directives,
-            default_value,
-        },
-        pos,
-    ))
-}
-
-fn parse_selection_set(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-    remaining_depth: usize,
-) -> Result<Positioned<SelectionSet>> {
-    debug_assert_eq!(pair.as_rule(), Rule::selection_set);
-
-    let pos = pc.step(&pair);
-
-    Ok(Positioned::new(
-        SelectionSet {
-            items: pair
-                .into_inner()
-                .map(|pair| parse_selection(pair, pc, remaining_depth))
-                .collect::<Result<_>>()?,
-        },
-        pos,
-    ))
-}
+        loop {
+            items.push(match self.token.text {
+                _ if self.is(TokenKind::BraceL) => {
+                    DefinitionItem::Operation(self.parse_operation_definition_item()?)
+                }
+                "query" | "mutation" | "subscription" if self.is(TokenKind::Name) => {
+                    DefinitionItem::Operation(self.parse_operation_definition_item()?)
+                }
+                "fragment" if self.is(TokenKind::Name) => {
+                    fragments += 1;
+                    self.options.check_fragments(fragments, self.token.pos)?;
+                    DefinitionItem::Fragment(self.parse_fragment_definition_item()?)
+                }
+                _ => {
+                    return self.unexpected(
+                        "\"{\", \"query\", \"mutation\", \"subscription\" or \"fragment\"",
+                    );
+                }
+            });
 
-fn parse_selection(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-    remaining_depth: usize,
-) -> Result<Positioned<Selection>> {
-    debug_assert_eq!(pair.as_rule(), Rule::selection);
-
-    let pos = pc.step(&pair);
-    let pair = exactly_one(pair.into_inner());
-
-    Ok(Positioned::new(
-        match pair.as_rule() {
-            Rule::field => Selection::Field(parse_field(pair, pc, remaining_depth)?),
-            Rule::fragment_spread => Selection::FragmentSpread(parse_fragment_spread(pair, pc)?),
-            Rule::inline_fragment => {
-                Selection::InlineFragment(parse_inline_fragment(pair, pc, remaining_depth)?)
+            if self.is(TokenKind::Eof) {
+                return Ok(items);
             }
-            _ => unreachable!(),
-        },
-        pos,
-    ))
-}
+        }
+    }
 
-fn parse_field(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-    remaining_depth: usize,
-) -> Result<Positioned<Field>> {
-    debug_assert_eq!(pair.as_rule(), Rule::field);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
-
-    let alias = parse_if_rule(&mut pairs, Rule::alias, |pair| parse_alias(pair, pc))?;
-    let name = parse_name(pairs.next().unwrap(), pc)?;
-    let arguments = parse_if_rule(&mut pairs, Rule::arguments, |pair| {
-        parse_arguments(pair, pc)
-    })?;
-    let directives = parse_opt_directives(&mut pairs, pc)?;
-    let selection_set = parse_if_rule(&mut pairs, Rule::selection_set, |pair| {
-        parse_selection_set(pair, pc, recursion_depth!(remaining_depth))
-    })?;
-
-    debug_assert_eq!(pairs.next(), None);
-
-    Ok(Positioned::new(
-        Field {
-            alias,
-            name,
-            arguments: arguments.unwrap_or_default(),
-            directives,
-            selection_set: selection_set.unwrap_or_default(),
-        },
-        pos,
-    ))
-}
+    fn parse_operation_definition_item(&mut self) -> Result<Positioned<OperationDefinitionItem>> {
+        let pos = self.token.pos;
+
+        if self.is(TokenKind::BraceL) {
+            let selection_set = self.parse_selection_set(MAX_RECURSION_DEPTH)?;
+            return Ok(self.positioned(
+                OperationDefinitionItem {
+                    name: None,
+                    definition: OperationDefinition {
+                        ty: OperationType::Query,
+                        variable_definitions: Vec::new(),
+                        directives: Vec::new(),
+                        selection_set,
+                    },
+                },
+                pos,
+            ));
+        }
 
-fn parse_alias(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Name>> {
-    debug_assert_eq!(pair.as_rule(), Rule::alias);
-    parse_name(exactly_one(pair.into_inner()), pc)
-}
+        let ty = self.parse_operation_type()?;
+        let name = if self.is(TokenKind::Name) {
+            Some(self.parse_name()?)
+        } else {
+            None
+        };
+        let variable_definitions = if self.is(TokenKind::ParenL) {
+            self.parse_variable_definitions()?
+        } else {
+            Vec::new()
+        };
+        let directives = self.parse_directives()?;
+        let selection_set = self.parse_selection_set(MAX_RECURSION_DEPTH)?;
+
+        Ok(self.positioned(
+            OperationDefinitionItem {
+                name,
+                definition: OperationDefinition {
+                    ty: ty.node,
+                    variable_definitions,
+                    directives,
+                    selection_set,
+                },
+            },
+            pos,
+        ))
+    }
 
-fn parse_fragment_spread(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<FragmentSpread>> {
-    debug_assert_eq!(pair.as_rule(), Rule::fragment_spread);
+    fn parse_variable_definitions(&mut self) -> Result<Vec<Positioned<VariableDefinition>>> {
+        self.any(TokenKind::ParenL, TokenKind::ParenR, |p| {
+            p.parse_variable_definition()
+        })
+    }
 
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
+    fn parse_variable_definition(&mut self) -> Result<Positioned<VariableDefinition>> {
+        let pos = self.token.pos;
 
-    let fragment_name = parse_name(pairs.next().unwrap(), pc)?;
-    let directives = parse_opt_directives(&mut pairs, pc)?;
+        let name = self.parse_variable()?;
+        self.expect(TokenKind::Colon)?;
+        let var_type = self.parse_type()?;
 
-    debug_assert_eq!(pairs.next(), None);
+        // directives before the default value are accepted for compatibility
+        let mut directives = self.parse_directives()?;
+        let default_value = self.parse_default_value()?;
+        if directives.is_empty() {
+            directives = self.parse_directives()?;
+        }
 
-    Ok(Positioned::new(
-        FragmentSpread {
-            fragment_name,
-            directives,
-        },
-        pos,
-    ))
-}
+        Ok(self.positioned(
+            VariableDefinition {
+                name,
+                var_type,
+                directives,
+                default_value,
+            },
+            pos,
+        ))
+    }
 
-fn parse_inline_fragment(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-    remaining_depth: usize,
-) -> Result<Positioned<InlineFragment>> {
-    debug_assert_eq!(pair.as_rule(), Rule::inline_fragment);
+    fn parse_selection_set(&mut self, remaining_depth: usize) -> Result<Positioned<SelectionSet>> {
+        let pos = self.token.pos;
+        let mut aliases = 0;
+
+        let items = self.many(TokenKind::BraceL, TokenKind::BraceR, |p| {
+            let selection_pos = p.token.pos;
+
+            match p.token.kind {
+                TokenKind::Name => {
+                    let mut name = p.parse_name()?;
+                    let mut alias = None;
+                    if p.eat(TokenKind::Colon)? {
+                        aliases += 1;
+                        p.options.check_aliases(aliases, pos)?;
+                        alias = Some(std::mem::replace(&mut name, p.parse_name()?));
+                    }
+                    let field = p.parse_field(selection_pos, alias, name, remaining_depth)?;
+                    Ok(p.positioned(Selection::Field(field), selection_pos))
+                }
+                TokenKind::Spread => p.parse_fragment(remaining_depth),
+                _ => p.unexpected("Name or \"...\""),
+            }
+        })?;
 
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
+        Ok(self.positioned(SelectionSet { items }, pos))
+    }
 
-    let type_condition = parse_if_rule(&mut pairs, Rule::type_condition, |pair| {
-        parse_type_condition(pair, pc)
-    })?;
-    let directives = parse_opt_directives(&mut pairs, pc)?;
-    let selection_set =
-        parse_selection_set(pairs.next().unwrap(), pc, recursion_depth!(remaining_depth))?;
+    fn parse_field(
+        &mut self,
+        pos: Pos,
+        alias: Option<Positioned<Name>>,
+        name: Positioned<Name>,
+        remaining_depth: usize,
+    ) -> Result<Positioned<Field>> {
+        let arguments = if self.is(TokenKind::ParenL) {
+            self.parse_arguments()?
+        } else {
+            Vec::new()
+        };
+        let directives = self.parse_directives()?;
+        let selection_set = if self.is(TokenKind::BraceL) {
+            self.parse_selection_set(recursion_depth!(remaining_depth))?
+        } else {
+            Positioned::default()
+        };
+
+        Ok(self.positioned(
+            Field {
+                alias,
+                name,
+                arguments,
+                directives,
+                selection_set,
+            },
+            pos,
+        ))
+    }
 
-    debug_assert_eq!(pairs.next(), None);
+    /// Parses a fragment spread or an inline fragment.
+    fn parse_fragment(&mut self, remaining_depth: usize) -> Result<Positioned<Selection>> {
+        let pos = self.expect(TokenKind::Spread)?.pos;
+
+        let is_spread = self.is(TokenKind::Name)
+            && !(self.token.text == "on" && self.peek()?.kind == TokenKind::Name);
+        if is_spread {
+            let fragment_name = self.parse_name()?;
+            let directives = self.parse_directives()?;
+            let spread = FragmentSpread {
+                fragment_name,
+                directives,
+            };
+            return Ok(
+                self.positioned(Selection::FragmentSpread(self.positioned(spread, pos)), pos)
+            );
+        }
 
-    Ok(Positioned::new(
-        InlineFragment {
+        let type_condition = if self.is(TokenKind::Name) {
+            Some(self.parse_type_condition()?)
+        } else {
+            None
+        };
+        let directives = self.parse_directives()?;
+        let selection_set = self.parse_selection_set(recursion_depth!(remaining_depth))?;
+        let inline_fragment = InlineFragment {
             type_condition,
             directives,
             selection_set,
-        },
-        pos,
-    ))
-}
-
-struct FragmentDefinitionItem {
-    name: Positioned<Name>,
-    definition: FragmentDefinition,
-}
-
-fn parse_fragment_definition_item(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<FragmentDefinitionItem>> {
-    debug_assert_eq!(pair.as_rule(), Rule::fragment_definition);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
+        };
 
-    let name = parse_name(pairs.next().unwrap(), pc)?;
-    let type_condition = parse_type_condition(pairs.next().unwrap(), pc)?;
-    let directives = parse_opt_directives(&mut pairs, pc)?;
-    let selection_set = parse_selection_set(pairs.next().unwrap(), pc, MAX_RECURSION_DEPTH)?;
-
-    debug_assert_eq!(pairs.next(), None);
+        Ok(self.positioned(
+            Selection::InlineFragment(self.positioned(inline_fragment, pos)),
+            pos,
+        ))
+    }
 
-    Ok(Positioned::new(
-        FragmentDefinitionItem {
-            name,
-            definition: FragmentDefinition {
-                type_condition,
-                directives,
-                selection_set,
+    fn parse_fragment_definition_item(&mut self) -> Result<Positioned<FragmentDefinitionItem>> {
+        let pos = self.expect_keyword("fragment")?.pos;
+
+        let name = self.parse_name()?;
+        let type_condition = self.parse_type_condition()?;
+        let directives = self.parse_directives()?;
+        let selection_set = self.parse_selection_set(MAX_RECURSION_DEPTH)?;
+
+        Ok(self.positioned(
+            FragmentDefinitionItem {
+                name,
+                definition: FragmentDefinition {
+                    type_condition,
+                    directives,
+                    selection_set,
+                },
             },
-        },
-        pos,
-    ))
-}
+            pos,
+        ))
+    }
 
-fn parse_type_condition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<TypeCondition>> {
-    debug_assert_eq!(pair.as_rule(), Rule::type_condition);
-
-    let pos = pc.step(&pair);
-    Ok(Positioned::new(
-        TypeCondition {
-            on: parse_name(exactly_one(pair.into_inner()), pc)?,
-        },
-        pos,
-    ))
+    fn parse_type_condition(&mut self) -> Result<Positioned<TypeCondition>> {
+        let pos = self.expect_keyword("on")?.pos;
+        let on = self.parse_name()?;
+        Ok(self.positioned(TypeCondition { on }, pos))
+    }
 }
 
 #[cfg(test)]
@@ -420,20 +370,7 @@ mod tests {
     use std::fs;
 
     use super::*;
-
-    #[test]
-    fn test_parser() {
-        for entry in fs::read_dir("tests/executables").unwrap() {
-            let entry = entry.unwrap();
-            eprintln!("Parsing file {}", entry.path().display());
-
-            GraphQLParser::parse(
-                Rule::executable_document,
-                &fs::read_to_string(entry.path()).unwrap(),
-            )
-            .unwrap();
-        }
-    }
+    use crate::ParseLimit;
 
     #[test]
     fn test_parser_ast() {
@@ -451,4 +388,119 @@ mod tests {
         assert!(parse_query(query_ok).is_ok());
         assert!(parse_query(query_overflow).is_ok());
     }
+
+    #[test]
+    fn test_parse_variable_directives() {
+        for query in [
+            "query($c: Int = 10 @directive) { value }",
+            "query($c: Int @directive = 10) { value }",
+        ] {
+            let doc = parse_query(query).unwrap();
+            let DocumentOperations::Single(operation) = doc.operations else {
+                panic!("expected a single operation");
+            };
+            let variable = &operation.node.variable_definitions[0].node;
+            assert_eq!(variable.directives[0].node.name.node, "directive");
+            assert_eq!(
+                variable.default_value.as_ref().unwrap().node,
+                ConstValue::Number(10.into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_spans() {
+        let source = "query($v: [Int!] = [1] @d) {\n  a: field(x: { y: $v }) @skip(if: true) { b }\n  ...F\n  ... on T { c }\n}\nfragment F on T { d }\n";
+        let doc = parse_query_with_options(source, ParseOptions::new().spans(true)).unwrap();
+        let span =
+            |positioned_span: Option<std::ops::Range<usize>>| &source[positioned_span.unwrap()];
+
+        let DocumentOperations::Single(operation) = &doc.operations else {
+            panic!("expected a single operation");
+        };
+        assert_eq!(
+            span(operation.span()),
+            &source[..source.find("\nfragment").unwrap()]
+        );
+
+        let variable = &operation.node.variable_definitions[0];
+        assert_eq!(span(variable.span()), "$v: [Int!] = [1] @d");
+        assert_eq!(span(variable.node.var_type.span()), "[Int!]");
+        assert_eq!(
+            span(variable.node.default_value.as_ref().unwrap().span()),
+            "[1]"
+        );
+
+        let items = &operation.node.selection_set.node.items;
+        assert_eq!(
+            span(items[0].span()),
+            "a: field(x: { y: $v }) @skip(if: true) { b }"
+        );
+        let Selection::Field(field) = &items[0].node else {
+            panic!("expected a field");
+        };
+        assert_eq!(span(field.node.alias.as_ref().unwrap().span()), "a");
+        assert_eq!(span(field.node.arguments[0].1.span()), "{ y: $v }");
+        assert_eq!(span(field.node.directives[0].span()), "@skip(if: true)");
+        assert_eq!(span(field.node.selection_set.span()), "{ b }");
+        assert_eq!(span(items[1].span()), "...F");
+        assert_eq!(span(items[2].span()), "... on T { c }");
+
+        let fragment = &doc.fragments[&Name::new("F")];
+        assert_eq!(span(fragment.span()), "fragment F on T { d }");
+        assert_eq!(span(fragment.node.type_condition.span()), "on T");
+
+        let doc = parse_query(source).unwrap();
+        let DocumentOperations::Single(operation) = &doc.operations else {
+            panic!("expected a single operation");
+        };
+        assert_eq!(operation.end, None);
+        assert_eq!(operation.pos.offset, 0);
+        assert_eq!(
+            operation.node.selection_set.node.items[1].pos.offset,
+            source.find("...F").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        let query = "query { a: value b: value ...F } fragment F on Query { c: value } fragment G on Query { value }";
+        let limit_exceeded = |options: ParseOptions| {
+            let err = parse_query_with_options(query, options).unwrap_err();
+            let Error::LimitExceeded { limit, max, pos } = err else {
+                panic!("unexpected error: {}", err);
+            };
+            (limit, max, pos.map(|pos| (pos.line, pos.column)))
+        };
+
+        assert!(parse_query_with_options(query, ParseOptions::new()).is_ok());
+        assert!(
+            parse_query_with_options(
+                query,
+                ParseOptions::new()
+                    .max_tokens(27)
+                    .max_document_length(query.len())
+                    .max_aliases(2)
+                    .max_fragments(2)
+            )
+            .is_ok()
+        );
+
+        assert_eq!(
+            limit_exceeded(ParseOptions::new().max_tokens(26)),
+            (ParseLimit::Tokens, 26, Some((1, 95)))
+        );
+        assert_eq!(
+            limit_exceeded(ParseOptions::new().max_document_length(10)),
+            (ParseLimit::DocumentLength, 10, None)
+        );
+        assert_eq!(
+            limit_exceeded(ParseOptions::new().max_aliases(1)),
+            (ParseLimit::Aliases, 1, Some((1, 7)))
+        );
+        assert_eq!(
+            limit_exceeded(ParseOptions::new().max_fragments(1)),
+            (ParseLimit::Fragments, 1, Some((1, 67)))
+        );
+    }
 }
@@ -0,0 +1,615 @@
+use std::fmt::{self, Display, Formatter};
+
+use super::ParseOptions;
+use crate::{Error, Pos, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TokenKind {
+    Bang,
+    Dollar,
+    Amp,
+    ParenL,
+    ParenR,
+    Spread,
+    Colon,
+    Equals,
+    At,
+    BracketL,
+    BracketR,
+    BraceL,
+    Pipe,
+    BraceR,
+    Name,
+    Int,
+    Float,
+    String,
+    BlockString,
+    Eof,
+}
+
+impl Display for TokenKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Bang => "\"!\"",
+            Self::Dollar => "\"$\"",
+            Self::Amp => "\"&\"",
+            Self::ParenL => "\"(\"",
+            Self::ParenR => "\")\"",
+            Self::Spread => "\"...\"",
+            Self::Colon => "\":\"",
+            Self::Equals => "\"=\"",
+            Self::At => "\"@\"",
+            Self::BracketL => "\"[\"",
+            Self::BracketR => "\"]\"",
+            Self::BraceL => "\"{\"",
+            Self::Pipe => "\"|\"",
+            Self::BraceR => "\"}\"",
+            Self::Name => "Name",
+            Self::Int => "Int",
+            Self::Float => "Float",
+            Self::String => "String",
+            Self::BlockString => "BlockString",
+            Self::Eof => "<EOF>",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Token<'a> {
+    pub(super) kind: TokenKind,
+    /// The source of the token, including the quotes of strings.
+    pub(super) text: &'a str,
+    pub(super) pos: Pos,
+    /// The position right after the token.
+    pub(super) end: Pos,
+}
+
+impl Token<'_> {
+    pub(super) fn is_keyword(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Name && self.text == keyword
+    }
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            TokenKind::Name | TokenKind::Int | TokenKind::Float => {
+                write!(f, "{} \"{}\"", self.kind, self.text)
+            }
+            TokenKind::String => write!(f, "{} {}", self.kind, self.text),
+            _ => self.kind.fmt(f),
+        }
+    }
+}
+
+/// Splits a document into tokens, skipping whitespace, commas and comments.
+#[derive(Clone)]
+pub(super) struct Lexer<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+    tokens: usize,
+    options: ParseOptions,
+}
+
+impl<'a> Lexer<'a> {
+    pub(super) fn new(input: &'a str, options: ParseOptions) -> Self {
+        Self {
+            input,
+            offset: 0,
+            line: 1,
+            column: 1,
+            tokens: 0,
+            options,
+        }
+    }
+
+    fn pos(&self) -> Pos {
+        Pos {
+            line: self.line,
+            column: self.column,
+            offset: self.offset,
+        }
+    }
+
+    fn peek_byte(&self, n: usize) -> Option<u8> {
+        self.input.as_bytes().get(self.offset + n).copied()
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.input[self.offset..].chars().next()
+    }
+
+    /// Advances to `end`, counting the lines and the columns in characters.
+    fn advance_to(&mut self, end: usize) {
+        let bytes = self.input.as_bytes();
+        for (idx, &byte) in bytes.iter().enumerate().take(end).skip(self.offset) {
+            match byte {
+                // "\r\n" is a single line terminator
+                b'\n' if idx > 0 && bytes[idx - 1] == b'\r' => {}
+                b'\n' | b'\r' => {
+                    self.line += 1;
+                    self.column = 1;
+                }
+                // UTF-8 continuation byte
+                _ if byte & 0xc0 == 0x80 => {}
+                _ => self.column += 1,
+            }
+        }
+        self.offset = end;
+    }
+
+    /// Advances over ASCII characters without line terminators.
+    fn advance_ascii(&mut self, len: usize) {
+        self.offset += len;
+        self.column += len;
+    }
+
+    fn skip_ignored(&mut self) {
+        let bytes = self.input.as_bytes();
+        while let Some(&byte) = bytes.get(self.offset) {
+            match byte {
+                b' ' | b'\t' | b',' => self.advance_ascii(1),
+                b'\n' | b'\r' => self.advance_to(self.offset + 1),
+                // byte order mark
+                0xef if bytes[self.offset..].starts_with(&[0xef, 0xbb, 0xbf]) => {
+                    self.advance_to(self.offset + 3)
+                }
+                b'#' => {
+                    let end = bytes[self.offset..]
+                        .iter()
+                        .position(|&byte| byte == b'\n' || byte == b'\r')
+                        .map_or(bytes.len(), |len| self.offset + len);
+                    self.advance_to(end);
+                }
+                _ => break,
+            }
+        }
+    }
+
+    pub(super) fn next_token(&mut self) -> Result<Token<'a>> {
+        self.skip_ignored();
+
+        let pos = self.pos();
+        let Some(byte) = self.peek_byte(0) else {
+            return Ok(Token {
+                kind: TokenKind::Eof,
+                text: "",
+                pos,
+                end: pos,
+            });
+        };
+
+        self.tokens += 1;
+        self.options.check_tokens(self.tokens, pos)?;
+
+        let kind = match byte {
+            b'!' => TokenKind::Bang,
+            b'$' => TokenKind::Dollar,
+            b'&' => TokenKind::Amp,
+            b'(' => TokenKind::ParenL,
+            b')' => TokenKind::ParenR,
+            b':' => TokenKind::Colon,
+            b'=' => TokenKind::Equals,
+            b'@' => TokenKind::At,
+            b'[' => TokenKind::BracketL,
+            b']' => TokenKind::BracketR,
+            b'{' => TokenKind::BraceL,
+            b'|' => TokenKind::Pipe,
+            b'}' => TokenKind::BraceR,
+            b'.' if self.input[self.offset..].starts_with("...") => {
+                self.advance_ascii(3);
+                return Ok(self.token(TokenKind::Spread, pos));
+            }
+            b'_' | b'a'..=b'z' | b'A'..=b'Z' => {
+                let len = self.input.as_bytes()[self.offset..]
+                    .iter()
+                    .position(|byte| !is_name_continue(*byte))
+                    .unwrap_or(self.input.len() - self.offset);
+                self.advance_ascii(len);
+                return Ok(self.token(TokenKind::Name, pos));
+            }
+            b'-' | b'0'..=b'9' => {
+                let kind = self.lex_number(pos)?;
+                return Ok(self.token(kind, pos));
+            }
+            b'"' if self.input[self.offset..].starts_with("\"\"\"") => {
+                self.lex_block_string(pos)?;
+                return Ok(self.token(TokenKind::BlockString, pos));
+            }
+            b'"' => {
+                self.lex_string(pos)?;
+                return Ok(self.token(TokenKind::String, pos));
+            }
+            _ => return Err(self.unexpected_char(pos)),
+        };
+
+        self.advance_ascii(1);
+        Ok(self.token(kind, pos))
+    }
+
+    fn token(&self, kind: TokenKind, start: Pos) -> Token<'a> {
+        Token {
+            kind,
+            text: &self.input[start.offset..self.offset],
+            pos: start,
+            end: self.pos(),
+        }
+    }
+
+    fn unexpected_char(&self, pos: Pos) -> Error {
+        Error::Syntax {
+            message: match self.peek_char() {
+                Some(ch) => format!("unexpected character {:?}", ch),
+                None => "unexpected end of document".to_string(),
+            },
+            start: pos,
+            end: None,
+        }
+    }
+
+    fn lex_number(&mut self, start: Pos) -> Result<TokenKind> {
+        let mut kind = TokenKind::Int;
+
+        if self.peek_byte(0) == Some(b'-') {
+            self.advance_ascii(1);
+        }
+        match self.peek_byte(0) {
+            Some(b'0') => {
+                self.advance_ascii(1);
+                if self.peek_byte(0).is_some_and(|byte| byte.is_ascii_digit()) {
+                    return Err(self.invalid_number(start, "unexpected digit after 0"));
+                }
+            }
+            Some(b'1'..=b'9') => self.lex_digits(),
+            _ => return Err(self.invalid_number(start, "expected a digit")),
+        }
+
+        if self.peek_byte(0) == Some(b'.') {
+            kind = TokenKind::Float;
+            self.advance_ascii(1);
+            if !self.peek_byte(0).is_some_and(|byte| byte.is_ascii_digit()) {
+                return Err(self.invalid_number(start, "expected a digit after the decimal point"));
+            }
+            self.lex_digits();
+        }
+
+        if matches!(self.peek_byte(0), Some(b'e' | b'E')) {
+            kind = TokenKind::Float;
+            self.advance_ascii(1);
+            if matches!(self.peek_byte(0), Some(b'+' | b'-')) {
+                self.advance_ascii(1);
+            }
+            if !self.peek_byte(0).is_some_and(|byte| byte.is_ascii_digit()) {
+                return Err(self.invalid_number(start, "expected a digit in the exponent"));
+            }
+            self.lex_digits();
+        }
+
+        if self
+            .peek_byte(0)
+            .is_some_and(|byte| byte == b'.' || is_name_start(byte))
+        {
+            return Err(self.invalid_number(start, "unexpected character after the number"));
+        }
+
+        Ok(kind)
+    }
+
+    fn lex_digits(&mut self) {
+        while self.peek_byte(0).is_some_and(|byte| byte.is_ascii_digit()) {
+            self.advance_ascii(1);
+        }
+    }
+
+    fn invalid_number(&self, start: Pos, reason: &str) -> Error {
+        Error::Syntax {
+            message: format!("invalid number, {}", reason),
+            start,
+            end: None,
+        }
+    }
+
+    fn lex_string(&mut self, start: Pos) -> Result<()> {
+        let bytes = self.input.as_bytes();
+        let mut end = self.offset + 1;
+
+        loop {
+            match bytes.get(end) {
+                Some(b'"') => break,
+                None | Some(b'\n' | b'\r') => return Err(unterminated_string(start)),
+                Some(b'\\') => {
+                    let escape = end;
+                    let valid = match bytes.get(end + 1) {
+                        Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {
+                            end += 2;
+                            true
+                        }
+                        Some(b'u') => {
+                            let hex = self.input.get(end + 2..end + 6);
+                            end += 6;
+                            // `from_str_radix` would also accept a sign
+                            hex.filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .is_some_and(|code| char::from_u32(code).is_some())
+                        }
+                        _ => false,
+                    };
+                    if !valid {
+                        // strings are on a single line
+                        let column =
+                            start.column + self.input[start.offset..escape].chars().count();
+                        return Err(Error::Syntax {
+                            message: "invalid escape sequence in string".to_string(),
+                            start: Pos {
+                                line: start.line,
+                                column,
+                                offset: escape,
+                            },
+                            end: None,
+                        });
+                    }
+                }
+                Some(_) => end += 1,
+            }
+        }
+
+        self.advance_to(end + 1);
+        Ok(())
+    }
+
+    fn lex_block_string(&mut self, start: Pos) -> Result<()> {
+        let rest = &self.input[self.offset + 3..];
+        let mut len = 0;
+
+        loop {
+            let Some(idx) = rest[len..].find("\"\"\"") else {
+                return Err(unterminated_string(start));
+            };
+            len += idx;
+            if rest[..len].ends_with('\\') {
+                // escaped triple quote
+                len += 3;
+                continue;
+            }
+            break;
+        }
+
+        self.advance_to(self.offset + 3 + len + 3);
+        Ok(())
+    }
+}
+
+fn unterminated_string(start: Pos) -> Error {
+    Error::Syntax {
+        message: "unterminated string".to_string(),
+        start,
+        end: None,
+    }
+}
+
+fn is_name_start(byte: u8) -> bool {
+    byte == b'_' || byte.is_ascii_alphabetic()
+}
+
+fn is_name_continue(byte: u8) -> bool {
+    byte == b'_' || byte.is_ascii_alphanumeric()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(input: &str) -> Result<Vec<(TokenKind, &str)>> {
+        let mut lexer = Lexer::new(input, ParseOptions::new());
+        let mut tokens = Vec::new();
+        loop {
+            let token = lexer.next_token()?;
+            if token.kind == TokenKind::Eof {
+                return Ok(tokens);
+            }
+            tokens.push((token.kind, token.text));
+        }
+    }
+
+    fn error(input: &str) -> (String, usize, usize) {
+        match tokens(input).unwrap_err() {
+            Error::Syntax { message, start, .. } => (message, start.line, start.column),
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            tokens(r#"query A($a: Int = -1.5e+3) { a: b(s: "x, \"y\"", t: """z \""" """) ...F }"#)
+                .unwrap(),
+            vec![
+                (TokenKind::Name, "query"),
+                (TokenKind::Name, "A"),
+                (TokenKind::ParenL, "("),
+                (TokenKind::Dollar, "$"),
+                (TokenKind::Name, "a"),
+                (TokenKind::Colon, ":"),
+                (TokenKind::Name, "Int"),
+                (TokenKind::Equals, "="),
+                (TokenKind::Float, "-1.5e+3"),
+                (TokenKind::ParenR, ")"),
+                (TokenKind::BraceL, "{"),
+                (TokenKind::Name, "a"),
+                (TokenKind::Colon, ":"),
+                (TokenKind::Name, "b"),
+                (TokenKind::ParenL, "("),
+                (TokenKind::Name, "s"),
+                (TokenKind::Colon, ":"),
+                (TokenKind::String, r#""x, \"y\"""#),
+                (TokenKind::Name, "t"),
+                (TokenKind::Colon, ":"),
+                (TokenKind::BlockString, r#""""z \""" """"#),
+                (TokenKind::ParenR, ")"),
+                (TokenKind::Spread, "..."),
+                (TokenKind::Name, "F"),
+                (TokenKind::BraceR, "}"),
+            ]
+        );
+        assert_eq!(
+            tokens("\u{feff}# comment\r\n0 -0 12.5, 1E9 []").unwrap(),
+            vec![
+                (TokenKind::Int, "0"),
+                (TokenKind::Int, "-0"),
+                (TokenKind::Float, "12.5"),
+                (TokenKind::Float, "1E9"),
+                (TokenKind::BracketL, "["),
+                (TokenKind::BracketR, "]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_positions() {
+        let mut lexer = Lexer::new("{\n  \"é\" a\r\n  b }", ParseOptions::new());
+        let mut positions = Vec::new();
+        loop {
+            let token = lexer.next_token().unwrap();
+            positions.push((token.pos.line, token.pos.column, token.pos.offset));
+            if token.kind == TokenKind::Eof {
+                break;
+            }
+        }
+        assert_eq!(
+            positions,
+            vec![
+                (1, 1, 0),
+                (2, 3, 4),
+                (2, 7, 9),
+                (3, 3, 14),
+                (3, 5, 16),
+                (3, 6, 17)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_terminators() {
+        let mut lexer = Lexer::new("a\rb\r\nc\n\rd\r\re", ParseOptions::new());
+        let mut positions = Vec::new();
+        loop {
+            let token = lexer.next_token().unwrap();
+            if token.kind == TokenKind::Eof {
+                break;
+            }
+            positions.push((token.pos.line, token.pos.column));
+        }
+        assert_eq!(positions, vec![(1, 1), (2, 1), (3, 1), (5, 1), (7, 1)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            error("{ % }"),
+            ("unexpected character '%'".to_string(), 1, 3)
+        );
+        assert_eq!(
+            error("{ .. }"),
+            ("unexpected character '.'".to_string(), 1, 3)
+        );
+        assert_eq!(
+            error("[123abc]"),
+            (
+                "invalid number, unexpected character after the number".to_string(),
+                1,
+                2
+            )
+        );
+        assert_eq!(
+            error("[123.0123e7abc]"),
+            (
+                "invalid number, unexpected character after the number".to_string(),
+                1,
+                2
+            )
+        );
+        assert_eq!(
+            error("01"),
+            ("invalid number, unexpected digit after 0".to_string(), 1, 1)
+        );
+        assert_eq!(
+            error("1."),
+            (
+                "invalid number, expected a digit after the decimal point".to_string(),
+                1,
+                1
+            )
+        );
+        assert_eq!(
+            error("-a"),
+            ("invalid number, expected a digit".to_string(), 1, 1)
+        );
+        assert_eq!(
+            error("\n  \"abc"),
+            ("unterminated string".to_string(), 2, 3)
+        );
+        assert_eq!(
+            error("\"abc\ndef\""),
+            ("unterminated string".to_string(), 1, 1)
+        );
+        assert_eq!(
+            error("\"\"\"abc"),
+            ("unterminated string".to_string(), 1, 1)
+        );
+        assert_eq!(
+            error(r#""ab\x""#),
+            ("invalid escape sequence in string".to_string(), 1, 4)
+        );
+        assert_eq!(
+            error(r#""\uD800""#),
+            ("invalid escape sequence in string".to_string(), 1, 2)
+        );
+        for query in [
+            r#""\u+123""#,
+            r#""\u-123""#,
+            r#""\u12""#,
+            r#""\u""#,
+            r#""\u12"#,
+        ] {
+            assert_eq!(
+                error(query),
+                ("invalid escape sequence in string".to_string(), 1, 2),
+                "{query}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_lookahead_restrictions() {
+        assert!(tokens("[123 abc]").is_ok());
+        assert!(tokens("[123.0123 abc]").is_ok());
+        assert!(tokens("[123.0123e7 abc]").is_ok());
+        assert!(tokens("[123.0123e77 abc]").is_ok());
+
+        assert!(tokens("[123abc]").is_err());
+        assert!(tokens("[123.0123abc]").is_err());
+        assert!(tokens("[123.0123e7abc]").is_err());
+        assert!(tokens("[123.0123e77abc]").is_err());
+    }
+
+    #[test]
+    fn test_max_tokens() {
+        let input = r#"query A($a: Int = -1.5e+3) { a: b(s: "x, \"y\"", t: """z""") ...F } # c"#;
+        let mut lexer = Lexer::new(input, ParseOptions::new().max_tokens(25));
+        while lexer.next_token().unwrap().kind != TokenKind::Eof {}
+
+        let mut lexer = Lexer::new(input, ParseOptions::new().max_tokens(24));
+        let err = loop {
+            match lexer.next_token() {
+                Ok(_) => {}
+                Err(err) => break err,
+            }
+        };
+        assert_eq!(
+            err.positions().next().map(|pos| pos.offset),
+            Some(input.rfind('}').unwrap())
+        );
+    }
+}
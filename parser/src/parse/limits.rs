@@ -0,0 +1,114 @@
+use crate::{Error, ParseLimit, Pos, Result};
+
+/// Options for parsing documents.
+///
+/// The limits are checked while parsing, so that oversized documents are
+/// rejected before they are fully parsed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseOptions {
+    max_tokens: Option<usize>,
+    max_document_length: Option<usize>,
+    max_aliases: Option<usize>,
+    max_fragments: Option<usize>,
+    pub(super) spans: bool,
+}
+
+impl ParseOptions {
+    /// Create a `ParseOptions` without any limit
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the maximum number of tokens in a document
+    #[inline]
+    #[must_use]
+    pub fn max_tokens(self, max_tokens: usize) -> Self {
+        Self {
+            max_tokens: Some(max_tokens),
+            ..self
+        }
+    }
+
+    /// Set the maximum length of a document, in bytes
+    #[inline]
+    #[must_use]
+    pub fn max_document_length(self, max_document_length: usize) -> Self {
+        Self {
+            max_document_length: Some(max_document_length),
+            ..self
+        }
+    }
+
+    /// Set the maximum number of aliased fields in a selection set
+    #[inline]
+    #[must_use]
+    pub fn max_aliases(self, max_aliases: usize) -> Self {
+        Self {
+            max_aliases: Some(max_aliases),
+            ..self
+        }
+    }
+
+    /// Set the maximum number of fragment definitions in a document
+    #[inline]
+    #[must_use]
+    pub fn max_fragments(self, max_fragments: usize) -> Self {
+        Self {
+            max_fragments: Some(max_fragments),
+            ..self
+        }
+    }
+
+    /// Record the end position of every node, see [`Positioned::end`](crate::Positioned::end)
+    #[inline]
+    #[must_use]
+    pub fn spans(self, spans: bool) -> Self {
+        Self { spans, ..self }
+    }
+
+    pub(super) fn check_document(&self, input: &str) -> Result<()> {
+        match self.max_document_length {
+            Some(max) if input.len() > max => Err(Error::LimitExceeded {
+                limit: ParseLimit::DocumentLength,
+                max,
+                pos: None,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn check_tokens(&self, count: usize, pos: Pos) -> Result<()> {
+        match self.max_tokens {
+            Some(max) if count > max => Err(Error::LimitExceeded {
+                limit: ParseLimit::Tokens,
+                max,
+                pos: Some(pos),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn check_aliases(&self, count: usize, pos: Pos) -> Result<()> {
+        match self.max_aliases {
+            Some(max) if count > max => Err(Error::LimitExceeded {
+                limit: ParseLimit::Aliases,
+                max,
+                pos: Some(pos),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn check_fragments(&self, count: usize, pos: Pos) -> Result<()> {
+        match self.max_fragments {
+            Some(max) if count > max => Err(Error::LimitExceeded {
+                limit: ParseLimit::Fragments,
+                max,
+                pos: Some(pos),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
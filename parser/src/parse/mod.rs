@@ -2,343 +2,423 @@
 //!
 //! This module's structure mirrors `types`.
 
-use std::collections::{HashMap, hash_map};
-
-use pest::{
-    Parser,
-    iterators::{Pair, Pairs},
+use std::{
+    collections::{HashMap, hash_map},
+    fmt::Display,
 };
+
+use lexer::{Lexer, Token, TokenKind};
 use utils::*;
 
 use crate::{
     Error, Result,
-    pos::{PositionCalculator, Positioned},
+    pos::{Pos, Positioned},
     types::*,
 };
 
 mod executable;
-#[allow(clippy::redundant_static_lifetimes)]
-#[rustfmt::skip]
-#[allow(dead_code)]
-mod generated;
+mod lexer;
+mod limits;
 mod service;
 mod utils;
 
-use async_graphql_value::{ConstValue, Name, Number, Value};
-pub use executable::parse_query;
-use generated::Rule;
-pub use service::parse_schema;
+use async_graphql_value::{ConstValue, Name, Value};
+pub use executable::{parse_query, parse_query_with_options};
+pub use limits::ParseOptions;
+pub use service::{parse_schema, parse_schema_with_options};
 
-struct GraphQLParser;
+const MAX_RECURSION_DEPTH: usize = 64;
 
-fn parse_operation_type(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<OperationType>> {
-    debug_assert_eq!(pair.as_rule(), Rule::operation_type);
+macro_rules! recursion_depth {
+    ($remaining_depth:ident) => {{
+        if $remaining_depth == 0 {
+            return Err(Error::RecursionLimitExceeded);
+        }
+        $remaining_depth - 1
+    }};
+}
+use recursion_depth;
+
+/// A recursive descent parser, reading one token ahead.
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    token: Token<'a>,
+    /// The position right after the last consumed token.
+    prev_end: Pos,
+    options: ParseOptions,
+}
 
-    let pos = pc.step(&pair);
+impl<'a> Parser<'a> {
+    fn new(input: &'a str, options: ParseOptions) -> Result<Self> {
+        options.check_document(input)?;
+
+        let mut lexer = Lexer::new(input, options);
+        let token = lexer.next_token()?;
+        Ok(Self {
+            lexer,
+            token,
+            prev_end: Pos::default(),
+            options,
+        })
+    }
 
-    Ok(Positioned::new(
-        match pair.as_str() {
-            "query" => OperationType::Query,
-            "mutation" => OperationType::Mutation,
-            "subscription" => OperationType::Subscription,
-            _ => unreachable!(),
-        },
-        pos,
-    ))
-}
+    /// Returns the current token and reads the next one.
+    fn bump(&mut self) -> Result<Token<'a>> {
+        let next = self.lexer.next_token()?;
+        self.prev_end = self.token.end;
+        Ok(std::mem::replace(&mut self.token, next))
+    }
 
-fn parse_default_value(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<ConstValue>> {
-    debug_assert_eq!(pair.as_rule(), Rule::default_value);
+    /// Creates a node starting at `pos` and ending with the last consumed
+    /// token.
+    fn positioned<T>(&self, node: T, pos: Pos) -> Positioned<T> {
+        let mut positioned = Positioned::new(node, pos);
+        if self.options.spans {
+            positioned.end = Some(self.prev_end);
+        }
+        positioned
+    }
 
-    parse_const_value(exactly_one(pair.into_inner()), pc)
-}
+    /// Returns the token following the current one.
+    fn peek(&self) -> Result<Token<'a>> {
+        self.lexer.clone().next_token()
+    }
 
-fn parse_type(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Type>> {
-    debug_assert_eq!(pair.as_rule(), Rule::type_);
+    fn is(&self, kind: TokenKind) -> bool {
+        self.token.kind == kind
+    }
 
-    Ok(Positioned::new(
-        Type::new(pair.as_str()).unwrap(),
-        pc.step(&pair),
-    ))
-}
+    fn eat(&mut self, kind: TokenKind) -> Result<bool> {
+        if self.is(kind) {
+            self.bump()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
 
-fn parse_const_value(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<ConstValue>> {
-    debug_assert_eq!(pair.as_rule(), Rule::const_value);
-
-    let pos = pc.step(&pair);
-    let pair = exactly_one(pair.into_inner());
-
-    Ok(Positioned::new(
-        match pair.as_rule() {
-            Rule::number => ConstValue::Number(parse_number(pair, pc)?.node),
-            Rule::string => ConstValue::String(parse_string(pair, pc)?.node),
-            Rule::boolean => ConstValue::Boolean(parse_boolean(pair, pc)?.node),
-            Rule::null => ConstValue::Null,
-            Rule::enum_value => ConstValue::Enum(parse_enum_value(pair, pc)?.node),
-            Rule::const_list => ConstValue::List(
-                pair.into_inner()
-                    .map(|pair| Ok(parse_const_value(pair, pc)?.node))
-                    .collect::<Result<_>>()?,
-            ),
-            Rule::const_object => ConstValue::Object(
-                pair.into_inner()
-                    .map(|pair| {
-                        debug_assert_eq!(pair.as_rule(), Rule::const_object_field);
+    fn eat_keyword(&mut self, keyword: &str) -> Result<bool> {
+        if self.token.is_keyword(keyword) {
+            self.bump()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
 
-                        let mut pairs = pair.into_inner();
+    fn expect(&mut self, kind: TokenKind) -> Result<Token<'a>> {
+        if self.is(kind) {
+            self.bump()
+        } else {
+            self.unexpected(kind)
+        }
+    }
 
-                        let name = parse_name(pairs.next().unwrap(), pc)?;
-                        let value = parse_const_value(pairs.next().unwrap(), pc)?;
+    fn expect_keyword(&mut self, keyword: &str) -> Result<Token<'a>> {
+        if self.token.is_keyword(keyword) {
+            self.bump()
+        } else {
+            self.unexpected(format_args!("\"{}\"", keyword))
+        }
+    }
 
-                        debug_assert_eq!(pairs.next(), None);
+    fn unexpected<T>(&self, expected: impl Display) -> Result<T> {
+        Err(Error::Syntax {
+            message: format!("expected {}, found {}", expected, self.token),
+            start: self.token.pos,
+            end: None,
+        })
+    }
 
-                        Ok((name.node, value.node))
-                    })
-                    .collect::<Result<_>>()?,
-            ),
-            _ => unreachable!(),
-        },
-        pos,
-    ))
-}
-fn parse_value(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Value>> {
-    debug_assert_eq!(pair.as_rule(), Rule::value);
-
-    let pos = pc.step(&pair);
-    let pair = exactly_one(pair.into_inner());
-
-    Ok(Positioned::new(
-        match pair.as_rule() {
-            Rule::variable => Value::Variable(parse_variable(pair, pc)?.node),
-            Rule::number => Value::Number(parse_number(pair, pc)?.node),
-            Rule::string => Value::String(parse_string(pair, pc)?.node),
-            Rule::boolean => Value::Boolean(parse_boolean(pair, pc)?.node),
-            Rule::null => Value::Null,
-            Rule::enum_value => Value::Enum(parse_enum_value(pair, pc)?.node),
-            Rule::list => Value::List(
-                pair.into_inner()
-                    .map(|pair| Ok(parse_value(pair, pc)?.node))
-                    .collect::<Result<_>>()?,
-            ),
-            Rule::object => Value::Object(
-                pair.into_inner()
-                    .map(|pair| {
-                        debug_assert_eq!(pair.as_rule(), Rule::object_field);
-                        let mut pairs = pair.into_inner();
+    /// Parses items until the closing token, requiring at least one item.
+    fn many<T>(
+        &mut self,
+        open: TokenKind,
+        close: TokenKind,
+        mut f: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        self.expect(open)?;
+        let mut items = Vec::new();
+        loop {
+            items.push(f(self)?);
+            if self.eat(close)? {
+                return Ok(items);
+            }
+        }
+    }
 
-                        let name = parse_name(pairs.next().unwrap(), pc)?;
-                        let value = parse_value(pairs.next().unwrap(), pc)?;
+    /// Parses items until the closing token.
+    fn any<T>(
+        &mut self,
+        open: TokenKind,
+        close: TokenKind,
+        mut f: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        self.expect(open)?;
+        let mut items = Vec::new();
+        while !self.eat(close)? {
+            items.push(f(self)?);
+        }
+        Ok(items)
+    }
 
-                        debug_assert_eq!(pairs.next(), None);
+    fn parse_operation_type(&mut self) -> Result<Positioned<OperationType>> {
+        let ty = match self.token.text {
+            "query" => OperationType::Query,
+            "mutation" => OperationType::Mutation,
+            "subscription" => OperationType::Subscription,
+            _ => return self.unexpected("\"query\", \"mutation\" or \"subscription\""),
+        };
+        let pos = self.bump()?.pos;
+        Ok(self.positioned(ty, pos))
+    }
 
-                        Ok((name.node, value.node))
-                    })
-                    .collect::<Result<_>>()?,
+    fn parse_default_value(&mut self) -> Result<Option<Positioned<ConstValue>>> {
+        if self.eat(TokenKind::Equals)? {
+            self.parse_const_value(MAX_RECURSION_DEPTH).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_type(&mut self) -> Result<Positioned<Type>> {
+        let pos = self.token.pos;
+        let ty = self.parse_type_inner(MAX_RECURSION_DEPTH)?;
+        Ok(self.positioned(ty, pos))
+    }
+
+    fn parse_type_inner(&mut self, remaining_depth: usize) -> Result<Type> {
+        let base = if self.eat(TokenKind::BracketL)? {
+            let ty = self.parse_type_inner(recursion_depth!(remaining_depth))?;
+            self.expect(TokenKind::BracketR)?;
+            BaseType::List(Box::new(ty))
+        } else {
+            BaseType::Named(self.parse_name()?.node)
+        };
+
+        Ok(Type {
+            base,
+            nullable: !self.eat(TokenKind::Bang)?,
+        })
+    }
+
+    fn parse_const_value(&mut self, remaining_depth: usize) -> Result<Positioned<ConstValue>> {
+        let pos = self.token.pos;
+
+        let value = match self.token.kind {
+            TokenKind::BracketL => {
+                ConstValue::List(self.any(TokenKind::BracketL, TokenKind::BracketR, |p| {
+                    Ok(p.parse_const_value(recursion_depth!(remaining_depth))?.node)
+                })?)
+            }
+            TokenKind::BraceL => ConstValue::Object(
+                self.any(TokenKind::BraceL, TokenKind::BraceR, |p| {
+                    let name = p.parse_name()?;
+                    p.expect(TokenKind::Colon)?;
+                    let value = p.parse_const_value(recursion_depth!(remaining_depth))?;
+                    Ok((name.node, value.node))
+                })?
+                .into_iter()
+                .collect(),
             ),
-            _ => unreachable!(),
-        },
-        pos,
-    ))
-}
+            _ => self.parse_scalar()?,
+        };
+        Ok(self.positioned(value, pos))
+    }
 
-fn parse_variable(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Name>> {
-    debug_assert_eq!(pair.as_rule(), Rule::variable);
-    parse_name(exactly_one(pair.into_inner()), pc)
-}
-fn parse_number(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Number>> {
-    debug_assert_eq!(pair.as_rule(), Rule::number);
-    let pos = pc.step(&pair);
-    Ok(Positioned::new(
-        pair.as_str().parse().map_err(|err| Error::Syntax {
-            message: format!("invalid number: {}", err),
-            start: pos,
-            end: None,
-        })?,
-        pos,
-    ))
-}
-fn parse_string(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<String>> {
-    debug_assert_eq!(pair.as_rule(), Rule::string);
-    let pos = pc.step(&pair);
-    let pair = exactly_one(pair.into_inner());
-    Ok(Positioned::new(
-        match pair.as_rule() {
-            Rule::block_string_content => block_string_value(pair.as_str()),
-            Rule::string_content => string_value(pair.as_str()),
-            _ => unreachable!(),
-        },
-        pos,
-    ))
-}
-fn parse_boolean(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<bool>> {
-    debug_assert_eq!(pair.as_rule(), Rule::boolean);
-    let pos = pc.step(&pair);
-    Ok(Positioned::new(
-        match pair.as_str() {
-            "true" => true,
-            "false" => false,
-            _ => unreachable!(),
-        },
-        pos,
-    ))
-}
-fn parse_enum_value(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Name>> {
-    debug_assert_eq!(pair.as_rule(), Rule::enum_value);
-    parse_name(exactly_one(pair.into_inner()), pc)
-}
+    fn parse_value(&mut self, remaining_depth: usize) -> Result<Positioned<Value>> {
+        let pos = self.token.pos;
+
+        let value = match self.token.kind {
+            TokenKind::Dollar => Value::Variable(self.parse_variable()?.node),
+            TokenKind::BracketL => {
+                Value::List(self.any(TokenKind::BracketL, TokenKind::BracketR, |p| {
+                    Ok(p.parse_value(recursion_depth!(remaining_depth))?.node)
+                })?)
+            }
+            TokenKind::BraceL => Value::Object(
+                self.any(TokenKind::BraceL, TokenKind::BraceR, |p| {
+                    let name = p.parse_name()?;
+                    p.expect(TokenKind::Colon)?;
+                    let value = p.parse_value(recursion_depth!(remaining_depth))?;
+                    Ok((name.node, value.node))
+                })?
+                .into_iter()
+                .collect(),
+            ),
+            _ => self.parse_scalar()?.into_value(),
+        };
+        Ok(self.positioned(value, pos))
+    }
 
-fn parse_opt_const_directives(
-    pairs: &mut Pairs<'_, Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<Positioned<ConstDirective>>> {
-    Ok(parse_if_rule(pairs, Rule::const_directives, |pair| {
-        parse_const_directives(pair, pc)
-    })?
-    .unwrap_or_default())
-}
-fn parse_opt_directives(
-    pairs: &mut Pairs<'_, Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<Positioned<Directive>>> {
-    Ok(
-        parse_if_rule(pairs, Rule::directives, |pair| parse_directives(pair, pc))?
-            .unwrap_or_default(),
-    )
-}
-fn parse_const_directives(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<Positioned<ConstDirective>>> {
-    debug_assert_eq!(pair.as_rule(), Rule::const_directives);
-
-    pair.into_inner()
-        .map(|pair| parse_const_directive(pair, pc))
-        .collect()
-}
-fn parse_directives(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<Positioned<Directive>>> {
-    debug_assert_eq!(pair.as_rule(), Rule::directives);
-
-    pair.into_inner()
-        .map(|pair| parse_directive(pair, pc))
-        .collect()
-}
+    /// Parses a value which is neither a variable, a list nor an object.
+    fn parse_scalar(&mut self) -> Result<ConstValue> {
+        Ok(match self.token.kind {
+            TokenKind::Int | TokenKind::Float => {
+                let token = self.bump()?;
+                ConstValue::Number(token.text.parse().map_err(|err| Error::Syntax {
+                    message: format!("invalid number: {}", err),
+                    start: token.pos,
+                    end: None,
+                })?)
+            }
+            TokenKind::String | TokenKind::BlockString => {
+                ConstValue::String(self.parse_string()?.node)
+            }
+            TokenKind::Name => match self.bump()?.text {
+                "true" => ConstValue::Boolean(true),
+                "false" => ConstValue::Boolean(false),
+                "null" => ConstValue::Null,
+                name => ConstValue::Enum(Name::new(name)),
+            },
+            _ => return self.unexpected("value"),
+        })
+    }
 
-fn parse_const_directive(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<ConstDirective>> {
-    debug_assert_eq!(pair.as_rule(), Rule::const_directive);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
-
-    let name = parse_name(pairs.next().unwrap(), pc)?;
-    let arguments = parse_if_rule(&mut pairs, Rule::const_arguments, |pair| {
-        parse_const_arguments(pair, pc)
-    })?;
-
-    debug_assert_eq!(pairs.next(), None);
-
-    Ok(Positioned::new(
-        ConstDirective {
-            name,
-            arguments: arguments.unwrap_or_default(),
-        },
-        pos,
-    ))
-}
-fn parse_directive(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Directive>> {
-    debug_assert_eq!(pair.as_rule(), Rule::directive);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
-
-    let name = parse_name(pairs.next().unwrap(), pc)?;
-    let arguments = parse_if_rule(&mut pairs, Rule::arguments, |pair| {
-        parse_arguments(pair, pc)
-    })?;
-
-    debug_assert_eq!(pairs.next(), None);
-
-    Ok(Positioned::new(
-        Directive {
-            name,
-            arguments: arguments.unwrap_or_default(),
-        },
-        pos,
-    ))
-}
+    fn parse_variable(&mut self) -> Result<Positioned<Name>> {
+        self.expect(TokenKind::Dollar)?;
+        self.parse_name()
+    }
 
-fn parse_const_arguments(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<(Positioned<Name>, Positioned<ConstValue>)>> {
-    debug_assert_eq!(pair.as_rule(), Rule::const_arguments);
-    pair.into_inner()
-        .map(|pair| {
-            debug_assert_eq!(pair.as_rule(), Rule::const_argument);
-            let mut pairs = pair.into_inner();
+    fn parse_string(&mut self) -> Result<Positioned<String>> {
+        let value = match self.token.kind {
+            TokenKind::String => string_value(
+                &self.token.text[1..self.token.text.len() - 1],
+                self.token.pos,
+            )?,
+            TokenKind::BlockString => {
+                block_string_value(&self.token.text[3..self.token.text.len() - 3])
+            }
+            _ => return self.unexpected(TokenKind::String),
+        };
+        let pos = self.bump()?.pos;
+        Ok(self.positioned(value, pos))
+    }
 
-            let name = parse_name(pairs.next().unwrap(), pc)?;
-            let value = parse_const_value(pairs.next().unwrap(), pc)?;
+    fn parse_description(&mut self) -> Result<Option<Positioned<String>>> {
+        if matches!(self.token.kind, TokenKind::String | TokenKind::BlockString) {
+            self.parse_string().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 
-            debug_assert_eq!(pairs.next(), None);
+    fn parse_const_directives(&mut self) -> Result<Vec<Positioned<ConstDirective>>> {
+        let mut directives = Vec::new();
+        while self.is(TokenKind::At) {
+            let pos = self.bump()?.pos;
+            let name = self.parse_name()?;
+            let arguments = if self.is(TokenKind::ParenL) {
+                self.parse_const_arguments()?
+            } else {
+                Vec::new()
+            };
+            directives.push(self.positioned(ConstDirective { name, arguments }, pos));
+        }
+        Ok(directives)
+    }
 
-            Ok((name, value))
+    fn parse_directives(&mut self) -> Result<Vec<Positioned<Directive>>> {
+        let mut directives = Vec::new();
+        while self.is(TokenKind::At) {
+            let pos = self.bump()?.pos;
+            let name = self.parse_name()?;
+            let arguments = if self.is(TokenKind::ParenL) {
+                self.parse_arguments()?
+            } else {
+                Vec::new()
+            };
+            directives.push(self.positioned(Directive { name, arguments }, pos));
+        }
+        Ok(directives)
+    }
+
+    fn parse_const_arguments(&mut self) -> Result<Vec<(Positioned<Name>, Positioned<ConstValue>)>> {
+        self.many(TokenKind::ParenL, TokenKind::ParenR, |p| {
+            let name = p.parse_name()?;
+            p.expect(TokenKind::Colon)?;
+            Ok((name, p.parse_const_value(MAX_RECURSION_DEPTH)?))
         })
-        .collect()
-}
-fn parse_arguments(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<(Positioned<Name>, Positioned<Value>)>> {
-    debug_assert_eq!(pair.as_rule(), Rule::arguments);
-    pair.into_inner()
-        .map(|pair| {
-            debug_assert_eq!(pair.as_rule(), Rule::argument);
-            let mut pairs = pair.into_inner();
-
-            let name = parse_name(pairs.next().unwrap(), pc)?;
-            let value = parse_value(pairs.next().unwrap(), pc)?;
-
-            debug_assert_eq!(pairs.next(), None);
-
-            Ok((name, value))
+    }
+
+    fn parse_arguments(&mut self) -> Result<Vec<(Positioned<Name>, Positioned<Value>)>> {
+        self.many(TokenKind::ParenL, TokenKind::ParenR, |p| {
+            let name = p.parse_name()?;
+            p.expect(TokenKind::Colon)?;
+            Ok((name, p.parse_value(MAX_RECURSION_DEPTH)?))
         })
-        .collect()
-}
+    }
 
-fn parse_name(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Positioned<Name>> {
-    debug_assert_eq!(pair.as_rule(), Rule::name);
-    Ok(Positioned::new(Name::new(pair.as_str()), pc.step(&pair)))
+    fn parse_name(&mut self) -> Result<Positioned<Name>> {
+        let token = self.expect(TokenKind::Name)?;
+        Ok(self.positioned(Name::new(token.text), token.pos))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn syntax_error(result: Result<ExecutableDocument>) -> (String, usize, usize) {
+        match result.unwrap_err() {
+            Error::Syntax { message, start, .. } => (message, start.line, start.column),
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        assert_eq!(
+            syntax_error(parse_query("{ a(x: ) }")),
+            ("expected value, found \")\"".to_string(), 1, 8)
+        );
+        assert_eq!(
+            syntax_error(parse_query("query { a }\nfragment F T { b }")),
+            ("expected \"on\", found Name \"T\"".to_string(), 2, 12)
+        );
+        assert_eq!(
+            syntax_error(parse_query("{ a(x: [1 2) }")),
+            ("expected value, found \")\"".to_string(), 1, 12)
+        );
+        assert_eq!(
+            syntax_error(parse_query("{ a(x: \"s\" y: 1 }")),
+            ("expected Name, found \"}\"".to_string(), 1, 17)
+        );
+        assert_eq!(
+            syntax_error(parse_query("{ a }}")),
+            (
+                "expected \"{\", \"query\", \"mutation\", \"subscription\" or \"fragment\", found \"}\""
+                    .to_string(),
+                1,
+                6
+            )
+        );
+        assert_eq!(
+            syntax_error(parse_query("query ($a: [Int) { a }")),
+            ("expected \"]\", found \")\"".to_string(), 1, 16)
+        );
+        assert_eq!(
+            syntax_error(parse_query("{ a(x: \"ab\\q\") }")),
+            ("invalid escape sequence in string".to_string(), 1, 11)
+        );
+        assert_eq!(
+            syntax_error(parse_query("")),
+            (
+                "expected \"{\", \"query\", \"mutation\", \"subscription\" or \"fragment\", found <EOF>"
+                    .to_string(),
+                1,
+                1
+            )
+        );
+    }
+
     #[test]
-    fn test_number_lookahead_restrictions() {
-        GraphQLParser::parse(Rule::const_list, "[123 abc]").unwrap();
-        GraphQLParser::parse(Rule::const_list, "[123.0123 abc]").unwrap();
-        GraphQLParser::parse(Rule::const_list, "[123.0123e7 abc]").unwrap();
-        GraphQLParser::parse(Rule::const_list, "[123.0123e77 abc]").unwrap();
-
-        assert!(GraphQLParser::parse(Rule::const_list, "[123abc]").is_err());
-        assert!(GraphQLParser::parse(Rule::const_list, "[123.0123abc]").is_err());
-        assert!(GraphQLParser::parse(Rule::const_list, "[123.0123e7abc]").is_err());
-        assert!(GraphQLParser::parse(Rule::const_list, "[123.0123e77abc]").is_err());
+    fn test_value_recursion_limit() {
+        let depth = MAX_RECURSION_DEPTH * 2;
+        let query = format!("{{ a(x: {}{}) }}", "[".repeat(depth), "]".repeat(depth));
+        assert_eq!(
+            parse_query(query).unwrap_err(),
+            Error::RecursionLimitExceeded
+        );
     }
 }
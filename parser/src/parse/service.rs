@@ -6,401 +6,360 @@ use super::*;
 ///
 /// Fails if the schema is not a valid GraphQL document.
 pub fn parse_schema<T: AsRef<str>>(input: T) -> Result<ServiceDocument> {
-    let mut pc = PositionCalculator::new(input.as_ref());
-    Ok(parse_service_document(
-        exactly_one(GraphQLParser::parse(
-            Rule::service_document,
-            input.as_ref(),
-        )?),
-        &mut pc,
-    )?)
+    parse_schema_with_options(input, ParseOptions::default())
 }
 
-fn parse_service_document(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
+/// Parse a GraphQL schema document with options, such as recording the
+/// [spans](ParseOptions::spans) of the nodes.
+///
+/// # Errors
+///
+/// Fails if the schema is not a valid GraphQL document, or if it exceeds one of
+/// the limits.
+pub fn parse_schema_with_options<T: AsRef<str>>(
+    input: T,
+    options: ParseOptions,
 ) -> Result<ServiceDocument> {
-    debug_assert_eq!(pair.as_rule(), Rule::service_document);
-
-    Ok(ServiceDocument {
-        definitions: pair
-            .into_inner()
-            .filter(|pair| pair.as_rule() != Rule::EOI)
-            .map(|pair| parse_type_system_definition(pair, pc))
-            .collect::<Result<_>>()?,
-    })
-}
+    let mut parser = Parser::new(input.as_ref(), options)?;
 
-fn parse_type_system_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<TypeSystemDefinition> {
-    debug_assert_eq!(pair.as_rule(), Rule::type_system_definition);
-
-    let pair = exactly_one(pair.into_inner());
-    Ok(match pair.as_rule() {
-        Rule::schema_definition => TypeSystemDefinition::Schema(parse_schema_definition(pair, pc)?),
-        Rule::type_definition => TypeSystemDefinition::Type(parse_type_definition(pair, pc)?),
-        Rule::directive_definition => {
-            TypeSystemDefinition::Directive(parse_directive_definition(pair, pc)?)
+    let mut definitions = Vec::new();
+    loop {
+        definitions.push(parser.parse_type_system_definition()?);
+        if parser.is(TokenKind::Eof) {
+            return Ok(ServiceDocument { definitions });
         }
-        _ => unreachable!(),
-    })
+    }
 }
 
-fn parse_schema_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<SchemaDefinition>> {
-    debug_assert_eq!(pair.as_rule(), Rule::schema_definition);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
-
-    let extend = next_if_rule(&mut pairs, Rule::extend).is_some();
-    let directives = parse_opt_const_directives(&mut pairs, pc)?;
-
-    let mut query = None;
-    let mut mutation = None;
-    let mut subscription = None;
-
-    for pair in pairs {
-        debug_assert_eq!(pair.as_rule(), Rule::operation_type_definition);
+impl Parser<'_> {
+    fn parse_type_system_definition(&mut self) -> Result<TypeSystemDefinition> {
+        let pos = self.token.pos;
+        let description = self.parse_description()?;
+        let extend = description.is_none() && self.eat_keyword("extend")?;
 
-        let mut pairs = pair.into_inner();
-
-        let operation_type = parse_operation_type(pairs.next().unwrap(), pc)?;
-        let name = parse_name(pairs.next().unwrap(), pc)?;
-
-        match operation_type.node {
-            OperationType::Query if query.is_none() => query = Some(name),
-            OperationType::Mutation if mutation.is_none() => mutation = Some(name),
-            OperationType::Subscription if subscription.is_none() => subscription = Some(name),
+        Ok(match self.token.text {
+            "schema" if extend || description.is_none() => {
+                TypeSystemDefinition::Schema(self.parse_schema_definition(pos, extend)?)
+            }
+            "scalar" | "type" | "interface" | "union" | "enum" | "input" => {
+                TypeSystemDefinition::Type(self.parse_type_definition(pos, description, extend)?)
+            }
+            "directive" if !extend => {
+                TypeSystemDefinition::Directive(self.parse_directive_definition(pos, description)?)
+            }
+            _ if extend => {
+                return self.unexpected(
+                    "\"schema\", \"scalar\", \"type\", \"interface\", \"union\", \"enum\" or \"input\"",
+                );
+            }
+            _ if description.is_some() => {
+                return self.unexpected(
+                    "\"scalar\", \"type\", \"interface\", \"union\", \"enum\", \"input\" or \"directive\"",
+                );
+            }
             _ => {
-                return Err(Error::MultipleRoots {
-                    root: operation_type.node,
-                    schema: pos,
-                    pos: operation_type.pos,
-                });
+                return self.unexpected(
+                    "\"schema\", \"extend\", \"scalar\", \"type\", \"interface\", \"union\", \"enum\", \"input\", \"directive\" or a description",
+                );
             }
-        }
-
-        debug_assert_eq!(pairs.next(), None);
+        })
     }
 
-    if !extend && query.is_none() {
-        return Err(Error::MissingQueryRoot { pos });
-    }
-
-    Ok(Positioned::new(
-        SchemaDefinition {
-            extend,
-            directives,
-            query,
-            mutation,
-            subscription,
-        },
-        pos,
-    ))
-}
+    fn parse_schema_definition(
+        &mut self,
+        pos: Pos,
+        extend: bool,
+    ) -> Result<Positioned<SchemaDefinition>> {
+        self.expect_keyword("schema")?;
+        let directives = self.parse_const_directives()?;
+
+        let mut query = None;
+        let mut mutation = None;
+        let mut subscription = None;
+
+        if !extend || directives.is_empty() || self.is(TokenKind::BraceL) {
+            let operation_types = self.many(TokenKind::BraceL, TokenKind::BraceR, |p| {
+                let operation_type = p.parse_operation_type()?;
+                p.expect(TokenKind::Colon)?;
+                Ok((operation_type, p.parse_name()?))
+            })?;
 
-fn parse_type_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<TypeDefinition>> {
-    debug_assert_eq!(pair.as_rule(), Rule::type_definition);
-
-    let pos = pc.step(&pair);
-    let pair = exactly_one(pair.into_inner());
-    let rule = pair.as_rule();
-    let mut pairs = pair.into_inner();
-
-    let description = parse_if_rule(&mut pairs, Rule::string, |pair| parse_string(pair, pc))?;
-    let extend = next_if_rule(&mut pairs, Rule::extend).is_some();
-    let name = parse_name(pairs.next().unwrap(), pc)?;
-
-    let (directives, kind) = match rule {
-        Rule::scalar_type => {
-            let directives = parse_opt_const_directives(&mut pairs, pc)?;
-            (directives, TypeKind::Scalar)
+            for (operation_type, name) in operation_types {
+                match operation_type.node {
+                    OperationType::Query if query.is_none() => query = Some(name),
+                    OperationType::Mutation if mutation.is_none() => mutation = Some(name),
+                    OperationType::Subscription if subscription.is_none() => {
+                        subscription = Some(name)
+                    }
+                    _ => {
+                        return Err(Error::MultipleRoots {
+                            root: operation_type.node,
+                            schema: pos,
+                            pos: operation_type.pos,
+                        });
+                    }
+                }
+            }
         }
-        Rule::object_type => {
-            let implements = parse_if_rule(&mut pairs, Rule::implements_interfaces, |pair| {
-                debug_assert_eq!(pair.as_rule(), Rule::implements_interfaces);
 
-                pair.into_inner()
-                    .map(|pair| parse_name(pair, pc))
-                    .collect::<Result<_>>()
-            })?;
-
-            let directives = parse_opt_const_directives(&mut pairs, pc)?;
+        if !extend && query.is_none() {
+            return Err(Error::MissingQueryRoot { pos });
+        }
 
-            let fields = parse_if_rule(&mut pairs, Rule::fields_definition, |pair| {
-                parse_fields_definition(pair, pc)
-            })?
-            .unwrap_or_default();
+        Ok(self.positioned(
+            SchemaDefinition {
+                extend,
+                directives,
+                query,
+                mutation,
+                subscription,
+            },
+            pos,
+        ))
+    }
 
-            (
+    fn parse_type_definition(
+        &mut self,
+        pos: Pos,
+        description: Option<Positioned<String>>,
+        extend: bool,
+    ) -> Result<Positioned<TypeDefinition>> {
+        let keyword = self.bump()?.text;
+        let name = self.parse_name()?;
+
+        let (directives, kind) = match keyword {
+            "scalar" => {
+                let directives = self.parse_const_directives()?;
+                if extend && directives.is_empty() {
+                    return self.unexpected(TokenKind::At);
+                }
+                (directives, TypeKind::Scalar)
+            }
+            "type" | "interface" => {
+                let implements = self.parse_implements_interfaces()?;
+                let directives = self.parse_const_directives()?;
+                let fields = if self.is(TokenKind::BraceL) {
+                    self.many(TokenKind::BraceL, TokenKind::BraceR, |p| {
+                        p.parse_field_definition()
+                    })?
+                } else if extend && implements.is_empty() && directives.is_empty() {
+                    return self.unexpected("\"implements\", \"@\" or \"{\"");
+                } else {
+                    Vec::new()
+                };
+
+                (
+                    directives,
+                    if keyword == "type" {
+                        TypeKind::Object(ObjectType { implements, fields })
+                    } else {
+                        TypeKind::Interface(InterfaceType { implements, fields })
+                    },
+                )
+            }
+            "union" => {
+                let directives = self.parse_const_directives()?;
+                let mut members = Vec::new();
+                if self.eat(TokenKind::Equals)? {
+                    self.eat(TokenKind::Pipe)?;
+                    loop {
+                        members.push(self.parse_name()?);
+                        if !self.eat(TokenKind::Pipe)? {
+                            break;
+                        }
+                    }
+                } else if extend && directives.is_empty() {
+                    return self.unexpected("\"@\" or \"=\"");
+                }
+                (directives, TypeKind::Union(UnionType { members }))
+            }
+            "enum" => {
+                let directives = self.parse_const_directives()?;
+                let values = if self.is(TokenKind::BraceL) {
+                    self.many(TokenKind::BraceL, TokenKind::BraceR, |p| {
+                        p.parse_enum_value_definition()
+                    })?
+                } else if extend && directives.is_empty() {
+                    return self.unexpected("\"@\" or \"{\"");
+                } else {
+                    Vec::new()
+                };
+                (directives, TypeKind::Enum(EnumType { values }))
+            }
+            "input" => {
+                let directives = self.parse_const_directives()?;
+                let fields = if self.is(TokenKind::BraceL) {
+                    self.many(TokenKind::BraceL, TokenKind::BraceR, |p| {
+                        p.parse_input_value_definition()
+                    })?
+                } else if extend && directives.is_empty() {
+                    return self.unexpected("\"@\" or \"{\"");
+                } else {
+                    Vec::new()
+                };
+                (
+                    directives,
+                    TypeKind::InputObject(InputObjectType { fields }),
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(self.positioned(
+            TypeDefinition {
+                extend,
+                description,
+                name,
                 directives,
-                TypeKind::Object(ObjectType {
-                    implements: implements.unwrap_or_default(),
-                    fields,
-                }),
-            )
+                kind,
+            },
+            pos,
+        ))
+    }
+
+    fn parse_implements_interfaces(&mut self) -> Result<Vec<Positioned<Name>>> {
+        let mut implements = Vec::new();
+        if self.eat_keyword("implements")? {
+            self.eat(TokenKind::Amp)?;
+            loop {
+                implements.push(self.parse_name()?);
+                if !self.eat(TokenKind::Amp)? {
+                    break;
+                }
+            }
         }
-        Rule::interface_type => {
-            let implements = parse_if_rule(&mut pairs, Rule::implements_interfaces, |pair| {
-                debug_assert_eq!(pair.as_rule(), Rule::implements_interfaces);
+        Ok(implements)
+    }
 
-                pair.into_inner()
-                    .map(|pair| parse_name(pair, pc))
-                    .collect::<Result<_>>()
-            })?;
+    fn parse_enum_value_definition(&mut self) -> Result<Positioned<EnumValueDefinition>> {
+        let pos = self.token.pos;
 
-            let directives = parse_opt_const_directives(&mut pairs, pc)?;
-            let fields = parse_if_rule(&mut pairs, Rule::fields_definition, |pair| {
-                parse_fields_definition(pair, pc)
-            })?
-            .unwrap_or_default();
-            (
-                directives,
-                TypeKind::Interface(InterfaceType {
-                    implements: implements.unwrap_or_default(),
-                    fields,
-                }),
-            )
-        }
-        Rule::union_type => {
-            let directives = parse_opt_const_directives(&mut pairs, pc)?;
-            let members = parse_if_rule(&mut pairs, Rule::union_member_types, |pair| {
-                debug_assert_eq!(pair.as_rule(), Rule::union_member_types);
-
-                pair.into_inner().map(|pair| parse_name(pair, pc)).collect()
-            })?
-            .unwrap_or_default();
-            (directives, TypeKind::Union(UnionType { members }))
+        let description = self.parse_description()?;
+        if matches!(self.token.text, "true" | "false" | "null") {
+            return self.unexpected("enum value");
         }
-        Rule::enum_type => {
-            let directives = parse_opt_const_directives(&mut pairs, pc)?;
-            let values = parse_if_rule(&mut pairs, Rule::enum_values, |pair| {
-                debug_assert_eq!(pair.as_rule(), Rule::enum_values);
-
-                pair.into_inner()
-                    .map(|pair| {
-                        debug_assert_eq!(pair.as_rule(), Rule::enum_value_definition);
-
-                        let pos = pc.step(&pair);
-                        let mut pairs = pair.into_inner();
-
-                        let description =
-                            parse_if_rule(&mut pairs, Rule::string, |pair| parse_string(pair, pc))?;
-                        let value = parse_enum_value(pairs.next().unwrap(), pc)?;
-                        let directives = parse_opt_const_directives(&mut pairs, pc)?;
-
-                        debug_assert_eq!(pairs.next(), None);
-
-                        Ok(Positioned::new(
-                            EnumValueDefinition {
-                                description,
-                                value,
-                                directives,
-                            },
-                            pos,
-                        ))
-                    })
-                    .collect()
-            })?
-            .unwrap_or_default();
-            (directives, TypeKind::Enum(EnumType { values }))
-        }
-        Rule::input_object_type => {
-            let directives = parse_opt_const_directives(&mut pairs, pc)?;
-            let fields = parse_if_rule(&mut pairs, Rule::input_fields_definition, |pair| {
-                debug_assert_eq!(pair.as_rule(), Rule::input_fields_definition);
-
-                pair.into_inner()
-                    .map(|pair| parse_input_value_definition(pair, pc))
-                    .collect()
-            })?
-            .unwrap_or_default();
-
-            (
+        let value = self.parse_name()?;
+        let directives = self.parse_const_directives()?;
+
+        Ok(self.positioned(
+            EnumValueDefinition {
+                description,
+                value,
                 directives,
-                TypeKind::InputObject(InputObjectType { fields }),
-            )
-        }
-        _ => unreachable!(),
-    };
-
-    debug_assert_eq!(pairs.next(), None);
-
-    Ok(Positioned::new(
-        TypeDefinition {
-            extend,
-            description,
-            name,
-            directives,
-            kind,
-        },
-        pos,
-    ))
-}
+            },
+            pos,
+        ))
+    }
 
-fn parse_fields_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<Positioned<FieldDefinition>>> {
-    debug_assert_eq!(pair.as_rule(), Rule::fields_definition);
+    fn parse_field_definition(&mut self) -> Result<Positioned<FieldDefinition>> {
+        let pos = self.token.pos;
+
+        let description = self.parse_description()?;
+        let name = self.parse_name()?;
+        let arguments = self.parse_arguments_definition()?;
+        self.expect(TokenKind::Colon)?;
+        let ty = self.parse_type()?;
+        let directives = self.parse_const_directives()?;
+
+        Ok(self.positioned(
+            FieldDefinition {
+                description,
+                name,
+                arguments,
+                ty,
+                directives,
+            },
+            pos,
+        ))
+    }
 
-    pair.into_inner()
-        .map(|pair| parse_field_definition(pair, pc))
-        .collect()
-}
+    fn parse_directive_definition(
+        &mut self,
+        pos: Pos,
+        description: Option<Positioned<String>>,
+    ) -> Result<Positioned<DirectiveDefinition>> {
+        self.expect_keyword("directive")?;
+        self.expect(TokenKind::At)?;
+        let name = self.parse_name()?;
+        let arguments = self.parse_arguments_definition()?;
+        let is_repeatable = self.eat_keyword("repeatable")?;
+        self.expect_keyword("on")?;
+
+        self.eat(TokenKind::Pipe)?;
+        let mut locations = Vec::new();
+        loop {
+            let location = match self.token.text {
+                "QUERY" => DirectiveLocation::Query,
+                "MUTATION" => DirectiveLocation::Mutation,
+                "SUBSCRIPTION" => DirectiveLocation::Subscription,
+                "FIELD" => DirectiveLocation::Field,
+                "FRAGMENT_DEFINITION" => DirectiveLocation::FragmentDefinition,
+                "FRAGMENT_SPREAD" => DirectiveLocation::FragmentSpread,
+                "INLINE_FRAGMENT" => DirectiveLocation::InlineFragment,
+                "VARIABLE_DEFINITION" => DirectiveLocation::VariableDefinition,
+                "SCHEMA" => DirectiveLocation::Schema,
+                "SCALAR" => DirectiveLocation::Scalar,
+                "OBJECT" => DirectiveLocation::Object,
+                "FIELD_DEFINITION" => DirectiveLocation::FieldDefinition,
+                "ARGUMENT_DEFINITION" => DirectiveLocation::ArgumentDefinition,
+                "INTERFACE" => DirectiveLocation::Interface,
+                "UNION" => DirectiveLocation::Union,
+                "ENUM" => DirectiveLocation::Enum,
+                "ENUM_VALUE" => DirectiveLocation::EnumValue,
+                "INPUT_OBJECT" => DirectiveLocation::InputObject,
+                "INPUT_FIELD_DEFINITION" => DirectiveLocation::InputFieldDefinition,
+                _ => return self.unexpected("directive location"),
+            };
+            let pos = self.bump()?.pos;
+            locations.push(self.positioned(location, pos));
+            if !self.eat(TokenKind::Pipe)? {
+                break;
+            }
+        }
 
-fn parse_field_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<FieldDefinition>> {
-    debug_assert_eq!(pair.as_rule(), Rule::field_definition);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
-
-    let description = parse_if_rule(&mut pairs, Rule::string, |pair| parse_string(pair, pc))?;
-    let name = parse_name(pairs.next().unwrap(), pc)?;
-    let arguments = parse_if_rule(&mut pairs, Rule::arguments_definition, |pair| {
-        parse_arguments_definition(pair, pc)
-    })?
-    .unwrap_or_default();
-    let ty = parse_type(pairs.next().unwrap(), pc)?;
-    let directives = parse_opt_const_directives(&mut pairs, pc)?;
-
-    debug_assert_eq!(pairs.next(), None);
-
-    Ok(Positioned::new(
-        FieldDefinition {
-            description,
-            name,
-            arguments,
-            ty,
-            directives,
-        },
-        pos,
-    ))
-}
+        Ok(self.positioned(
+            DirectiveDefinition {
+                description,
+                name,
+                arguments,
+                is_repeatable,
+                locations,
+            },
+            pos,
+        ))
+    }
 
-fn parse_directive_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<DirectiveDefinition>> {
-    debug_assert_eq!(pair.as_rule(), Rule::directive_definition);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
-
-    let description = parse_if_rule(&mut pairs, Rule::string, |pair| parse_string(pair, pc))?;
-    let name = parse_name(pairs.next().unwrap(), pc)?;
-    let arguments = parse_if_rule(&mut pairs, Rule::arguments_definition, |pair| {
-        debug_assert_eq!(pair.as_rule(), Rule::arguments_definition);
-        pair.into_inner()
-            .map(|pair| parse_input_value_definition(pair, pc))
-            .collect()
-    })?
-    .unwrap_or_default();
-    let is_repeatable = parse_if_rule(&mut pairs, Rule::repeatable, |pair| {
-        debug_assert_eq!(pair.as_rule(), Rule::repeatable);
-        Ok(())
-    })
-    .unwrap_or_default()
-    .is_some();
-    let locations = {
-        let pair = pairs.next().unwrap();
-        debug_assert_eq!(pair.as_rule(), Rule::directive_locations);
-        pair.into_inner()
-            .map(|pair| {
-                let pos = pc.step(&pair);
-                debug_assert_eq!(pair.as_rule(), Rule::directive_location);
-                Positioned::new(
-                    match pair.as_str() {
-                        "QUERY" => DirectiveLocation::Query,
-                        "MUTATION" => DirectiveLocation::Mutation,
-                        "SUBSCRIPTION" => DirectiveLocation::Subscription,
-                        "FIELD" => DirectiveLocation::Field,
-                        "FRAGMENT_DEFINITION" => DirectiveLocation::FragmentDefinition,
-                        "FRAGMENT_SPREAD" => DirectiveLocation::FragmentSpread,
-                        "INLINE_FRAGMENT" => DirectiveLocation::InlineFragment,
-                        "VARIABLE_DEFINITION" => DirectiveLocation::VariableDefinition,
-                        "SCHEMA" => DirectiveLocation::Schema,
-                        "SCALAR" => DirectiveLocation::Scalar,
-                        "OBJECT" => DirectiveLocation::Object,
-                        "FIELD_DEFINITION" => DirectiveLocation::FieldDefinition,
-                        "ARGUMENT_DEFINITION" => DirectiveLocation::ArgumentDefinition,
-                        "INTERFACE" => DirectiveLocation::Interface,
-                        "UNION" => DirectiveLocation::Union,
-                        "ENUM" => DirectiveLocation::Enum,
-                        "ENUM_VALUE" => DirectiveLocation::EnumValue,
-                        "INPUT_OBJECT" => DirectiveLocation::InputObject,
-                        "INPUT_FIELD_DEFINITION" => DirectiveLocation::InputFieldDefinition,
-                        _ => unreachable!(),
-                    },
-                    pos,
-                )
+    fn parse_arguments_definition(&mut self) -> Result<Vec<Positioned<InputValueDefinition>>> {
+        if self.is(TokenKind::ParenL) {
+            self.many(TokenKind::ParenL, TokenKind::ParenR, |p| {
+                p.parse_input_value_definition()
             })
-            .collect()
-    };
-
-    debug_assert_eq!(pairs.next(), None);
-
-    Ok(Positioned::new(
-        DirectiveDefinition {
-            description,
-            name,
-            arguments,
-            is_repeatable,
-            locations,
-        },
-        pos,
-    ))
-}
-
-fn parse_arguments_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Vec<Positioned<InputValueDefinition>>> {
-    debug_assert_eq!(pair.as_rule(), Rule::arguments_definition);
-
-    pair.into_inner()
-        .map(|pair| parse_input_value_definition(pair, pc))
-        .collect()
-}
+        } else {
+            Ok(Vec::new())
+        }
+    }
 
-fn parse_input_value_definition(
-    pair: Pair<Rule>,
-    pc: &mut PositionCalculator,
-) -> Result<Positioned<InputValueDefinition>> {
-    debug_assert_eq!(pair.as_rule(), Rule::input_value_definition);
-
-    let pos = pc.step(&pair);
-    let mut pairs = pair.into_inner();
-
-    let description = parse_if_rule(&mut pairs, Rule::string, |pair| parse_string(pair, pc))?;
-    let name = parse_name(pairs.next().unwrap(), pc)?;
-    let ty = parse_type(pairs.next().unwrap(), pc)?;
-    let default_value = parse_if_rule(&mut pairs, Rule::default_value, |pair| {
-        parse_default_value(pair, pc)
-    })?;
-    let directives = parse_opt_const_directives(&mut pairs, pc)?;
-
-    Ok(Positioned::new(
-        InputValueDefinition {
-            description,
-            name,
-            ty,
-            default_value,
-            directives,
-        },
-        pos,
-    ))
+    fn parse_input_value_definition(&mut self) -> Result<Positioned<InputValueDefinition>> {
+        let pos = self.token.pos;
+
+        let description = self.parse_description()?;
+        let name = self.parse_name()?;
+        self.expect(TokenKind::Colon)?;
+        let ty = self.parse_type()?;
+        let default_value = self.parse_default_value()?;
+        let directives = self.parse_const_directives()?;
+
+        Ok(self.positioned(
+            InputValueDefinition {
+                description,
+                name,
+                ty,
+                default_value,
+                directives,
+            },
+            pos,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -410,23 +369,39 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parser() {
+    fn test_parser_ast() {
         for entry in fs::read_dir("tests/services").unwrap() {
             let entry = entry.unwrap();
-            eprintln!("Parsing file {}", entry.path().display());
-            GraphQLParser::parse(
-                Rule::service_document,
-                &fs::read_to_string(entry.path()).unwrap(),
-            )
-            .unwrap();
+            parse_schema(fs::read_to_string(entry.path()).unwrap()).unwrap();
         }
     }
 
     #[test]
-    fn test_parser_ast() {
-        for entry in fs::read_dir("tests/services").unwrap() {
-            let entry = entry.unwrap();
-            parse_schema(fs::read_to_string(entry.path()).unwrap()).unwrap();
-        }
+    fn test_parse_spans() {
+        let source = "\"A type\" type T implements I @d { \"\"\"field\"\"\" f(a: Int = 1): [T!]! }\ndirective @d on OBJECT | FIELD\n";
+        let doc = parse_schema_with_options(source, ParseOptions::new().spans(true)).unwrap();
+        let span = |span: Option<std::ops::Range<usize>>| &source[span.unwrap()];
+
+        let TypeSystemDefinition::Type(ty) = &doc.definitions[0] else {
+            panic!("expected a type definition");
+        };
+        assert_eq!(span(ty.span()), source.lines().next().unwrap());
+        assert_eq!(
+            span(ty.node.description.as_ref().unwrap().span()),
+            "\"A type\""
+        );
+        let TypeKind::Object(object) = &ty.node.kind else {
+            panic!("expected an object type");
+        };
+        let field = &object.fields[0];
+        assert_eq!(span(field.span()), "\"\"\"field\"\"\" f(a: Int = 1): [T!]!");
+        assert_eq!(span(field.node.arguments[0].span()), "a: Int = 1");
+        assert_eq!(span(field.node.ty.span()), "[T!]!");
+
+        let TypeSystemDefinition::Directive(directive) = &doc.definitions[1] else {
+            panic!("expected a directive definition");
+        };
+        assert_eq!(span(directive.span()), "directive @d on OBJECT | FIELD");
+        assert_eq!(span(directive.node.locations[1].span()), "FIELD");
     }
 }
@@ -1,31 +1,14 @@
-use pest::iterators::{Pair, Pairs};
+use std::borrow::Cow;
 
-use super::Rule;
-use crate::Result;
+use crate::{Error, Pos, Result};
 
-pub(super) fn next_if_rule<'a>(pairs: &mut Pairs<'a, Rule>, rule: Rule) -> Option<Pair<'a, Rule>> {
-    if pairs.peek().is_some_and(|pair| pair.as_rule() == rule) {
-        Some(pairs.next().unwrap())
+pub(super) fn block_string_value(raw: &str) -> String {
+    let raw = if raw.contains("\\\"\"\"") {
+        raw.replace("\\\"\"\"", "\"\"\"").into()
     } else {
-        None
-    }
-}
-pub(super) fn parse_if_rule<T>(
-    pairs: &mut Pairs<Rule>,
-    rule: Rule,
-    f: impl FnOnce(Pair<Rule>) -> Result<T>,
-) -> Result<Option<T>> {
-    next_if_rule(pairs, rule).map(f).transpose()
-}
-
-pub(super) fn exactly_one<T>(iter: impl IntoIterator<Item = T>) -> T {
-    let mut iter = iter.into_iter();
-    let res = iter.next().unwrap();
-    debug_assert!(iter.next().is_none());
-    res
-}
+        Cow::Borrowed(raw)
+    };
 
-pub(super) fn block_string_value(raw: &str) -> String {
     // Split the string by either \r\n, \r or \n
     let lines: Vec<_> = raw
         .split("\r\n")
@@ -104,37 +87,58 @@ fn test_block_string_value() {
         ),
         "a\nb\n\nc"
     );
+    assert_eq!(block_string_value(r#"a \""" b"#), r#"a """ b"#);
 }
 
-pub(super) fn string_value(s: &str) -> String {
+/// Unescapes the content of a string token at `pos`.
+///
+/// The lexer already rejects invalid escape sequences, but they are reported
+/// as an error rather than a panic here too.
+pub(super) fn string_value(s: &str, pos: Pos) -> Result<String> {
+    let invalid = || Error::Syntax {
+        message: "invalid escape sequence in string".to_string(),
+        start: pos,
+        end: None,
+    };
     let mut chars = s.chars();
+    let mut value = String::with_capacity(s.len());
 
-    std::iter::from_fn(|| {
-        Some(match chars.next()? {
-            '\\' => match chars.next().expect("backslash at end") {
+    while let Some(c) = chars.next() {
+        value.push(match c {
+            '\\' => match chars.next().ok_or_else(invalid)? {
                 c @ '\"' | c @ '\\' | c @ '/' => c,
                 'b' => '\x08',
                 'f' => '\x0C',
                 'n' => '\n',
                 'r' => '\r',
                 't' => '\t',
-                'u' => std::char::from_u32(
-                    (0..4)
-                        .map(|_| chars.next().unwrap().to_digit(16).unwrap())
-                        .fold(0, |acc, digit| acc * 16 + digit),
-                )
-                .unwrap(),
-                _ => unreachable!(),
+                'u' => {
+                    let mut code = 0;
+                    for _ in 0..4 {
+                        let digit = chars
+                            .next()
+                            .and_then(|c| c.to_digit(16))
+                            .ok_or_else(invalid)?;
+                        code = code * 16 + digit;
+                    }
+                    char::from_u32(code).ok_or_else(invalid)?
+                }
+                _ => return Err(invalid()),
             },
             other => other,
-        })
-    })
-    .collect()
+        });
+    }
+
+    Ok(value)
 }
 
 #[test]
 fn test_string_value() {
-    assert_eq!(string_value("abc"), "abc");
-    assert_eq!(string_value("\\n\\b\\u2a1A"), "\n\x08\u{2A1A}");
-    assert_eq!(string_value("\\\"\\\\"), "\"\\");
+    let value = |s| string_value(s, Pos::default());
+    assert_eq!(value("abc").unwrap(), "abc");
+    assert_eq!(value("\\n\\b\\u2a1A").unwrap(), "\n\x08\u{2A1A}");
+    assert_eq!(value("\\\"\\\\").unwrap(), "\"\\");
+    for s in ["\\", "\\x", "\\u+123", "\\u12", "\\uD800"] {
+        assert!(value(s).is_err(), "{s}");
+    }
 }
@@ -3,22 +3,51 @@ use std::{
     cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
+    ops::Range,
 };
 
-use pest::{RuleType, iterators::Pair};
 use serde::{Deserialize, Serialize};
 
 /// Original position of an element in source code.
 ///
 /// You can serialize and deserialize it to the GraphQL `locations` format
 /// ([reference](https://spec.graphql.org/October2021/#sec-Errors)).
-#[derive(PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Default, Hash, Serialize, Deserialize)]
+///
+/// Positions are compared by their line and column only, the byte offset is
+/// not serialized.
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
 pub struct Pos {
     /// One-based line number.
     pub line: usize,
 
     /// One-based column number.
     pub column: usize,
+
+    /// Zero-based byte offset in the source.
+    #[serde(skip)]
+    pub offset: usize,
+}
+
+impl PartialEq for Pos {
+    fn eq(&self, other: &Self) -> bool {
+        (self.line, self.column) == (other.line, other.column)
+    }
+}
+impl Eq for Pos {}
+impl PartialOrd for Pos {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Pos {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.line, self.column).cmp(&(other.line, other.column))
+    }
+}
+impl Hash for Pos {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.line, self.column).hash(state)
+    }
 }
 
 impl fmt::Debug for Pos {
@@ -35,15 +64,25 @@ impl fmt::Display for Pos {
 
 impl From<(usize, usize)> for Pos {
     fn from((line, column): (usize, usize)) -> Self {
-        Self { line, column }
+        Self {
+            line,
+            column,
+            offset: 0,
+        }
     }
 }
 
 /// An AST node that stores its original position.
+///
+/// The end position is only recorded when parsing with
+/// [`ParseOptions::spans`](crate::ParseOptions::spans), and is not serialized.
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct Positioned<T: ?Sized> {
     /// The position of the node.
     pub pos: Pos,
+    /// The position right after the end of the node, if it was recorded.
+    #[serde(skip)]
+    pub end: Option<Pos>,
     /// The node itself.
     pub node: T,
 }
@@ -52,7 +91,28 @@ impl<T> Positioned<T> {
     /// Create a new positioned node from the node and its position.
     #[must_use]
     pub const fn new(node: T, pos: Pos) -> Positioned<T> {
-        Positioned { pos, node }
+        Positioned {
+            pos,
+            end: None,
+            node,
+        }
+    }
+
+    /// Get the byte range of the node in the source, if its end position was
+    /// recorded.
+    ///
+    /// ```
+    /// use async_graphql_parser::{ParseOptions, parse_query_with_options, types::DocumentOperations};
+    ///
+    /// let source = "{ a(x: 1) b }";
+    /// let doc = parse_query_with_options(source, ParseOptions::new().spans(true)).unwrap();
+    /// let DocumentOperations::Single(operation) = doc.operations else { unreachable!() };
+    /// let field = &operation.node.selection_set.node.items[0];
+    /// assert_eq!(&source[field.span().unwrap()], "a(x: 1)");
+    /// ```
+    #[must_use]
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.end.map(|end| self.pos.offset..end.offset)
     }
 
     /// Get the inner node.
@@ -67,13 +127,21 @@ impl<T> Positioned<T> {
     /// Create a new positioned node with the same position as this one.
     #[must_use]
     pub fn position_node<U>(&self, other: U) -> Positioned<U> {
-        Positioned::new(other, self.pos)
+        Positioned {
+            pos: self.pos,
+            end: self.end,
+            node: other,
+        }
     }
 
     /// Map the inner value of this positioned node.
     #[must_use]
     pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Positioned<U> {
-        Positioned::new(f(self.node), self.pos)
+        Positioned {
+            pos: self.pos,
+            end: self.end,
+            node: f(self.node),
+        }
     }
 }
 
@@ -115,48 +183,3 @@ impl BorrowMut<str> for Positioned<String> {
         self.node.as_mut_str()
     }
 }
-
-pub(crate) struct PositionCalculator<'a> {
-    input: &'a str,
-    pos: usize,
-    line: usize,
-    column: usize,
-}
-
-impl<'a> PositionCalculator<'a> {
-    pub(crate) fn new(input: &'a str) -> PositionCalculator<'a> {
-        Self {
-            input,
-            pos: 0,
-            line: 1,
-            column: 1,
-        }
-    }
-
-    pub(crate) fn step<R: RuleType>(&mut self, pair: &Pair<R>) -> Pos {
-        let pos = pair.as_span().start();
-        debug_assert!(pos >= self.pos);
-        let bytes_to_read = pos - self.pos;
-        let chars_to_read = self.input[..bytes_to_read].chars();
-        for ch in chars_to_read {
-            match ch {
-                '\r' => {
-                    self.column = 1;
-                }
-                '\n' => {
-                    self.line += 1;
-                    self.column = 1;
-                }
-                _ => {
-                    self.column += 1;
-                }
-            }
-        }
-        self.pos = pos;
-        self.input = &self.input[bytes_to_read..];
-        Pos {
-            line: self.line,
-            column: self.column,
-        }
-    }
-}
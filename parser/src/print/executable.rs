@@ -0,0 +1,209 @@
+use super::*;
+
+enum Definition<'a> {
+    Operation(Option<&'a Name>, &'a Positioned<OperationDefinition>),
+    Fragment(&'a Name, &'a Positioned<FragmentDefinition>),
+}
+
+/// Print a GraphQL query document.
+///
+/// The operations and fragments are printed in the order they were parsed.
+///
+/// ```
+/// use async_graphql_parser::{PrintOptions, parse_query, print_query};
+///
+/// let doc = parse_query("query A($id: ID!) { user(id: $id) { name ...F } } fragment F on User { age }").unwrap();
+/// assert_eq!(
+///     print_query(&doc, PrintOptions::new().compact()),
+///     "query A($id: ID!) { user(id: $id) { name ...F } } fragment F on User { age }"
+/// );
+/// ```
+#[must_use]
+pub fn print_query(doc: &ExecutableDocument, options: PrintOptions) -> String {
+    let mut definitions = doc
+        .operations
+        .iter()
+        .map(|(name, operation)| (operation.pos, Definition::Operation(name, operation)))
+        .chain(
+            doc.fragments
+                .iter()
+                .map(|(name, fragment)| (fragment.pos, Definition::Fragment(name, fragment))),
+        )
+        .collect::<Vec<_>>();
+    definitions.sort_by_key(|(pos, _)| *pos);
+
+    let mut printer = Printer::new(options);
+    for (idx, (_, definition)) in definitions.iter().enumerate() {
+        if idx > 0 {
+            printer.definition_separator();
+        }
+        match definition {
+            Definition::Operation(name, operation) => {
+                printer.operation_definition(*name, &operation.node)
+            }
+            Definition::Fragment(name, fragment) => {
+                printer.fragment_definition(name, &fragment.node)
+            }
+        }
+    }
+    printer.finish()
+}
+
+impl Printer {
+    fn operation_definition(&mut self, name: Option<&Name>, operation: &OperationDefinition) {
+        let is_shorthand = name.is_none()
+            && operation.ty == OperationType::Query
+            && operation.variable_definitions.is_empty()
+            && operation.directives.is_empty();
+
+        if is_shorthand {
+            self.push("{");
+            self.level += 1;
+            for selection in &operation.selection_set.node.items {
+                self.line();
+                self.selection(&selection.node);
+            }
+            self.level -= 1;
+            self.line();
+            self.push("}");
+            return;
+        }
+
+        self.push_display(operation.ty);
+        if let Some(name) = name {
+            self.push_display(format_args!(" {}", name));
+        }
+        if !operation.variable_definitions.is_empty() {
+            self.push("(");
+            self.separated(&operation.variable_definitions, ", ", |p, variable| {
+                p.variable_definition(&variable.node)
+            });
+            self.push(")");
+        }
+        self.directives(&operation.directives);
+        self.selection_set(&operation.selection_set.node);
+    }
+
+    fn variable_definition(&mut self, variable: &VariableDefinition) {
+        self.push_display(format_args!(
+            "${}: {}",
+            variable.name.node, variable.var_type.node
+        ));
+        if let Some(default_value) = &variable.default_value {
+            self.push_display(format_args!(" = {}", default_value.node));
+        }
+        self.directives(&variable.directives);
+    }
+
+    fn fragment_definition(&mut self, name: &Name, fragment: &FragmentDefinition) {
+        self.push_display(format_args!(
+            "fragment {} on {}",
+            name, fragment.type_condition.node.on.node
+        ));
+        self.directives(&fragment.directives);
+        self.selection_set(&fragment.selection_set.node);
+    }
+
+    fn selection_set(&mut self, selection_set: &SelectionSet) {
+        if selection_set.items.is_empty() {
+            return;
+        }
+        self.block(&selection_set.items, |p, selection| {
+            p.selection(&selection.node)
+        });
+    }
+
+    fn selection(&mut self, selection: &Selection) {
+        match selection {
+            Selection::Field(field) => {
+                let field = &field.node;
+                if let Some(alias) = &field.alias {
+                    self.push_display(format_args!("{}: ", alias.node));
+                }
+                self.push(&field.name.node);
+                self.arguments(&field.arguments);
+                self.directives(&field.directives);
+                self.selection_set(&field.selection_set.node);
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                self.push_display(format_args!(
+                    "...{}",
+                    fragment_spread.node.fragment_name.node
+                ));
+                self.directives(&fragment_spread.node.directives);
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                let inline_fragment = &inline_fragment.node;
+                self.push("...");
+                if let Some(type_condition) = &inline_fragment.type_condition {
+                    self.push_display(format_args!(" on {}", type_condition.node.on.node));
+                }
+                self.directives(&inline_fragment.directives);
+                self.selection_set(&inline_fragment.selection_set.node);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::parse_query;
+
+    #[test]
+    fn test_print_round_trip() {
+        for entry in fs::read_dir("tests/executables").unwrap() {
+            let entry = entry.unwrap();
+            eprintln!("Printing file {}", entry.path().display());
+
+            let doc = parse_query(fs::read_to_string(entry.path()).unwrap()).unwrap();
+            let pretty = print_query(&doc, PrintOptions::new());
+            let compact = print_query(&doc, PrintOptions::new().compact());
+            assert_eq!(
+                print_query(&parse_query(&pretty).unwrap(), PrintOptions::new()),
+                pretty
+            );
+            assert_eq!(
+                print_query(&parse_query(&compact).unwrap(), PrintOptions::new()),
+                pretty
+            );
+        }
+    }
+
+    #[test]
+    fn test_print_canonical() {
+        let doc =
+            parse_query(fs::read_to_string("tests/executables/kitchen-sink.graphql").unwrap())
+                .unwrap();
+        assert_eq!(
+            print_query(&doc, PrintOptions::new()),
+            fs::read_to_string("tests/executables/kitchen-sink_canonical.graphql").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_print_strings() {
+        let doc = parse_query(r#"{ a(s: "\"quoted\"\n\u001f") }"#).unwrap();
+        let printed = print_query(&doc, PrintOptions::new().compact());
+        assert_eq!(printed, r#"{ a(s: "\"quoted\"\n\u001f") }"#);
+    }
+
+    #[test]
+    fn test_print_shorthand() {
+        let doc = parse_query("{ a(x: [1, 2]) @skip(if: $s) { ... on T { b } } }").unwrap();
+        assert_eq!(
+            print_query(&doc, PrintOptions::new()),
+            "{\n  a(x: [1, 2]) @skip(if: $s) {\n    ... on T {\n      b\n    }\n  }\n}\n"
+        );
+        assert_eq!(
+            print_query(&doc, PrintOptions::new().compact().indent_width(4)),
+            "{ a(x: [1, 2]) @skip(if: $s) { ... on T { b } } }"
+        );
+        assert_eq!(
+            print_query(&doc, PrintOptions::new().indent_width(4)),
+            "{\n    a(x: [1, 2]) @skip(if: $s) {\n        ... on T {\n            b\n        }\n    }\n}\n"
+        );
+    }
+}
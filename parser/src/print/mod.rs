@@ -0,0 +1,208 @@
+//! Printing module.
+//!
+//! This module's structure mirrors `types`.
+
+use std::fmt::{Display, Write};
+
+use async_graphql_value::{ConstValue, Name};
+
+use crate::{pos::Positioned, types::*};
+
+mod executable;
+mod service;
+
+pub use executable::print_query;
+pub use service::print_schema;
+
+/// Options for printing documents.
+#[derive(Debug, Clone, Copy)]
+pub struct PrintOptions {
+    compact: bool,
+    indent_width: u8,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            compact: false,
+            indent_width: 2,
+        }
+    }
+}
+
+impl PrintOptions {
+    /// Create a `PrintOptions`
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Print the document on a single line, with as little whitespace as
+    /// possible
+    #[inline]
+    #[must_use]
+    pub fn compact(self) -> Self {
+        Self {
+            compact: true,
+            ..self
+        }
+    }
+
+    /// Set the number of spaces to use for each indentation level (default: 2).
+    /// Not used in compact mode
+    #[inline]
+    #[must_use]
+    pub fn indent_width(self, width: u8) -> Self {
+        Self {
+            indent_width: width,
+            ..self
+        }
+    }
+}
+
+struct Printer {
+    options: PrintOptions,
+    output: String,
+    level: usize,
+}
+
+impl Printer {
+    fn new(options: PrintOptions) -> Self {
+        Self {
+            options,
+            output: String::new(),
+            level: 0,
+        }
+    }
+
+    fn finish(mut self) -> String {
+        if !self.options.compact && !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        self.output
+    }
+
+    fn push(&mut self, s: &str) {
+        self.output.push_str(s);
+    }
+
+    fn push_display(&mut self, value: impl Display) {
+        write!(self.output, "{}", value).ok();
+    }
+
+    /// Starts a new line at the current indentation level, or a space in
+    /// compact mode.
+    fn line(&mut self) {
+        if self.options.compact {
+            self.output.push(' ');
+        } else {
+            self.output.push('\n');
+            self.indent();
+        }
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.level * usize::from(self.options.indent_width) {
+            self.output.push(' ');
+        }
+    }
+
+    /// Separates two definitions of a document.
+    fn definition_separator(&mut self) {
+        if self.options.compact {
+            self.output.push(' ');
+        } else {
+            self.output.push_str("\n\n");
+        }
+    }
+
+    /// Writes `items` in braces, one per line.
+    fn block<T>(&mut self, items: &[T], mut f: impl FnMut(&mut Self, &T)) {
+        self.push(" {");
+        self.level += 1;
+        for item in items {
+            self.line();
+            f(self, item);
+        }
+        self.level -= 1;
+        self.line();
+        self.push("}");
+    }
+
+    fn separated<T>(&mut self, items: &[T], separator: &str, mut f: impl FnMut(&mut Self, &T)) {
+        for (idx, item) in items.iter().enumerate() {
+            if idx > 0 {
+                self.push(separator);
+            }
+            f(self, item);
+        }
+    }
+
+    fn arguments<T: Display>(&mut self, arguments: &[(Positioned<Name>, Positioned<T>)]) {
+        if arguments.is_empty() {
+            return;
+        }
+        self.push("(");
+        self.separated(arguments, ", ", |p, (name, value)| {
+            p.push_display(format_args!("{}: {}", name.node, value.node));
+        });
+        self.push(")");
+    }
+
+    fn directives(&mut self, directives: &[Positioned<Directive>]) {
+        for directive in directives {
+            self.push_display(format_args!(" @{}", directive.node.name.node));
+            self.arguments(&directive.node.arguments);
+        }
+    }
+
+    fn const_directives(&mut self, directives: &[Positioned<ConstDirective>]) {
+        for directive in directives {
+            self.push_display(format_args!(" @{}", directive.node.name.node));
+            self.arguments(&directive.node.arguments);
+        }
+    }
+
+    /// Writes a description, followed by a new line at the current indentation
+    /// level.
+    fn description(&mut self, description: &Option<Positioned<String>>) {
+        let Some(description) = description else {
+            return;
+        };
+        let description = &description.node;
+
+        if self.options.compact || !is_block_string_safe(description) {
+            self.push_display(ConstValue::String(description.clone()));
+        } else {
+            self.push("\"\"\"");
+            for line in description.split('\n') {
+                self.output.push('\n');
+                if !line.is_empty() {
+                    self.indent();
+                    self.push(line);
+                }
+            }
+            self.output.push('\n');
+            self.indent();
+            self.push("\"\"\"");
+        }
+        self.line();
+    }
+}
+
+/// Returns `true` if the string is parsed back to the same value when written
+/// as an indented block string.
+fn is_block_string_safe(s: &str) -> bool {
+    if s.contains("\"\"\"") || s.chars().any(|c| c.is_control() && c != '\n' && c != '\t') {
+        return false;
+    }
+
+    let has_content = |line: &str| line.chars().any(|c| c != ' ' && c != '\t');
+    let lines = s.split('\n').collect::<Vec<_>>();
+    lines.first().copied().is_some_and(has_content)
+        && lines.last().copied().is_some_and(has_content)
+        && lines
+            .iter()
+            .any(|line| has_content(line) && !line.starts_with([' ', '\t']))
+}
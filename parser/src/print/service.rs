@@ -0,0 +1,272 @@
+use super::*;
+
+/// Print a GraphQL schema document.
+///
+/// ```
+/// use async_graphql_parser::{PrintOptions, parse_schema, print_schema};
+///
+/// let doc = parse_schema("type Query { user(id: ID!): User } type User { name: String }").unwrap();
+/// assert_eq!(
+///     print_schema(&doc, PrintOptions::new()),
+///     "type Query {\n  user(id: ID!): User\n}\n\ntype User {\n  name: String\n}\n"
+/// );
+/// ```
+#[must_use]
+pub fn print_schema(doc: &ServiceDocument, options: PrintOptions) -> String {
+    let mut printer = Printer::new(options);
+    for (idx, definition) in doc.definitions.iter().enumerate() {
+        if idx > 0 {
+            printer.definition_separator();
+        }
+        match definition {
+            TypeSystemDefinition::Schema(schema) => printer.schema_definition(&schema.node),
+            TypeSystemDefinition::Type(ty) => printer.type_definition(&ty.node),
+            TypeSystemDefinition::Directive(directive) => {
+                printer.directive_definition(&directive.node)
+            }
+        }
+    }
+    printer.finish()
+}
+
+impl Printer {
+    fn schema_definition(&mut self, schema: &SchemaDefinition) {
+        if schema.extend {
+            self.push("extend ");
+        }
+        self.push("schema");
+        self.const_directives(&schema.directives);
+
+        let roots = [
+            ("query", &schema.query),
+            ("mutation", &schema.mutation),
+            ("subscription", &schema.subscription),
+        ]
+        .into_iter()
+        .filter_map(|(ty, name)| Some((ty, name.as_ref()?)))
+        .collect::<Vec<_>>();
+        if !roots.is_empty() {
+            self.block(&roots, |p, (ty, name)| {
+                p.push_display(format_args!("{}: {}", ty, name.node))
+            });
+        }
+    }
+
+    fn type_definition(&mut self, ty: &TypeDefinition) {
+        self.description(&ty.description);
+        if ty.extend {
+            self.push("extend ");
+        }
+        self.push(match &ty.kind {
+            TypeKind::Scalar => "scalar ",
+            TypeKind::Object(_) => "type ",
+            TypeKind::Interface(_) => "interface ",
+            TypeKind::Union(_) => "union ",
+            TypeKind::Enum(_) => "enum ",
+            TypeKind::InputObject(_) => "input ",
+        });
+        self.push(&ty.name.node);
+
+        match &ty.kind {
+            TypeKind::Scalar => self.const_directives(&ty.directives),
+            TypeKind::Object(ObjectType { implements, fields })
+            | TypeKind::Interface(InterfaceType { implements, fields }) => {
+                if !implements.is_empty() {
+                    self.push(" implements ");
+                    self.separated(implements, " & ", |p, name| p.push(&name.node));
+                }
+                self.const_directives(&ty.directives);
+                if !fields.is_empty() {
+                    self.block(fields, |p, field| p.field_definition(&field.node));
+                }
+            }
+            TypeKind::Union(union) => {
+                self.const_directives(&ty.directives);
+                if !union.members.is_empty() {
+                    self.push(" = ");
+                    self.separated(&union.members, " | ", |p, name| p.push(&name.node));
+                }
+            }
+            TypeKind::Enum(enum_type) => {
+                self.const_directives(&ty.directives);
+                if !enum_type.values.is_empty() {
+                    self.block(&enum_type.values, |p, value| {
+                        p.description(&value.node.description);
+                        p.push(&value.node.value.node);
+                        p.const_directives(&value.node.directives);
+                    });
+                }
+            }
+            TypeKind::InputObject(input_object) => {
+                self.const_directives(&ty.directives);
+                if !input_object.fields.is_empty() {
+                    self.block(&input_object.fields, |p, field| {
+                        p.input_value_definition(&field.node)
+                    });
+                }
+            }
+        }
+    }
+
+    fn field_definition(&mut self, field: &FieldDefinition) {
+        self.description(&field.description);
+        self.push(&field.name.node);
+        self.argument_definitions(&field.arguments);
+        self.push_display(format_args!(": {}", field.ty.node));
+        self.const_directives(&field.directives);
+    }
+
+    fn argument_definitions(&mut self, arguments: &[Positioned<InputValueDefinition>]) {
+        if arguments.is_empty() {
+            return;
+        }
+
+        let multiline = !self.options.compact
+            && arguments
+                .iter()
+                .any(|argument| argument.node.description.is_some());
+        if multiline {
+            self.push("(");
+            self.level += 1;
+            for argument in arguments {
+                self.line();
+                self.input_value_definition(&argument.node);
+            }
+            self.level -= 1;
+            self.line();
+            self.push(")");
+        } else {
+            self.push("(");
+            self.separated(arguments, ", ", |p, argument| {
+                p.input_value_definition(&argument.node)
+            });
+            self.push(")");
+        }
+    }
+
+    fn input_value_definition(&mut self, input_value: &InputValueDefinition) {
+        self.description(&input_value.description);
+        self.push_display(format_args!(
+            "{}: {}",
+            input_value.name.node, input_value.ty.node
+        ));
+        if let Some(default_value) = &input_value.default_value {
+            self.push_display(format_args!(" = {}", default_value.node));
+        }
+        self.const_directives(&input_value.directives);
+    }
+
+    fn directive_definition(&mut self, directive: &DirectiveDefinition) {
+        self.description(&directive.description);
+        self.push_display(format_args!("directive @{}", directive.name.node));
+        self.argument_definitions(&directive.arguments);
+        if directive.is_repeatable {
+            self.push(" repeatable");
+        }
+        self.push(" on ");
+        self.separated(&directive.locations, " | ", |p, location| {
+            p.push(directive_location_name(location.node))
+        });
+    }
+}
+
+fn directive_location_name(location: DirectiveLocation) -> &'static str {
+    match location {
+        DirectiveLocation::Query => "QUERY",
+        DirectiveLocation::Mutation => "MUTATION",
+        DirectiveLocation::Subscription => "SUBSCRIPTION",
+        DirectiveLocation::Field => "FIELD",
+        DirectiveLocation::FragmentDefinition => "FRAGMENT_DEFINITION",
+        DirectiveLocation::FragmentSpread => "FRAGMENT_SPREAD",
+        DirectiveLocation::InlineFragment => "INLINE_FRAGMENT",
+        DirectiveLocation::Schema => "SCHEMA",
+        DirectiveLocation::Scalar => "SCALAR",
+        DirectiveLocation::Object => "OBJECT",
+        DirectiveLocation::FieldDefinition => "FIELD_DEFINITION",
+        DirectiveLocation::ArgumentDefinition => "ARGUMENT_DEFINITION",
+        DirectiveLocation::Interface => "INTERFACE",
+        DirectiveLocation::Union => "UNION",
+        DirectiveLocation::Enum => "ENUM",
+        DirectiveLocation::EnumValue => "ENUM_VALUE",
+        DirectiveLocation::InputObject => "INPUT_OBJECT",
+        DirectiveLocation::InputFieldDefinition => "INPUT_FIELD_DEFINITION",
+        DirectiveLocation::VariableDefinition => "VARIABLE_DEFINITION",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::parse_schema;
+
+    #[test]
+    fn test_print_round_trip() {
+        for entry in fs::read_dir("tests/services").unwrap() {
+            let entry = entry.unwrap();
+            eprintln!("Printing file {}", entry.path().display());
+
+            let doc = parse_schema(fs::read_to_string(entry.path()).unwrap()).unwrap();
+            let pretty = print_schema(&doc, PrintOptions::new());
+            let compact = print_schema(&doc, PrintOptions::new().compact());
+            assert_eq!(
+                print_schema(&parse_schema(&pretty).unwrap(), PrintOptions::new()),
+                pretty
+            );
+            assert_eq!(
+                print_schema(&parse_schema(&compact).unwrap(), PrintOptions::new()),
+                pretty
+            );
+        }
+    }
+
+    #[test]
+    fn test_print_descriptions() {
+        let doc = parse_schema(
+            r#"
+            """
+            A user.
+
+              Indented line.
+            """
+            type User {
+              "The name."
+              name(
+                "Upper case."
+                upper: Boolean = false
+              ): String
+              "  Leading whitespace."
+              age: Int
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            print_schema(&doc, PrintOptions::new()),
+            r#""""
+A user.
+
+  Indented line.
+"""
+type User {
+  """
+  The name.
+  """
+  name(
+    """
+    Upper case.
+    """
+    upper: Boolean = false
+  ): String
+  "  Leading whitespace."
+  age: Int
+}
+"#
+        );
+        assert_eq!(
+            print_schema(&doc, PrintOptions::new().compact()),
+            r#""A user.\n\n  Indented line." type User { "The name." name("Upper case." upper: Boolean = false): String "  Leading whitespace." age: Int }"#
+        );
+    }
+}
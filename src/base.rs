@@ -6,6 +6,7 @@ use std::{
 };
 
 use async_graphql_value::ConstValue;
+use futures_util::{FutureExt, future::BoxFuture};
 
 use crate::{
     ContainerType, Context, ContextSelectionSet, Error, InputValueError, InputValueResult,
@@ -62,6 +63,16 @@ pub trait InputType: Send + Sync + Sized {
 
     /// Returns a reference to the raw value.
     fn as_raw_value(&self) -> Option<&Self::RawValueType>;
+
+    /// Returns a future that runs the asynchronous validators of this value
+    /// and of the input values it contains, or `None` if there are none.
+    #[doc(hidden)]
+    fn validate_async<'a>(
+        &'a self,
+        _ctx: &'a Context<'_>,
+    ) -> Option<BoxFuture<'a, Result<(), InputValueError<Self>>>> {
+        None
+    }
 }
 
 /// Represents a GraphQL output type.
@@ -263,6 +274,17 @@ impl<T: InputType> InputType for Box<T> {
     fn as_raw_value(&self) -> Option<&Self::RawValueType> {
         self.as_ref().as_raw_value()
     }
+
+    fn validate_async<'a>(
+        &'a self,
+        ctx: &'a Context<'_>,
+    ) -> Option<BoxFuture<'a, Result<(), InputValueError<Self>>>> {
+        let fut = T::validate_async(self, ctx)?;
+        Some(
+            fut.map(|res| res.map_err(InputValueError::propagate))
+                .boxed(),
+        )
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -313,6 +335,17 @@ impl<T: InputType> InputType for Arc<T> {
     fn as_raw_value(&self) -> Option<&Self::RawValueType> {
         self.as_ref().as_raw_value()
     }
+
+    fn validate_async<'a>(
+        &'a self,
+        ctx: &'a Context<'_>,
+    ) -> Option<BoxFuture<'a, Result<(), InputValueError<Self>>>> {
+        let fut = T::validate_async(self, ctx)?;
+        Some(
+            fut.map(|res| res.map_err(InputValueError::propagate))
+                .boxed(),
+        )
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -17,8 +17,9 @@ use serde::{
 };
 
 use crate::{
-    Error, InputType, Lookahead, Name, OneofObjectType, PathSegment, Pos, Positioned, Result,
-    ServerError, ServerResult, UploadValue, Value,
+    CacheControl, Error, InputType, Lookahead, Name, OneofObjectType, PathSegment, Pos, Positioned,
+    Result, ServerError, ServerResult, UploadValue, Value,
+    execution_plan::ExecutionPlan,
     extensions::Extensions,
     parser::types::{
         Directive, Field, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
@@ -258,6 +259,23 @@ pub struct QueryEnvInner {
     pub http_headers: Mutex<http::HeaderMap>,
     pub introspection_mode: IntrospectionMode,
     pub errors: Mutex<Vec<ServerError>>,
+    pub cache_control: Mutex<CacheControl>,
+    pub plan: Option<Arc<ExecutionPlan>>,
+    #[cfg(feature = "response_cache")]
+    pub(crate) records: Arc<Mutex<ResolveRecords>>,
+}
+
+/// What the resolvers reported while the operation was executed, which the
+/// response cache needs to know about.
+#[cfg(feature = "response_cache")]
+#[derive(Default)]
+pub(crate) struct ResolveRecords {
+    /// The keys of the objects returned by the fields, by path.
+    pub(crate) cache_keys: HashMap<String, String>,
+    /// The hints added by the resolvers, with the paths of their fields.
+    pub(crate) cache_hints: Vec<(String, CacheControl)>,
+    /// The number of errors reported by the resolvers.
+    pub(crate) errors: usize,
 }
 
 #[doc(hidden)]
@@ -278,6 +296,12 @@ impl QueryEnv {
         QueryEnv(Arc::new(inner))
     }
 
+    /// Returns a mutable reference to the environment while it is still
+    /// being prepared and has not been shared.
+    pub(crate) fn get_mut(&mut self) -> &mut QueryEnvInner {
+        Arc::get_mut(&mut self.0).expect("the query environment has already been shared")
+    }
+
     #[doc(hidden)]
     pub fn create_context<'a, T>(
         &'a self,
@@ -367,6 +391,115 @@ impl<'a, T> ContextBase<'a, T> {
     /// to report this error and return `Value::Null`.
     pub fn add_error(&self, error: ServerError) {
         self.query_env.errors.lock().unwrap().push(error);
+        #[cfg(feature = "response_cache")]
+        {
+            self.query_env.records.lock().unwrap().errors += 1;
+        }
+    }
+
+    /// Lowers the cache control hint of the response.
+    ///
+    /// The hint is merged with the hints of the fields that have been
+    /// resolved, so it can only make the response less cacheable.
+    ///
+    /// ```
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     #[graphql(cache_control(max_age = 60))]
+    ///     async fn value(&self, ctx: &Context<'_>, fresh: bool) -> i32 {
+    ///         if fresh {
+    ///             ctx.add_cache_hint(CacheControl {
+    ///                 max_age: -1,
+    ///                 ..Default::default()
+    ///             });
+    ///         }
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// assert_eq!(
+    ///     schema
+    ///         .execute("{ value(fresh: false) }")
+    ///         .await
+    ///         .cache_control
+    ///         .value(),
+    ///     Some("max-age=60".to_string())
+    /// );
+    /// assert_eq!(
+    ///     schema
+    ///         .execute("{ value(fresh: true) }")
+    ///         .await
+    ///         .cache_control
+    ///         .value(),
+    ///     Some("no-cache".to_string())
+    /// );
+    /// # });
+    /// ```
+    pub fn add_cache_hint(&self, cache_control: CacheControl) {
+        let mut current = self.query_env.cache_control.lock().unwrap();
+        *current = current.merge(&cache_control);
+        #[cfg(feature = "response_cache")]
+        {
+            self.query_env
+                .records
+                .lock()
+                .unwrap()
+                .cache_hints
+                .push((self.path_string(), cache_control));
+        }
+    }
+
+    /// Sets the key that identifies the object returned by the current field
+    /// in the [`ResponseCache`](crate::extensions::response_cache::ResponseCache).
+    ///
+    /// The fields of an object are only cached individually when the resolver
+    /// that returned it has set its key, for example its type name and its
+    /// id, and they are shared by every field that returns an object with
+    /// the same key.
+    #[cfg(feature = "response_cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "response_cache")))]
+    pub fn set_cache_key(&self, key: impl Into<String>) {
+        self.query_env
+            .records
+            .lock()
+            .unwrap()
+            .cache_keys
+            .insert(self.path_string(), key.into());
+    }
+
+    /// Sets the keys that identify the items of the list returned by the
+    /// current field, in order, like [`set_cache_key`](Self::set_cache_key)
+    /// does for an object.
+    #[cfg(feature = "response_cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "response_cache")))]
+    pub fn set_cache_keys<I>(&self, keys: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let path = self.path_string();
+        let mut records = self.query_env.records.lock().unwrap();
+        for (idx, key) in keys.into_iter().enumerate() {
+            let item_path = if path.is_empty() {
+                idx.to_string()
+            } else {
+                format!("{path}.{idx}")
+            };
+            records.cache_keys.insert(item_path, key.into());
+        }
+    }
+
+    #[cfg(feature = "response_cache")]
+    fn path_string(&self) -> String {
+        self.path_node
+            .map(|node| node.to_string())
+            .unwrap_or_default()
     }
 
     /// Gets the global data defined in the `Context` or `Schema`.
@@ -598,7 +731,7 @@ impl<'a, T> ContextBase<'a, T> {
         };
         InputType::parse(value)
             .map(|value| (pos, value))
-            .map_err(|e| e.into_server_error(pos))
+            .map_err(|e| e.prefix_input_path(name).into_server_error(pos))
     }
 
     #[doc(hidden)]
@@ -628,6 +761,16 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
         name: &str,
         default: Option<fn() -> T>,
     ) -> ServerResult<(Pos, T)> {
+        if let Some((pos, value)) = self
+            .query_env
+            .plan
+            .as_deref()
+            .and_then(|plan| plan.argument(self.item, name))
+        {
+            return InputType::parse(Some(value.clone()))
+                .map(|value| (pos, value))
+                .map_err(|e| e.prefix_input_path(name).into_server_error(pos));
+        }
         self.get_param_value(&self.item.node.arguments, name, default)
     }
 
@@ -815,6 +958,31 @@ impl<'a> SelectionField<'a> {
         Ok(arguments)
     }
 
+    /// Get the value of an argument of this field.
+    ///
+    /// A missing argument is parsed from `None`, so use an `Option` type for
+    /// optional arguments.
+    pub fn argument<T: InputType>(&self, name: &str) -> ServerResult<T> {
+        let value = match self.field.get_argument(name) {
+            Some(value) => Some((
+                value.pos,
+                value
+                    .node
+                    .clone()
+                    .into_const_with(|name| self.context.var_value(&name, value.pos))?,
+            )),
+            None => None,
+        };
+        let pos = value.as_ref().map_or(self.field.name.pos, |(pos, _)| *pos);
+        T::parse(value.map(|(_, value)| value)).map_err(|err| err.into_server_error(pos))
+    }
+
+    /// Get the alias of this field, or its name if there is no alias.
+    #[inline]
+    pub fn response_key(&self) -> &'a str {
+        self.alias().unwrap_or_else(|| self.name())
+    }
+
     /// Get all subfields of the current selection set.
     pub fn selection_set(&self) -> impl Iterator<Item = SelectionField<'a>> {
         SelectionFieldsIter {
@@ -1,10 +1,21 @@
 use std::borrow::Cow;
 
+use futures_util::future::BoxFuture;
+
 use crate::{
-    Context, ContextDirective, ServerResult, Value, extensions::ResolveFut,
-    parser::types::Directive, registry::Registry,
+    Context, ContextDirective, ContextSelectionSet, QueryEnv, Response, ServerResult, Value,
+    extensions::ResolveFut,
+    parser::{
+        Positioned,
+        types::{Directive, Selection, SelectionSet},
+    },
+    registry::Registry,
+    schema::SchemaEnv,
 };
 
+/// Represents the future of executing an operation.
+pub type ExecuteOperationFut<'a> = &'a mut (dyn Future<Output = Response> + Send + Unpin);
+
 #[doc(hidden)]
 pub trait CustomDirectiveFactory: Send + Sync + 'static {
     fn name(&self) -> Cow<'static, str>;
@@ -38,4 +49,246 @@ pub trait CustomDirective: Sync + Send + 'static {
     ) -> ServerResult<Option<Value>> {
         resolve.await
     }
+
+    /// Called before the operation is executed, for the field, fragment
+    /// spread or inline fragment the directive is applied to.
+    ///
+    /// Returns the selection to execute in its place, or `None` to remove it
+    /// from the selection set.
+    fn transform_selection(
+        &self,
+        ctx: &ContextDirective<'_>,
+        selection: Positioned<Selection>,
+    ) -> ServerResult<Option<Positioned<Selection>>> {
+        Ok(Some(selection))
+    }
+
+    /// Called before the operation is executed, for the variable definition
+    /// the directive is applied to.
+    ///
+    /// `value` is the value provided by the request, or the default value of
+    /// the variable. Returns the value to use in its place.
+    fn transform_variable(
+        &self,
+        ctx: &ContextDirective<'_>,
+        name: &str,
+        value: Option<Value>,
+    ) -> ServerResult<Option<Value>> {
+        Ok(value)
+    }
+
+    /// Called at execute the query or mutation operation the directive is
+    /// applied to.
+    ///
+    /// The response of `execute` can be post-processed, or replaced without
+    /// executing the operation.
+    async fn execute_operation(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        execute: ExecuteOperationFut<'_>,
+    ) -> Response {
+        execute.await
+    }
+}
+
+fn find_factory<'a>(
+    schema_env: &'a SchemaEnv,
+    directive: &Positioned<Directive>,
+) -> Option<&'a dyn CustomDirectiveFactory> {
+    schema_env
+        .custom_directives
+        .get(directive.node.name.node.as_str())
+        .map(AsRef::as_ref)
+}
+
+/// Applies the custom directives of the variable definitions of the
+/// operation.
+pub(crate) fn apply_variable_directives(
+    schema_env: &SchemaEnv,
+    env: &mut QueryEnv,
+) -> ServerResult<()> {
+    let mut values = Vec::new();
+
+    for variable_definition in &env.operation.node.variable_definitions {
+        let name = &variable_definition.node.name.node;
+        let mut value = None;
+        let mut transformed = false;
+
+        for directive in &variable_definition.node.directives {
+            if let Some(factory) = find_factory(schema_env, directive) {
+                if !transformed {
+                    value = env
+                        .variables
+                        .get(name)
+                        .or_else(|| {
+                            variable_definition
+                                .node
+                                .default_value
+                                .as_ref()
+                                .map(|value| &value.node)
+                        })
+                        .cloned();
+                    transformed = true;
+                }
+
+                let ctx_directive = env.create_context(schema_env, None, directive, None);
+                let directive_instance = factory.create(&ctx_directive, &directive.node)?;
+                value = directive_instance.transform_variable(&ctx_directive, name, value)?;
+            }
+        }
+
+        if transformed {
+            values.push((name.clone(), value));
+        }
+    }
+
+    if !values.is_empty() {
+        let variables = &mut env.get_mut().variables;
+        for (name, value) in values {
+            match value {
+                Some(value) => variables.insert(name, value),
+                None => variables.remove(&name),
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies the custom directives of the selections of the operation and the
+/// fragments.
+pub(crate) fn apply_selection_directives(
+    schema_env: &SchemaEnv,
+    env: &mut QueryEnv,
+) -> ServerResult<()> {
+    if schema_env.custom_directives.is_empty() {
+        return Ok(());
+    }
+
+    let mut operation_selection_set = None;
+    if contains_custom_directives(schema_env, &env.operation.node.selection_set.node) {
+        let mut selection_set = env.operation.node.selection_set.node.clone();
+        transform_selection_set(schema_env, env, &mut selection_set)?;
+        operation_selection_set = Some(selection_set);
+    }
+
+    let mut fragment_selection_sets = Vec::new();
+    for (name, fragment) in &env.fragments {
+        if contains_custom_directives(schema_env, &fragment.node.selection_set.node) {
+            let mut selection_set = fragment.node.selection_set.node.clone();
+            transform_selection_set(schema_env, env, &mut selection_set)?;
+            fragment_selection_sets.push((name.clone(), selection_set));
+        }
+    }
+
+    if operation_selection_set.is_some() || !fragment_selection_sets.is_empty() {
+        let env = env.get_mut();
+        if let Some(selection_set) = operation_selection_set {
+            env.operation.node.selection_set.node = selection_set;
+        }
+        for (name, selection_set) in fragment_selection_sets {
+            if let Some(fragment) = env.fragments.get_mut(&name) {
+                fragment.node.selection_set.node = selection_set;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn contains_custom_directives(
+    schema_env: &SchemaEnv,
+    selection_set: &SelectionSet,
+) -> bool {
+    selection_set.items.iter().any(|selection| {
+        selection
+            .node
+            .directives()
+            .iter()
+            .any(|directive| find_factory(schema_env, directive).is_some())
+            || match &selection.node {
+                Selection::Field(field) => {
+                    contains_custom_directives(schema_env, &field.node.selection_set.node)
+                }
+                Selection::FragmentSpread(_) => false,
+                Selection::InlineFragment(inline_fragment) => {
+                    contains_custom_directives(schema_env, &inline_fragment.node.selection_set.node)
+                }
+            }
+    })
+}
+
+fn transform_selection_set(
+    schema_env: &SchemaEnv,
+    env: &QueryEnv,
+    selection_set: &mut SelectionSet,
+) -> ServerResult<()> {
+    let items = std::mem::take(&mut selection_set.items);
+
+    for selection in items {
+        let directives = selection.node.directives().clone();
+        let mut selection = Some(selection);
+
+        for directive in &directives {
+            if let Some(factory) = find_factory(schema_env, directive) {
+                let ctx_directive = env.create_context(schema_env, None, directive, None);
+                let directive_instance = factory.create(&ctx_directive, &directive.node)?;
+                selection = match selection {
+                    Some(selection) => {
+                        directive_instance.transform_selection(&ctx_directive, selection)?
+                    }
+                    None => break,
+                };
+            }
+        }
+
+        if let Some(mut selection) = selection {
+            match &mut selection.node {
+                Selection::Field(field) => {
+                    transform_selection_set(schema_env, env, &mut field.node.selection_set.node)?
+                }
+                Selection::FragmentSpread(_) => {}
+                Selection::InlineFragment(inline_fragment) => transform_selection_set(
+                    schema_env,
+                    env,
+                    &mut inline_fragment.node.selection_set.node,
+                )?,
+            }
+            selection_set.items.push(selection);
+        }
+    }
+
+    Ok(())
+}
+
+/// Executes the operation, wrapped by the custom directives of the operation.
+pub(crate) async fn execute_operation_directives<'a>(
+    ctx: &'a ContextSelectionSet<'a>,
+    execute: BoxFuture<'a, Response>,
+) -> Response {
+    let mut execute = execute;
+
+    for directive in &ctx.query_env.operation.node.directives {
+        if let Some(factory) = find_factory(ctx.schema_env, directive) {
+            let ctx_directive = ContextDirective {
+                path_node: None,
+                is_for_introspection: false,
+                item: directive,
+                schema_env: ctx.schema_env,
+                query_env: ctx.query_env,
+                execute_data: ctx.execute_data,
+            };
+            let directive_instance = match factory.create(&ctx_directive, &directive.node) {
+                Ok(directive_instance) => directive_instance,
+                Err(err) => return Response::from_errors(vec![err]),
+            };
+            execute = Box::pin(async move {
+                directive_instance
+                    .execute_operation(ctx, &mut execute)
+                    .await
+            });
+        }
+    }
+
+    execute.await
 }
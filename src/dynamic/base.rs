@@ -1,4 +1,23 @@
-use crate::dynamic::{Field, InputValue, Interface, InterfaceField, Object, TypeRef};
+use std::fmt::{self, Debug};
+
+use crate::{
+    dynamic::{Field, InputValue, Interface, InterfaceField, Object, TypeRef},
+    registry::MetaVisibleFn,
+};
+
+/// The visibility function of a dynamic type, field or value.
+#[derive(Clone)]
+pub(crate) struct VisibleFn(pub(crate) MetaVisibleFn);
+
+impl Debug for VisibleFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("VisibleFn")
+    }
+}
+
+pub(crate) fn to_meta_visible(visible: &Option<VisibleFn>) -> Option<MetaVisibleFn> {
+    visible.as_ref().map(|visible| visible.0.clone())
+}
 
 pub(crate) trait BaseField {
     fn ty(&self) -> &TypeRef;
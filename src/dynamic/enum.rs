@@ -1,6 +1,10 @@
 use indexmap::IndexMap;
 
-use super::{Directive, directive::to_meta_directive_invocation};
+use super::{
+    Directive,
+    base::{VisibleFn, to_meta_visible},
+    directive::to_meta_directive_invocation,
+};
 use crate::{
     dynamic::SchemaError,
     registry::{Deprecation, MetaEnumValue, MetaType, Registry},
@@ -15,6 +19,7 @@ pub struct EnumItem {
     inaccessible: bool,
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
+    visible: Option<VisibleFn>,
 }
 
 impl<T: Into<String>> From<T> for EnumItem {
@@ -27,6 +32,7 @@ impl<T: Into<String>> From<T> for EnumItem {
             inaccessible: false,
             tags: Vec::new(),
             directives: Vec::new(),
+            visible: None,
         }
     }
 }
@@ -42,6 +48,7 @@ impl EnumItem {
     impl_set_deprecation!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_visible!();
     impl_directive!();
 }
 
@@ -55,6 +62,7 @@ pub struct Enum {
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     requires_scopes: Vec<String>,
+    visible: Option<VisibleFn>,
 }
 
 impl Enum {
@@ -69,10 +77,12 @@ impl Enum {
             tags: Vec::new(),
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            visible: None,
         }
     }
 
     impl_set_description!();
+    impl_set_visible!();
     impl_directive!();
 
     /// Add an item
@@ -111,7 +121,7 @@ impl Enum {
                     name: item.name.as_str().into(),
                     description: item.description.clone(),
                     deprecation: item.deprecation.clone(),
-                    visible: None,
+                    visible: to_meta_visible(&item.visible),
                     inaccessible: item.inaccessible,
                     tags: item.tags.clone(),
                     directive_invocations: to_meta_directive_invocation(item.directives.clone()),
@@ -125,7 +135,7 @@ impl Enum {
                 name: self.name.clone(),
                 description: self.description.clone(),
                 enum_values,
-                visible: None,
+                visible: to_meta_visible(&self.visible),
                 inaccessible: self.inaccessible,
                 tags: self.tags.clone(),
                 rust_typename: None,
@@ -198,7 +208,11 @@ mod tests {
             vec![ServerError {
                 message: "internal: invalid item for enum \"MyEnum\"".to_owned(),
                 source: None,
-                locations: vec![Pos { column: 3, line: 1 }],
+                locations: vec![Pos {
+                    column: 3,
+                    line: 1,
+                    ..Default::default()
+                }],
                 path: vec![PathSegment::Field("errValue".to_owned())],
                 extensions: None,
             }]
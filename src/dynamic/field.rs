@@ -3,16 +3,18 @@ use std::{
     borrow::Cow,
     fmt::{self, Debug},
     ops::Deref,
+    sync::Arc,
 };
 
 use futures_util::{Future, FutureExt, future::BoxFuture};
 use indexmap::IndexMap;
 
-use super::Directive;
+use super::{Directive, base::VisibleFn};
 use crate::{
-    Context, Error, Result, Value,
+    CacheControl, Context, Error, Guard, Positioned, Result, SelectionField, Value,
     dynamic::{InputValue, ObjectAccessor, TypeRef},
-    registry::{Deprecation, SemanticNullability},
+    parser::types::{Selection, SelectionSet, TypeCondition},
+    registry::{Deprecation, MetaType, SemanticNullability},
 };
 
 /// A value returned from the resolver function
@@ -272,6 +274,150 @@ pub struct ResolverContext<'a> {
     pub args: ObjectAccessor<'a>,
     /// Parent value
     pub parent_value: &'a FieldValue<'a>,
+    /// The name of the type returned by the field, without list and non-null
+    /// wrappers
+    pub(crate) field_type: &'a str,
+}
+
+impl<'a> ResolverContext<'a> {
+    /// Returns the fields selected below this field when it resolves to the
+    /// object type `type_name`.
+    ///
+    /// Fields of inline fragments and fragment spreads are included when their
+    /// type condition is `type_name`, or an interface or union that `type_name`
+    /// belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_graphql::{Value, dynamic::*, value};
+    ///
+    /// // every field of `User` returns the names of the fields selected on it
+    /// let user = Object::new("User")
+    ///     .field(Field::new("id", TypeRef::named_nn(TypeRef::STRING), |ctx| {
+    ///         FieldFuture::new(async move { Ok(ctx.parent_value.as_value().cloned()) })
+    ///     }))
+    ///     .field(Field::new("name", TypeRef::named_nn(TypeRef::STRING), |ctx| {
+    ///         FieldFuture::new(async move { Ok(ctx.parent_value.as_value().cloned()) })
+    ///     }));
+    /// let query = Object::new("Query").field(Field::new(
+    ///     "user",
+    ///     TypeRef::named_nn("User"),
+    ///     |ctx| {
+    ///         let names = ctx
+    ///             .selection_fields_for("User")
+    ///             .into_iter()
+    ///             .map(|field| field.name())
+    ///             .collect::<Vec<_>>()
+    ///             .join(",");
+    ///         FieldFuture::new(async move { Ok(Some(Value::from(names))) })
+    ///     },
+    /// ));
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// let schema = Schema::build("Query", None, None)
+    ///     .register(user)
+    ///     .register(query)
+    ///     .finish()?;
+    ///
+    /// assert_eq!(
+    ///     schema
+    ///         .execute("{ user { id ... on User { name } } }")
+    ///         .await
+    ///         .into_result()
+    ///         .unwrap()
+    ///         .data,
+    ///     value!({ "user": { "id": "id,name", "name": "id,name" } })
+    /// );
+    /// # Ok::<_, SchemaError>(())
+    /// # }).unwrap();
+    /// ```
+    pub fn selection_fields_for(&self, type_name: &str) -> Vec<SelectionField<'a>> {
+        let mut fields = Vec::new();
+        collect_selection_fields(
+            &mut fields,
+            self.ctx,
+            &self.ctx.item.node.selection_set.node,
+            type_name,
+        );
+        fields
+    }
+
+    /// Returns the fields selected below this field for every object type
+    /// that the field can resolve to.
+    ///
+    /// For a field that returns an interface or a union, there is an entry for
+    /// each possible type, in the same form as
+    /// [`ResolverContext::selection_fields_for`]. For a field that returns an
+    /// object there is a single entry, and for a field that returns a scalar
+    /// or an enum the map is empty.
+    pub fn selection_fields_by_type(&self) -> IndexMap<&'a str, Vec<SelectionField<'a>>> {
+        let registry = &self.ctx.schema_env.registry;
+        match registry.types.get(self.field_type) {
+            Some(MetaType::Object { name, .. }) => {
+                std::iter::once((name.as_str(), self.selection_fields_for(name))).collect()
+            }
+            Some(ty @ (MetaType::Interface { .. } | MetaType::Union { .. })) => ty
+                .possible_types()
+                .into_iter()
+                .flatten()
+                .map(|name| (name.as_str(), self.selection_fields_for(name)))
+                .collect(),
+            _ => IndexMap::new(),
+        }
+    }
+}
+
+fn collect_selection_fields<'a>(
+    fields: &mut Vec<SelectionField<'a>>,
+    ctx: &'a Context<'a>,
+    selection_set: &'a SelectionSet,
+    type_name: &str,
+) {
+    let registry = &ctx.schema_env.registry;
+    let applies = |type_condition: Option<&Positioned<TypeCondition>>| match type_condition {
+        Some(type_condition) => {
+            let on = type_condition.node.on.node.as_str();
+            on == type_name
+                || registry
+                    .types
+                    .get(on)
+                    .is_some_and(|ty| ty.is_possible_type(type_name))
+        }
+        None => true,
+    };
+
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => fields.push(SelectionField {
+                fragments: &ctx.query_env.fragments,
+                field: &field.node,
+                context: ctx,
+            }),
+            Selection::InlineFragment(fragment) => {
+                if applies(fragment.node.type_condition.as_ref()) {
+                    collect_selection_fields(
+                        fields,
+                        ctx,
+                        &fragment.node.selection_set.node,
+                        type_name,
+                    );
+                }
+            }
+            Selection::FragmentSpread(spread) => {
+                if let Some(fragment) = ctx.query_env.fragments.get(&spread.node.fragment_name.node)
+                    && applies(Some(&fragment.node.type_condition))
+                {
+                    collect_selection_fields(
+                        fields,
+                        ctx,
+                        &fragment.node.selection_set.node,
+                        type_name,
+                    );
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Deref for ResolverContext<'a> {
@@ -316,6 +462,19 @@ impl<'a> FieldFuture<'a> {
 pub(crate) type BoxResolverFn =
     Box<dyn for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync>;
 
+pub(crate) type BoxGuardFn =
+    Arc<dyn for<'a> Fn(&'a Context<'_>) -> BoxFuture<'a, Result<()>> + Send + Sync>;
+
+pub(crate) type ComplexityFn =
+    Arc<dyn Fn(&ObjectAccessor<'_>, usize) -> Result<usize> + Send + Sync>;
+
+pub(crate) fn guard_fn<F>(f: F) -> BoxGuardFn
+where
+    F: for<'a> Fn(&'a Context<'_>) -> BoxFuture<'a, Result<()>> + Send + Sync + 'static,
+{
+    Arc::new(f)
+}
+
 /// A GraphQL field
 pub struct Field {
     pub(crate) name: String,
@@ -335,6 +494,10 @@ pub struct Field {
     pub(crate) directives: Vec<Directive>,
     pub(crate) requires_scopes: Vec<String>,
     pub(crate) semantic_nullability: SemanticNullability,
+    pub(crate) guard: Option<BoxGuardFn>,
+    pub(crate) visible: Option<VisibleFn>,
+    pub(crate) cache_control: CacheControl,
+    pub(crate) complexity: Option<ComplexityFn>,
 }
 
 impl Debug for Field {
@@ -376,6 +539,10 @@ impl Field {
             directives: Vec::new(),
             requires_scopes: Vec::new(),
             semantic_nullability: SemanticNullability::None,
+            guard: None,
+            visible: None,
+            cache_control: Default::default(),
+            complexity: None,
         }
     }
 
@@ -389,8 +556,56 @@ impl Field {
     impl_set_tags!();
     impl_set_override_from!();
     impl_set_semantic_nullability!();
+    impl_set_visible!();
+    impl_set_cache_control!();
     impl_directive!();
 
+    /// Set a guard that is checked before the field is resolved
+    ///
+    /// Combine several guards with [`GuardExt`](crate::GuardExt).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_graphql::{Context, Error, Value, dynamic::*};
+    ///
+    /// struct Role(&'static str);
+    ///
+    /// let field = Field::new("secret", TypeRef::named(TypeRef::STRING), |_| {
+    ///     FieldFuture::new(async move { Ok(Some(Value::from("abc"))) })
+    /// })
+    /// .guard(|ctx: &Context<'_>| match ctx.data_opt::<Role>() {
+    ///     Some(Role("admin")) => Ok(()),
+    ///     _ => Err(Error::new("forbidden")),
+    /// });
+    /// ```
+    pub fn guard<G: Guard + Send + Sync + 'static>(self, guard: G) -> Self {
+        let guard = Arc::new(guard);
+        Self {
+            guard: Some(guard_fn(move |ctx| {
+                let guard = guard.clone();
+                async move { guard.check(ctx).await }.boxed()
+            })),
+            ..self
+        }
+    }
+
+    /// Set a function that computes the complexity of the field
+    ///
+    /// The function receives the arguments of the field and the complexity of
+    /// its children, and returns the complexity of the field including its
+    /// children. By default, the complexity of a field is `1` plus the
+    /// complexity of its children.
+    pub fn complexity(
+        self,
+        f: impl Fn(&ObjectAccessor<'_>, usize) -> Result<usize> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            complexity: Some(Arc::new(f)),
+            ..self
+        }
+    }
+
     /// Add an argument to the field
     #[inline]
     pub fn argument(mut self, input_value: InputValue) -> Self {
@@ -1,11 +1,24 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
 use indexmap::IndexMap;
 
-use super::{Directive, directive::to_meta_directive_invocation};
+use super::{
+    Directive,
+    base::{VisibleFn, to_meta_visible},
+    directive::to_meta_directive_invocation,
+};
 use crate::{
-    dynamic::InputValue,
+    Error, Name, PathSegment, Value,
+    dynamic::{InputValue, ObjectAccessor, Type, TypeRef},
+    error::input_path_value,
     registry::{MetaInputValue, MetaType, Registry},
 };
 
+type InputObjectValidatorFn = Arc<dyn Fn(&ObjectAccessor<'_>) -> Result<(), Error> + Send + Sync>;
+
 /// A GraphQL input object type
 ///
 /// # Examples
@@ -50,15 +63,30 @@ use crate::{
 /// # Ok::<_, SchemaError>(())
 /// # }).unwrap();
 /// ```
-#[derive(Debug)]
 pub struct InputObject {
     pub(crate) name: String,
     pub(crate) description: Option<String>,
     pub(crate) fields: IndexMap<String, InputValue>,
     pub(crate) oneof: bool,
+    validators: Vec<InputObjectValidatorFn>,
     inaccessible: bool,
     tags: Vec<String>,
     directives: Vec<Directive>,
+    visible: Option<VisibleFn>,
+}
+
+impl Debug for InputObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputObject")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("fields", &self.fields)
+            .field("oneof", &self.oneof)
+            .field("inaccessible", &self.inaccessible)
+            .field("tags", &self.tags)
+            .field("directives", &self.directives)
+            .finish()
+    }
 }
 
 impl InputObject {
@@ -70,15 +98,18 @@ impl InputObject {
             description: None,
             fields: Default::default(),
             oneof: false,
+            validators: Vec::new(),
             inaccessible: false,
             tags: Vec::new(),
             directives: Vec::new(),
+            visible: None,
         }
     }
 
     impl_set_description!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_visible!();
     impl_directive!();
 
     /// Add a field
@@ -101,12 +132,57 @@ impl InputObject {
         }
     }
 
+    /// Add a validator that checks the input object as a whole
+    ///
+    /// Validators run after the input fields themselves have been checked, in
+    /// the order they were added. A returned error is reported with the path
+    /// of the input object in the `inputPath` extension.
+    #[inline]
+    pub fn validator(
+        mut self,
+        validator: impl Fn(&ObjectAccessor<'_>) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+
     /// Returns the type name
     #[inline]
     pub fn type_name(&self) -> &str {
         &self.name
     }
 
+    pub(crate) fn validate(
+        &self,
+        types: &IndexMap<String, Type>,
+        value: &IndexMap<Name, Value>,
+        path: &mut Vec<PathSegment>,
+    ) -> Result<(), Error> {
+        for field in self.fields.values() {
+            if let Some(field_value) = value.get(field.name.as_str()) {
+                path.push(PathSegment::Field(field.name.clone()));
+                field.validate(types, field_value, path)?;
+                path.pop();
+            }
+        }
+
+        let accessor = ObjectAccessor(std::borrow::Cow::Borrowed(value));
+        for validator in &self.validators {
+            (validator)(&accessor).map_err(|err| {
+                let mut err = Error {
+                    message: format!(r#"Failed to parse "{}": {}"#, self.name, err.message),
+                    ..err
+                };
+                err.extensions
+                    .get_or_insert_with(Default::default)
+                    .set("inputPath", input_path_value(path));
+                err
+            })?;
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn register(&self, registry: &mut Registry) -> Result<(), super::SchemaError> {
         let mut input_fields = IndexMap::new();
 
@@ -119,7 +195,7 @@ impl InputObject {
                     ty: field.ty.to_string(),
                     deprecation: field.deprecation.clone(),
                     default_value: field.default_value.as_ref().map(ToString::to_string),
-                    visible: None,
+                    visible: to_meta_visible(&field.visible),
                     inaccessible: self.inaccessible,
                     tags: self.tags.clone(),
                     is_secret: false,
@@ -134,7 +210,7 @@ impl InputObject {
                 name: self.name.clone(),
                 description: self.description.clone(),
                 input_fields,
-                visible: None,
+                visible: to_meta_visible(&self.visible),
                 inaccessible: self.inaccessible,
                 tags: self.tags.clone(),
                 rust_typename: None,
@@ -147,9 +223,36 @@ impl InputObject {
     }
 }
 
+/// Runs the validators of every input object reachable from `value`, which
+/// is an input value of type `ty`. `path` holds the input path of `value`.
+pub(crate) fn validate_input_value(
+    types: &IndexMap<String, Type>,
+    ty: &TypeRef,
+    value: &Value,
+    path: &mut Vec<PathSegment>,
+) -> Result<(), Error> {
+    match (ty, value) {
+        (TypeRef::NonNull(ty), _) => validate_input_value(types, ty, value, path),
+        (TypeRef::List(ty), Value::List(items)) => {
+            for (idx, item) in items.iter().enumerate() {
+                path.push(PathSegment::Index(idx));
+                validate_input_value(types, ty, item, path)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        (TypeRef::List(ty), _) => validate_input_value(types, ty, value, path),
+        (TypeRef::Named(name), Value::Object(obj)) => match types.get(name.as_ref()) {
+            Some(Type::InputObject(object)) => object.validate(types, obj, path),
+            _ => Ok(()),
+        },
+        (TypeRef::Named(_), _) => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Pos, ServerError, Value, dynamic::*, value};
+    use crate::{ErrorExtensionValues, PathSegment, Pos, ServerError, Value, dynamic::*, value};
 
     #[tokio::test]
     async fn input_object() {
@@ -191,6 +294,176 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn input_object_validator() {
+        let range = InputObject::new("Range")
+            .field(InputValue::new("start", TypeRef::named_nn(TypeRef::INT)))
+            .field(InputValue::new("end", TypeRef::named_nn(TypeRef::INT)))
+            .validator(|obj| {
+                if obj.try_get("end")?.i64()? < obj.try_get("start")?.i64()? {
+                    Err("end must not be before start".into())
+                } else {
+                    Ok(())
+                }
+            });
+        let event = InputObject::new("Event")
+            .field(InputValue::new("name", TypeRef::named_nn(TypeRef::STRING)))
+            .field(InputValue::new(
+                "ranges",
+                TypeRef::named_nn_list_nn("Range"),
+            ));
+        let query = Object::new("Query").field(
+            Field::new("count", TypeRef::named_nn(TypeRef::INT), |ctx| {
+                FieldFuture::new(async move {
+                    let event = ctx.args.try_get("event")?.object()?;
+                    let ranges = event.try_get("ranges")?.list()?;
+                    Ok(Some(Value::from(ranges.len())))
+                })
+            })
+            .argument(InputValue::new(
+                "event",
+                TypeRef::named_nn(event.type_name()),
+            )),
+        );
+
+        let schema = Schema::build(query.type_name(), None, None)
+            .register(query)
+            .register(event)
+            .register(range)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute(r#"{ count(event: { name: "a", ranges: [{ start: 1, end: 2 }] }) }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "count": 1 })
+        );
+
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set("inputPath", value!(["event", "ranges", 1]));
+        assert_eq!(
+            schema
+                .execute(
+                    r#"{ count(event: { name: "a", ranges: [{ start: 1, end: 2 }, { start: 2, end: 1 }] }) }"#
+                )
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: r#"Failed to parse "Range": end must not be before start"#.to_owned(),
+                source: None,
+                locations: vec![Pos {
+                    column: 16,
+                    line: 1,
+                    ..Default::default()
+                }],
+                path: vec![PathSegment::Field("count".to_owned())],
+                extensions: Some(extensions),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn input_value_validator() {
+        let range = InputObject::new("Range")
+            .field(InputValue::new("start", TypeRef::named_nn(TypeRef::INT)).minimum(0.0))
+            .field(InputValue::new("end", TypeRef::named_nn(TypeRef::INT)).maximum(100.0));
+        let query = Object::new("Query").field(
+            Field::new("count", TypeRef::named_nn(TypeRef::INT), |ctx| {
+                FieldFuture::new(async move {
+                    let tags = ctx.args.try_get("tags")?.list()?;
+                    Ok(Some(Value::from(tags.len())))
+                })
+            })
+            .argument(
+                InputValue::new("tags", TypeRef::named_nn_list_nn(TypeRef::STRING))
+                    .max_items(3)
+                    .max_length(5),
+            )
+            .argument(InputValue::new("range", TypeRef::named(range.type_name())))
+            .argument(
+                InputValue::new("name", TypeRef::named(TypeRef::STRING)).validator(|value| {
+                    if value.string()?.starts_with('_') {
+                        Err("must not start with an underscore".into())
+                    } else {
+                        Ok(())
+                    }
+                }),
+            ),
+        );
+
+        let schema = Schema::build(query.type_name(), None, None)
+            .register(query)
+            .register(range)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute(r#"{ count(tags: ["a", "b"], range: { start: 0, end: 10 }, name: null) }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "count": 2 })
+        );
+
+        let check = |query: &'static str, message: &'static str, column, input_path| {
+            let schema = schema.clone();
+            async move {
+                let mut extensions = ErrorExtensionValues::default();
+                extensions.set("inputPath", input_path);
+                assert_eq!(
+                    schema.execute(query).await.into_result().unwrap_err(),
+                    vec![ServerError {
+                        message: message.to_owned(),
+                        source: None,
+                        locations: vec![Pos {
+                            column,
+                            line: 1,
+                            ..Default::default()
+                        }],
+                        path: vec![PathSegment::Field("count".to_owned())],
+                        extensions: Some(extensions),
+                    }]
+                );
+            }
+        };
+
+        check(
+            r#"{ count(tags: ["a", "abcdef"]) }"#,
+            r#"Failed to parse "[String!]": the string length is 6, must be less than or equal to 5"#,
+            15,
+            value!(["tags", 1]),
+        )
+        .await;
+        check(
+            r#"{ count(tags: ["a", "b", "c", "d"]) }"#,
+            r#"Failed to parse "[String!]": the value length is 4, must be less than or equal to 3"#,
+            15,
+            value!(["tags"]),
+        )
+        .await;
+        check(
+            r#"{ count(tags: [], range: { start: -1, end: 10 }) }"#,
+            r#"Failed to parse "Int": the value is -1, must be greater than or equal to 0"#,
+            26,
+            value!(["range", "start"]),
+        )
+        .await;
+        check(
+            r#"{ count(tags: [], name: "_a") }"#,
+            r#"Failed to parse "String": must not start with an underscore"#,
+            25,
+            value!(["name"]),
+        )
+        .await;
+    }
+
     #[tokio::test]
     async fn oneof_input_object() {
         let myinput = InputObject::new("MyInput")
@@ -257,7 +530,7 @@ mod tests {
             vec![ServerError {
                 message: "Invalid value for argument \"input\", Oneof input objects requires have exactly one field".to_owned(),
                 source: None,
-                locations: vec![Pos { column: 9, line: 1 }],
+                locations: vec![Pos { column: 9, line: 1, ..Default::default() }],
                 path: vec![],
                 extensions: None,
             }]
@@ -272,7 +545,7 @@ mod tests {
             vec![ServerError {
                 message: "Invalid value for argument \"input\", Oneof input objects requires have exactly one field".to_owned(),
                 source: None,
-                locations: vec![Pos { column: 9, line: 1 }],
+                locations: vec![Pos { column: 9, line: 1, ..Default::default() }],
                 path: vec![],
                 extensions: None,
             }]
@@ -1,12 +1,75 @@
-use super::{Directive, directive::to_meta_directive_invocation};
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use indexmap::IndexMap;
+
+use super::{
+    Directive,
+    base::{VisibleFn, to_meta_visible},
+    directive::to_meta_directive_invocation,
+    input_object::validate_input_value,
+};
 use crate::{
-    Value,
-    dynamic::TypeRef,
+    Error, InputValueError, PathSegment, Value,
+    dynamic::{Type, TypeRef, ValueAccessor},
+    error::input_path_value,
     registry::{Deprecation, MetaInputValue},
+    validators,
 };
 
+/// A validator returns the indexes of the list item that failed the check, if
+/// the check applies to the items of lists.
+type InputValueValidatorFn =
+    Arc<dyn Fn(&ValueAccessor<'_>) -> Result<(), (Vec<usize>, Error)> + Send + Sync>;
+
 /// A GraphQL input value type
-#[derive(Debug)]
+///
+/// Validators can be attached to arguments and input object fields. They run
+/// before the resolver of the field, and a failed check is reported as an
+/// error of the argument with the path of the value in the `inputPath`
+/// extension.
+///
+/// # Examples
+///
+/// ```
+/// use async_graphql::{dynamic::*, value, Value};
+///
+/// let query = Object::new("Query").field(
+///     Field::new("hello", TypeRef::named_nn(TypeRef::STRING), |ctx| {
+///         FieldFuture::new(async move {
+///             let name = ctx.args.try_get("name")?;
+///             Ok(Some(Value::from(format!("Hello {}!", name.string()?))))
+///         })
+///     })
+///     .argument(
+///         InputValue::new("name", TypeRef::named_nn(TypeRef::STRING))
+///             .min_length(1)
+///             .max_length(5),
+///     ),
+/// );
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+///
+/// let schema = Schema::build(query.type_name(), None, None)
+///     .register(query)
+///     .finish()?;
+///
+/// assert!(schema.execute(r#"{ hello(name: "abc") }"#).await.is_ok());
+/// assert_eq!(
+///     schema
+///         .execute(r#"{ hello(name: "abcdef") }"#)
+///         .await
+///         .into_result()
+///         .unwrap_err()[0]
+///         .message,
+///     r#"Failed to parse "String": the string length is 6, must be less than or equal to 5"#
+/// );
+///
+/// # Ok::<_, SchemaError>(())
+/// # }).unwrap();
+/// ```
 pub struct InputValue {
     pub(crate) name: String,
     pub(crate) description: Option<String>,
@@ -16,6 +79,24 @@ pub struct InputValue {
     pub(crate) tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     pub(crate) deprecation: Deprecation,
+    pub(crate) visible: Option<VisibleFn>,
+    validators: Vec<InputValueValidatorFn>,
+}
+
+impl Debug for InputValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputValue")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("ty", &self.ty)
+            .field("default_value", &self.default_value)
+            .field("inaccessible", &self.inaccessible)
+            .field("tags", &self.tags)
+            .field("directives", &self.directives)
+            .field("deprecation", &self.deprecation)
+            .field("visible", &self.visible)
+            .finish()
+    }
 }
 
 impl InputValue {
@@ -31,6 +112,8 @@ impl InputValue {
             tags: Vec::new(),
             directives: vec![],
             deprecation: Deprecation::NoDeprecated,
+            visible: None,
+            validators: Vec::new(),
         }
     }
 
@@ -39,6 +122,7 @@ impl InputValue {
     impl_set_tags!();
     impl_directive!();
     impl_set_deprecation!();
+    impl_set_visible!();
 
     /// Set the default value
     #[inline]
@@ -49,6 +133,198 @@ impl InputValue {
         }
     }
 
+    /// Add a custom validator
+    ///
+    /// The validator is not called for `null` values. Validators run after the
+    /// value has been checked against its type, in the order they were added.
+    #[inline]
+    pub fn validator(
+        mut self,
+        validator: impl Fn(&ValueAccessor<'_>) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Arc::new(move |value| {
+            validator(value).map_err(|err| (Vec::new(), err))
+        }));
+        self
+    }
+
+    fn item_validator(
+        mut self,
+        validator: impl Fn(&ValueAccessor<'_>) -> Result<(), (Vec<usize>, Error)>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+
+    /// Check that the length of a string is less than or equal to `len`
+    ///
+    /// Like the other string and number validators, it checks every item
+    /// when the value is a list.
+    #[inline]
+    pub fn max_length(self, len: usize) -> Self {
+        self.item_validator(string_validator(move |value| {
+            validators::max_length(value, len)
+        }))
+    }
+
+    /// Check that the length of a string is greater than or equal to `len`
+    #[inline]
+    pub fn min_length(self, len: usize) -> Self {
+        self.item_validator(string_validator(move |value| {
+            validators::min_length(value, len)
+        }))
+    }
+
+    /// Check that the number of characters of a string is less than or equal
+    /// to `len`
+    #[inline]
+    pub fn chars_max_length(self, len: usize) -> Self {
+        self.item_validator(string_validator(move |value| {
+            validators::chars_max_length(value, len)
+        }))
+    }
+
+    /// Check that the number of characters of a string is greater than or
+    /// equal to `len`
+    #[inline]
+    pub fn chars_min_length(self, len: usize) -> Self {
+        self.item_validator(string_validator(move |value| {
+            validators::chars_min_length(value, len)
+        }))
+    }
+
+    /// Check that a string matches the regular expression
+    #[inline]
+    pub fn regex(self, regex: &'static str) -> Self {
+        self.item_validator(string_validator(move |value| {
+            validators::regex(value, regex)
+        }))
+    }
+
+    /// Check that a string is a valid email address
+    #[cfg(feature = "email-validator")]
+    #[inline]
+    pub fn email(self) -> Self {
+        self.item_validator(string_validator(validators::email))
+    }
+
+    /// Check that a string is a valid URL
+    #[inline]
+    pub fn url(self) -> Self {
+        self.item_validator(string_validator(validators::url))
+    }
+
+    /// Check that a string is a valid IP address
+    #[inline]
+    pub fn ip(self) -> Self {
+        self.item_validator(string_validator(validators::ip))
+    }
+
+    /// Check that a string is a valid UUID, optionally of the given version
+    #[inline]
+    pub fn uuid(self, version: Option<usize>) -> Self {
+        self.item_validator(string_validator(move |value| {
+            validators::uuid(value, version)
+        }))
+    }
+
+    /// Check that the strength of a password is greater than or equal to
+    /// `min_score`
+    #[cfg(feature = "password-strength-validator")]
+    #[inline]
+    pub fn min_password_strength(self, min_score: u8) -> Self {
+        self.item_validator(string_validator(move |value| {
+            validators::min_password_strength(value, min_score)
+        }))
+    }
+
+    /// Check that a number is less than or equal to `n`
+    #[inline]
+    pub fn maximum(self, n: f64) -> Self {
+        self.item_validator(number_validator(move |value| validators::maximum(value, n)))
+    }
+
+    /// Check that a number is greater than or equal to `n`
+    #[inline]
+    pub fn minimum(self, n: f64) -> Self {
+        self.item_validator(number_validator(move |value| validators::minimum(value, n)))
+    }
+
+    /// Check that a number is a multiple of `n`
+    #[inline]
+    pub fn multiple_of(self, n: f64) -> Self {
+        self.item_validator(number_validator(move |value| {
+            validators::multiple_of(value, n)
+        }))
+    }
+
+    /// Check that the length of a list is less than or equal to `len`
+    #[inline]
+    pub fn max_items(self, len: usize) -> Self {
+        self.validator(move |value| match value.as_value() {
+            Value::List(items) if items.len() > len => Err(Error::new(format!(
+                "the value length is {}, must be less than or equal to {}",
+                items.len(),
+                len
+            ))),
+            _ => Ok(()),
+        })
+    }
+
+    /// Check that the length of a list is greater than or equal to `len`
+    #[inline]
+    pub fn min_items(self, len: usize) -> Self {
+        self.validator(move |value| match value.as_value() {
+            Value::List(items) if items.len() < len => Err(Error::new(format!(
+                "the value length is {}, must be greater than or equal to {}",
+                items.len(),
+                len
+            ))),
+            _ => Ok(()),
+        })
+    }
+
+    /// Checks `value` against the type and the validators of this input
+    /// value. `path` holds the input path of `value`.
+    pub(crate) fn validate(
+        &self,
+        types: &IndexMap<String, Type>,
+        value: &Value,
+        path: &mut Vec<PathSegment>,
+    ) -> Result<(), Error> {
+        validate_input_value(types, &self.ty, value, path)?;
+        if matches!(value, Value::Null) {
+            return Ok(());
+        }
+
+        // the name of a non-null type is reported without the `!`, like in
+        // the errors of the derived input types
+        let type_name = match &self.ty {
+            TypeRef::NonNull(ty) => ty.to_string(),
+            ty => ty.to_string(),
+        };
+        let accessor = ValueAccessor(value);
+        for validator in &self.validators {
+            (validator)(&accessor).map_err(|(indexes, err)| {
+                let mut err = Error {
+                    message: format!(r#"Failed to parse "{}": {}"#, type_name, err.message),
+                    ..err
+                };
+                let mut path = path.clone();
+                path.extend(indexes.into_iter().map(PathSegment::Index));
+                err.extensions
+                    .get_or_insert_with(Default::default)
+                    .set("inputPath", input_path_value(&path));
+                err
+            })?;
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn to_meta_input_value(&self) -> MetaInputValue {
         MetaInputValue {
             name: self.name.clone(),
@@ -59,7 +335,7 @@ impl InputValue {
                 .default_value
                 .as_ref()
                 .map(std::string::ToString::to_string),
-            visible: None,
+            visible: to_meta_visible(&self.visible),
             inaccessible: self.inaccessible,
             tags: self.tags.clone(),
             is_secret: false,
@@ -67,3 +343,58 @@ impl InputValue {
         }
     }
 }
+
+/// Applies a validator for `String` values to a string, or to every string in
+/// a list.
+fn string_validator(
+    f: impl Fn(&String) -> Result<(), InputValueError<String>> + Send + Sync + 'static,
+) -> impl Fn(&ValueAccessor<'_>) -> Result<(), (Vec<usize>, Error)> + Send + Sync + 'static {
+    fn check(
+        f: &dyn Fn(&String) -> Result<(), InputValueError<String>>,
+        value: &Value,
+    ) -> Result<(), (Vec<usize>, Error)> {
+        match value {
+            Value::String(s) => {
+                f(s).map_err(|err| (Vec::new(), Error::new(err.into_raw_message())))
+            }
+            Value::List(items) => check_items(items, |item| check(f, item)),
+            _ => Ok(()),
+        }
+    }
+    move |value| check(&f, value.as_value())
+}
+
+/// Applies a validator for `f64` values to a number, or to every number in a
+/// list.
+fn number_validator(
+    f: impl Fn(&f64) -> Result<(), InputValueError<f64>> + Send + Sync + 'static,
+) -> impl Fn(&ValueAccessor<'_>) -> Result<(), (Vec<usize>, Error)> + Send + Sync + 'static {
+    fn check(
+        f: &dyn Fn(&f64) -> Result<(), InputValueError<f64>>,
+        value: &Value,
+    ) -> Result<(), (Vec<usize>, Error)> {
+        match value {
+            Value::Number(n) => match n.as_f64() {
+                Some(n) => f(&n).map_err(|err| (Vec::new(), Error::new(err.into_raw_message()))),
+                None => Ok(()),
+            },
+            Value::List(items) => check_items(items, |item| check(f, item)),
+            _ => Ok(()),
+        }
+    }
+    move |value| check(&f, value.as_value())
+}
+
+/// Checks every item of a list, and adds the index of the item that failed
+/// the check to the error.
+fn check_items(
+    items: &[Value],
+    check: impl Fn(&Value) -> Result<(), (Vec<usize>, Error)>,
+) -> Result<(), (Vec<usize>, Error)> {
+    items.iter().enumerate().try_for_each(|(idx, item)| {
+        check(item).map_err(|(mut indexes, err)| {
+            indexes.insert(0, idx);
+            (indexes, err)
+        })
+    })
+}
@@ -1,8 +1,21 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use futures_util::FutureExt;
 use indexmap::{IndexMap, IndexSet};
 
-use super::{Directive, directive::to_meta_directive_invocation};
+use super::{
+    Directive,
+    base::{VisibleFn, to_meta_visible},
+    directive::to_meta_directive_invocation,
+    field::{BoxGuardFn, ComplexityFn, guard_fn},
+    object::to_compute_complexity,
+};
 use crate::{
-    dynamic::{InputValue, SchemaError, TypeRef},
+    CacheControl, Guard, Result,
+    dynamic::{InputValue, ObjectAccessor, SchemaError, TypeRef},
     registry::{Deprecation, MetaField, MetaType, Registry, SemanticNullability},
 };
 
@@ -82,7 +95,6 @@ use crate::{
 /// # Ok::<_, SchemaError>(())
 /// # }).unwrap();
 /// ```
-#[derive(Debug)]
 pub struct InterfaceField {
     pub(crate) name: String,
     pub(crate) description: Option<String>,
@@ -99,6 +111,22 @@ pub struct InterfaceField {
     pub(crate) directives: Vec<Directive>,
     pub(crate) requires_scopes: Vec<String>,
     pub(crate) semantic_nullability: SemanticNullability,
+    pub(crate) guard: Option<BoxGuardFn>,
+    pub(crate) visible: Option<VisibleFn>,
+    pub(crate) cache_control: CacheControl,
+    pub(crate) complexity: Option<ComplexityFn>,
+}
+
+impl Debug for InterfaceField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterfaceField")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("arguments", &self.arguments)
+            .field("ty", &self.ty)
+            .field("deprecation", &self.deprecation)
+            .finish()
+    }
 }
 
 impl InterfaceField {
@@ -120,6 +148,10 @@ impl InterfaceField {
             directives: Vec::new(),
             requires_scopes: Vec::new(),
             semantic_nullability: SemanticNullability::None,
+            guard: None,
+            visible: None,
+            cache_control: Default::default(),
+            complexity: None,
         }
     }
 
@@ -133,8 +165,39 @@ impl InterfaceField {
     impl_set_tags!();
     impl_set_override_from!();
     impl_set_semantic_nullability!();
+    impl_set_visible!();
+    impl_set_cache_control!();
     impl_directive!();
 
+    /// Set a guard that is checked before the field is resolved on any object
+    /// implementing the interface
+    ///
+    /// The guard runs in addition to the guard of the object field, if any.
+    pub fn guard<G: Guard + Send + Sync + 'static>(self, guard: G) -> Self {
+        let guard = Arc::new(guard);
+        Self {
+            guard: Some(guard_fn(move |ctx| {
+                let guard = guard.clone();
+                async move { guard.check(ctx).await }.boxed()
+            })),
+            ..self
+        }
+    }
+
+    /// Set a function that computes the complexity of the field when it is
+    /// selected on the interface type
+    ///
+    /// See also: [`Field::complexity`](crate::dynamic::Field::complexity)
+    pub fn complexity(
+        self,
+        f: impl Fn(&ObjectAccessor<'_>, usize) -> Result<usize> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            complexity: Some(Arc::new(f)),
+            ..self
+        }
+    }
+
     /// Add an argument to the field
     #[inline]
     pub fn argument(mut self, input_value: InputValue) -> Self {
@@ -156,6 +219,7 @@ pub struct Interface {
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     requires_scopes: Vec<String>,
+    visible: Option<VisibleFn>,
 }
 
 impl Interface {
@@ -173,6 +237,7 @@ impl Interface {
             tags: Vec::new(),
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            visible: None,
         }
     }
 
@@ -180,6 +245,7 @@ impl Interface {
     impl_set_extends!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_visible!();
     impl_directive!();
 
     /// Add a field to the interface type
@@ -244,16 +310,20 @@ impl Interface {
                     args,
                     ty: field.ty.to_string(),
                     deprecation: field.deprecation.clone(),
-                    cache_control: Default::default(),
+                    cache_control: field.cache_control,
+                    guarded: field.guard.is_some(),
                     external: field.external,
                     requires: field.requires.clone(),
                     provides: field.provides.clone(),
-                    visible: None,
+                    visible: to_meta_visible(&field.visible),
                     shareable: field.shareable,
                     inaccessible: field.inaccessible,
                     tags: field.tags.clone(),
                     override_from: field.override_from.clone(),
-                    compute_complexity: None,
+                    compute_complexity: to_compute_complexity(
+                        field.complexity.as_ref(),
+                        &field.arguments,
+                    ),
                     directive_invocations: to_meta_directive_invocation(field.directives.clone()),
                     requires_scopes: field.requires_scopes.clone(),
                     semantic_nullability: field.semantic_nullability,
@@ -274,7 +344,7 @@ impl Interface {
                 } else {
                     None
                 },
-                visible: None,
+                visible: to_meta_visible(&self.visible),
                 inaccessible: self.inaccessible,
                 tags: self.tags.clone(),
                 rust_typename: None,
@@ -406,7 +476,8 @@ mod tests {
                 source: None,
                 locations: vec![Pos {
                     column: 13,
-                    line: 3
+                    line: 3,
+                    ..Default::default()
                 }],
                 path: vec![PathSegment::Field("valueA".to_owned())],
                 extensions: None,
@@ -456,4 +527,70 @@ mod tests {
             })
         );
     }
+
+    #[tokio::test]
+    async fn interface_field_guard_complexity_and_cache_control() {
+        struct Role(&'static str);
+
+        let obj_a = Object::new("MyObjA")
+            .implement("MyInterface")
+            .field(Field::new("a", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(100))) })
+            }));
+        let interface = Interface::new("MyInterface").field(
+            InterfaceField::new("a", TypeRef::named(TypeRef::INT))
+                .guard(|ctx: &crate::Context<'_>| match ctx.data_opt::<Role>() {
+                    Some(Role("admin")) => Ok(()),
+                    _ => Err("forbidden".into()),
+                })
+                .complexity(|_, _| Ok(5))
+                .cache_control(crate::CacheControl {
+                    public: false,
+                    max_age: 30,
+                    ..Default::default()
+                }),
+        );
+        let query = Object::new("Query").field(Field::new(
+            "value",
+            TypeRef::named_nn(interface.type_name()),
+            |_| FieldFuture::new(async { Ok(Some(FieldValue::NULL.with_type("MyObjA"))) }),
+        ));
+        let schema = Schema::build(query.type_name(), None, None)
+            .register(obj_a)
+            .register(interface)
+            .register(query)
+            .limit_complexity(6)
+            .finish()
+            .unwrap();
+
+        let resp = schema
+            .execute(crate::Request::new("{ value { a } }").data(Role("admin")))
+            .await;
+        assert_eq!(resp.data, value!({ "value": { "a": 100 } }));
+        assert_eq!(
+            resp.cache_control,
+            crate::CacheControl {
+                public: false,
+                max_age: 30,
+                ..Default::default()
+            }
+        );
+
+        // the guard of the interface field also applies through the object type
+        let resp = schema
+            .execute(crate::Request::new("{ value { ... on MyObjA { a } } }").data(Role("guest")))
+            .await;
+        assert_eq!(resp.errors.len(), 1);
+        assert_eq!(resp.errors[0].message, "forbidden");
+
+        assert_eq!(
+            schema
+                .execute("{ value { a b: a } }")
+                .await
+                .into_result()
+                .unwrap_err()[0]
+                .message,
+            "Query is too complex."
+        );
+    }
 }
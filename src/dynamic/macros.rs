@@ -175,6 +175,37 @@ macro_rules! impl_set_semantic_nullability {
     };
 }
 
+macro_rules! impl_set_visible {
+    () => {
+        /// Set a function that decides whether this is visible in introspection
+        /// and in the exported SDL
+        #[inline]
+        pub fn visible(
+            self,
+            f: impl Fn(&crate::Context<'_>) -> bool + Send + Sync + 'static,
+        ) -> Self {
+            Self {
+                visible: Some(crate::dynamic::base::VisibleFn(std::sync::Arc::new(f))),
+                ..self
+            }
+        }
+    };
+}
+
+macro_rules! impl_set_cache_control {
+    () => {
+        /// Set the cache control hint used to compute the `Cache-Control`
+        /// header of responses
+        #[inline]
+        pub fn cache_control(self, cache_control: crate::CacheControl) -> Self {
+            Self {
+                cache_control,
+                ..self
+            }
+        }
+    };
+}
+
 macro_rules! impl_directive {
     () => {
         /// Attach directive to the entity
@@ -47,6 +47,7 @@ mod resolve;
 mod scalar;
 mod schema;
 mod subscription;
+mod swappable;
 mod r#type;
 mod type_ref;
 mod union;
@@ -65,6 +66,7 @@ pub use request::{DynamicRequest, DynamicRequestExt};
 pub use scalar::Scalar;
 pub use schema::{Schema, SchemaBuilder};
 pub use subscription::{Subscription, SubscriptionField, SubscriptionFieldFuture};
+pub use swappable::{BreakingChange, SchemaVersion, SwappableSchema};
 pub use r#type::Type;
 pub use type_ref::TypeRef;
 pub use union::Union;
@@ -1,9 +1,17 @@
+use std::{borrow::Cow, sync::Arc};
+
 use indexmap::{IndexMap, IndexSet};
 
-use super::{Directive, directive::to_meta_directive_invocation};
+use super::{
+    Directive,
+    base::{VisibleFn, to_meta_visible},
+    directive::to_meta_directive_invocation,
+};
 use crate::{
-    dynamic::{Field, SchemaError},
-    registry::{MetaField, MetaType, Registry},
+    CacheControl, Name, Positioned, ServerError, ServerResult, Value, VisitorContext,
+    dynamic::{Field, InputValue, ObjectAccessor, SchemaError, field::ComplexityFn},
+    parser::types::{Field as ParsedField, VariableDefinition},
+    registry::{ComputeComplexityFn, MetaField, MetaType, Registry},
 };
 
 /// A GraphQL object type
@@ -51,6 +59,8 @@ pub struct Object {
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     requires_scopes: Vec<String>,
+    visible: Option<VisibleFn>,
+    cache_control: CacheControl,
 }
 
 impl Object {
@@ -71,6 +81,8 @@ impl Object {
             tags: Vec::new(),
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            visible: None,
+            cache_control: Default::default(),
         }
     }
 
@@ -80,6 +92,8 @@ impl Object {
     impl_set_inaccessible!();
     impl_set_interface_object!();
     impl_set_tags!();
+    impl_set_visible!();
+    impl_set_cache_control!();
     impl_directive!();
 
     /// Add an field to the object
@@ -180,16 +194,20 @@ impl Object {
                     args,
                     ty: field.ty.to_string(),
                     deprecation: field.deprecation.clone(),
-                    cache_control: Default::default(),
+                    cache_control: field.cache_control,
+                    guarded: field.guard.is_some(),
                     external: field.external,
                     requires: field.requires.clone(),
                     provides: field.provides.clone(),
-                    visible: None,
+                    visible: to_meta_visible(&field.visible),
                     shareable: field.shareable,
                     inaccessible: field.inaccessible,
                     tags: field.tags.clone(),
                     override_from: field.override_from.clone(),
-                    compute_complexity: None,
+                    compute_complexity: to_compute_complexity(
+                        field.complexity.as_ref(),
+                        &field.arguments,
+                    ),
                     directive_invocations: to_meta_directive_invocation(field.directives.clone()),
                     requires_scopes: field.requires_scopes.clone(),
                     semantic_nullability: field.semantic_nullability,
@@ -203,7 +221,7 @@ impl Object {
                 name: self.name.clone(),
                 description: self.description.clone(),
                 fields,
-                cache_control: Default::default(),
+                cache_control: self.cache_control,
                 extends: self.extends,
                 shareable: self.shareable,
                 resolvable: self.resolvable,
@@ -212,7 +230,7 @@ impl Object {
                 } else {
                     None
                 },
-                visible: None,
+                visible: to_meta_visible(&self.visible),
                 inaccessible: self.inaccessible,
                 interface_object: self.interface_object,
                 tags: self.tags.clone(),
@@ -236,6 +254,42 @@ impl Object {
     }
 }
 
+pub(crate) fn to_compute_complexity(
+    complexity: Option<&ComplexityFn>,
+    arguments: &IndexMap<String, InputValue>,
+) -> Option<ComputeComplexityFn> {
+    let complexity = complexity?.clone();
+    let default_values = arguments
+        .values()
+        .filter_map(|arg| Some((Name::new(&arg.name), arg.default_value.clone()?)))
+        .collect::<Vec<_>>();
+    Some(Arc::new(
+        move |ctx: &VisitorContext<'_>,
+              variable_definitions: &[Positioned<VariableDefinition>],
+              field: &ParsedField,
+              child_complexity: usize|
+              -> ServerResult<usize> {
+            let mut arguments = field
+                .arguments
+                .iter()
+                .map(|(name, value)| {
+                    Ok((
+                        name.node.clone(),
+                        ctx.resolve_value(variable_definitions, value.clone())?,
+                    ))
+                })
+                .collect::<ServerResult<IndexMap<Name, Value>>>()?;
+            for (name, value) in &default_values {
+                if !arguments.contains_key(name) {
+                    arguments.insert(name.clone(), value.clone());
+                }
+            }
+            complexity(&ObjectAccessor(Cow::Owned(arguments)), child_complexity)
+                .map_err(|err| ServerError::new(err.message, Some(field.response_key().pos)))
+        },
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Value, dynamic::*, value};
@@ -288,4 +342,221 @@ mod tests {
             })
         );
     }
+
+    #[tokio::test]
+    async fn field_guard() {
+        struct Role(&'static str);
+
+        let query = Object::new("Query").field(
+            Field::new("value", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async move { Ok(Some(Value::from(10))) })
+            })
+            .guard(|ctx: &crate::Context<'_>| match ctx.data_opt::<Role>() {
+                Some(Role("admin")) => Ok(()),
+                _ => Err("forbidden".into()),
+            }),
+        );
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute(crate::Request::new("{ value }").data(Role("admin")))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": 10 })
+        );
+
+        let resp = schema
+            .execute(crate::Request::new("{ value }").data(Role("guest")))
+            .await;
+        assert_eq!(resp.data, Value::Null);
+        assert_eq!(resp.errors.len(), 1);
+        assert_eq!(resp.errors[0].message, "forbidden");
+        assert_eq!(
+            resp.errors[0].locations,
+            vec![crate::Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn field_visible() {
+        struct ShowHidden;
+
+        let query = Object::new("Query")
+            .field(Field::new("a", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async move { Ok(Some(Value::from(1))) })
+            }))
+            .field(
+                Field::new("b", TypeRef::named(TypeRef::INT), |_| {
+                    FieldFuture::new(async move { Ok(Some(Value::from(2))) })
+                })
+                .visible(|ctx| ctx.data_opt::<ShowHidden>().is_some()),
+            );
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .finish()
+            .unwrap();
+
+        let query = r#"{ __type(name: "Query") { fields { name } } }"#;
+        assert_eq!(
+            schema.execute(query).await.into_result().unwrap().data,
+            value!({ "__type": { "fields": [{ "name": "a" }] } })
+        );
+        assert_eq!(
+            schema
+                .execute(crate::Request::new(query).data(ShowHidden))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "__type": { "fields": [{ "name": "a" }, { "name": "b" }] } })
+        );
+    }
+
+    #[tokio::test]
+    async fn field_cache_control() {
+        let query = Object::new("Query")
+            .cache_control(crate::CacheControl {
+                public: true,
+                max_age: 60,
+                ..Default::default()
+            })
+            .field(
+                Field::new("value", TypeRef::named(TypeRef::INT), |_| {
+                    FieldFuture::new(async move { Ok(Some(Value::from(10))) })
+                })
+                .cache_control(crate::CacheControl {
+                    public: false,
+                    max_age: 30,
+                    ..Default::default()
+                }),
+            )
+            .field(Field::new("other", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async move { Ok(Some(Value::from(20))) })
+            }));
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema.execute("{ other }").await.cache_control,
+            crate::CacheControl {
+                public: true,
+                max_age: 60,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            schema.execute("{ value other }").await.cache_control,
+            crate::CacheControl {
+                public: false,
+                max_age: 30,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn field_complexity() {
+        let obj = Object::new("MyObj")
+            .field(Field::new("a", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async move { Ok(Some(Value::from(1))) })
+            }))
+            .field(Field::new("b", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async move { Ok(Some(Value::from(2))) })
+            }));
+        let query = Object::new("Query").field(
+            Field::new("objs", TypeRef::named_nn_list_nn("MyObj"), |_| {
+                FieldFuture::new(
+                    async move { Ok(Some(FieldValue::list(Vec::<FieldValue>::new()))) },
+                )
+            })
+            .argument(InputValue::new("count", TypeRef::named(TypeRef::INT)).default_value(5))
+            .complexity(|args, child_complexity| {
+                let count = usize::try_from(args.try_get("count")?.i64()?)?;
+                Ok(count * child_complexity)
+            }),
+        );
+        let schema = Schema::build("Query", None, None)
+            .register(obj)
+            .register(query)
+            .limit_complexity(10)
+            .finish()
+            .unwrap();
+
+        assert!(schema.execute("{ objs { a b } }").await.is_ok());
+        assert!(
+            schema
+                .execute("{ objs(count: 3) { a b c: a } }")
+                .await
+                .is_ok()
+        );
+        let resp = schema
+            .execute(
+                crate::Request::new("query($n: Int) { objs(count: $n) { a b c: a } }")
+                    .variables(crate::Variables::from_value(value!({ "n": 4 }))),
+            )
+            .await;
+        assert_eq!(resp.errors.len(), 1);
+        assert_eq!(resp.errors[0].message, "Query is too complex.");
+
+        let resp = schema.execute("{ objs(count: -1) { a } }").await;
+        assert_eq!(resp.errors.len(), 1);
+        assert_eq!(
+            resp.errors[0].locations,
+            vec![crate::Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn deserialize_arguments() {
+        #[derive(serde::Deserialize)]
+        struct Args {
+            a: i32,
+            b: Option<Vec<String>>,
+        }
+
+        let query = Object::new("Query").field(
+            Field::new("value", TypeRef::named_nn(TypeRef::STRING), |ctx| {
+                FieldFuture::new(async move {
+                    let args = ctx.args.deserialize::<Args>()?;
+                    Ok(Some(Value::from(format!(
+                        "{}:{}",
+                        args.a,
+                        args.b.unwrap_or_default().join(",")
+                    ))))
+                })
+            })
+            .argument(InputValue::new("a", TypeRef::named_nn(TypeRef::INT)))
+            .argument(InputValue::new("b", TypeRef::named_list(TypeRef::STRING))),
+        );
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute(r#"{ x: value(a: 1) y: value(a: 2, b: ["c", "d"]) }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "x": "1:", "y": "2:c,d" })
+        );
+    }
 }
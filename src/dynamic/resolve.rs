@@ -6,11 +6,11 @@ use futures_util::{Future, FutureExt, future::BoxFuture};
 use indexmap::IndexMap;
 
 use crate::{
-    Context, ContextSelectionSet, Error, IntrospectionMode, Name, SDLExportOptions, ServerError,
-    ServerResult, Value,
+    Context, ContextBase, ContextSelectionSet, Error, IntrospectionMode, Name, PathSegment,
+    SDLExportOptions, ServerError, ServerResult, Value,
     dynamic::{
-        FieldFuture, FieldValue, Object, ObjectAccessor, ResolverContext, Schema, Type, TypeRef,
-        field::FieldValueInner,
+        FieldFuture, FieldValue, InputValue, Object, ObjectAccessor, ResolverContext, Schema, Type,
+        TypeRef, field::FieldValueInner,
     },
     extensions::ResolveInfo,
     parser::types::Selection,
@@ -182,6 +182,7 @@ fn collect_entities_field<'a>(
                 ctx: &ctx_field,
                 args: arguments,
                 parent_value,
+                field_type: "_Entity",
             });
 
             let field_value = match field_future {
@@ -230,6 +231,7 @@ fn collect_field<'a>(
                         args.insert(Name::new(name), def.clone());
                     }
                 });
+                validate_arguments(schema, &ctx_field, &field_def.arguments, &args)?;
                 args
             }));
 
@@ -243,10 +245,29 @@ fn collect_field<'a>(
                 field: &field.node,
             };
             let resolve_fut = async {
+                let interface_guards =
+                    object
+                        .implements
+                        .iter()
+                        .filter_map(|name| match schema.0.types.get(name) {
+                            Some(Type::Interface(interface)) => interface
+                                .fields
+                                .get(field.node.name.node.as_str())?
+                                .guard
+                                .as_ref(),
+                            _ => None,
+                        });
+                for guard in field_def.guard.iter().chain(interface_guards) {
+                    guard(&ctx_field)
+                        .await
+                        .map_err(|err| err.into_server_error(field.pos))?;
+                }
+
                 let field_future = (field_def.resolver_fn)(ResolverContext {
                     ctx: &ctx_field,
                     args: arguments,
                     parent_value,
+                    field_type: field_def.ty.type_name(),
                 });
 
                 let field_value = match field_future {
@@ -261,7 +282,34 @@ fn collect_field<'a>(
 
                 Ok(value)
             };
-            futures_util::pin_mut!(resolve_fut);
+            let mut resolve_fut = resolve_fut.boxed();
+
+            for directive in &field.node.directives {
+                if let Some(directive_factory) = ctx
+                    .schema_env
+                    .custom_directives
+                    .get(directive.node.name.node.as_str())
+                {
+                    let ctx_directive = ContextBase {
+                        path_node: ctx_field.path_node,
+                        is_for_introspection: false,
+                        item: directive,
+                        schema_env: ctx_field.schema_env,
+                        query_env: ctx_field.query_env,
+                        execute_data: ctx_field.execute_data,
+                    };
+                    let directive_instance =
+                        directive_factory.create(&ctx_directive, &directive.node)?;
+                    resolve_fut = Box::pin({
+                        let ctx_field = ctx_field.clone();
+                        async move {
+                            directive_instance
+                                .resolve_field(&ctx_field, &mut resolve_fut)
+                                .await
+                        }
+                    });
+                }
+            }
 
             let res_value = ctx_field
                 .query_env
@@ -389,6 +437,34 @@ fn collect_fields<'a>(
     Ok(())
 }
 
+pub(crate) fn validate_arguments(
+    schema: &Schema,
+    ctx: &Context<'_>,
+    arguments: &IndexMap<String, InputValue>,
+    values: &IndexMap<Name, Value>,
+) -> ServerResult<()> {
+    for (name, argument) in arguments {
+        if let Some(value) = values.get(name.as_str()) {
+            argument
+                .validate(
+                    &schema.0.types,
+                    value,
+                    &mut vec![PathSegment::Field(name.clone())],
+                )
+                .map_err(|err| {
+                    let pos = ctx
+                        .item
+                        .node
+                        .get_argument(name)
+                        .map(|value| value.pos)
+                        .unwrap_or(ctx.item.pos);
+                    ctx.set_error_path(err.into_server_error(pos))
+                })?;
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn resolve<'a>(
     schema: &'a Schema,
     ctx: &'a Context<'a>,
@@ -3,7 +3,11 @@ use std::{
     sync::Arc,
 };
 
-use super::{Directive, directive::to_meta_directive_invocation};
+use super::{
+    Directive,
+    base::{VisibleFn, to_meta_visible},
+    directive::to_meta_directive_invocation,
+};
 use crate::{
     Value,
     dynamic::SchemaError,
@@ -52,6 +56,7 @@ pub struct Scalar {
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     requires_scopes: Vec<String>,
+    visible: Option<VisibleFn>,
 }
 
 impl Debug for Scalar {
@@ -80,12 +85,14 @@ impl Scalar {
             tags: Vec::new(),
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            visible: None,
         }
     }
 
     impl_set_description!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_visible!();
     impl_directive!();
 
     /// Set the validator
@@ -127,7 +134,7 @@ impl Scalar {
                 name: self.name.clone(),
                 description: self.description.clone(),
                 is_valid: self.validator.clone(),
-                visible: None,
+                visible: to_meta_visible(&self.visible),
                 inaccessible: self.inaccessible,
                 tags: self.tags.clone(),
                 specified_by_url: self.specified_by_url.clone(),
@@ -204,7 +211,7 @@ mod tests {
                 message: "internal: invalid value for scalar \"MyScalar\", expected \"FieldValue::Value\""
                     .to_owned(),
                 source: None,
-                locations: vec![Pos { column: 3, line: 1 }],
+                locations: vec![Pos { column: 3, line: 1, ..Default::default() }],
                 path: vec![PathSegment::Field("value".to_owned())],
                 extensions: None,
             }]
@@ -1,12 +1,17 @@
 use std::{any::Any, collections::HashMap, fmt::Debug, sync::Arc};
 
-use async_graphql_parser::types::OperationType;
-use futures_util::{StreamExt, TryFutureExt, stream::BoxStream};
+use async_graphql_parser::{ParseOptions, types::OperationType};
+use futures_util::{
+    StreamExt, TryFutureExt,
+    stream::{BoxStream, FuturesOrdered},
+};
 use indexmap::IndexMap;
 
 use crate::{
-    Data, Executor, IntrospectionMode, QueryEnv, Request, Response, SDLExportOptions, SchemaEnv,
-    ServerError, ServerResult, ValidationMode,
+    BatchRequest, BatchResponse, CustomDirectiveFactory, Data, Executor, IntrospectionMode,
+    QueryEnv, Request, Response, SDLExportOptions, SchemaEnv, ServerError, ServerResult,
+    ValidationMode, ValidationRuleFactory,
+    custom_directive::execute_operation_directives,
     dynamic::{
         DynamicRequest, FieldFuture, FieldValue, Object, ResolverContext, Scalar, SchemaError,
         Subscription, TypeRef, Union, field::BoxResolverFn, resolve::resolve_container,
@@ -14,8 +19,10 @@ use crate::{
     },
     extensions::{ExtensionFactory, Extensions},
     registry::{MetaType, Registry},
-    schema::{SchemaEnvInner, prepare_request},
+    schema::{QueryLimits, SchemaEnvInner, check_batch_size, prepare_request},
 };
+#[cfg(feature = "validation_cache")]
+use crate::{ValidationCacheStorage, validation::cache::schema_fingerprint};
 
 /// Dynamic schema builder
 pub struct SchemaBuilder {
@@ -27,13 +34,19 @@ pub struct SchemaBuilder {
     extensions: Vec<Box<dyn ExtensionFactory>>,
     validation_mode: ValidationMode,
     recursive_depth: usize,
-    max_directives: Option<usize>,
+    parse_options: ParseOptions,
+    limits: QueryLimits,
     complexity: Option<usize>,
     depth: Option<usize>,
     enable_suggestions: bool,
+    apollo_cache_control: bool,
     introspection_mode: IntrospectionMode,
     enable_federation: bool,
     entity_resolver: Option<BoxResolverFn>,
+    custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
+    validation_rules: Vec<Box<dyn ValidationRuleFactory>>,
+    #[cfg(feature = "validation_cache")]
+    validation_cache: Option<Box<dyn ValidationCacheStorage>>,
 }
 
 impl SchemaBuilder {
@@ -91,10 +104,78 @@ impl SchemaBuilder {
         self
     }
 
+    /// Set the limits checked while parsing a query, such as the maximum
+    /// number of tokens. (default: no limit)
+    ///
+    /// Queries exceeding them are rejected before they are fully parsed.
+    #[must_use]
+    pub fn parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+
     /// Set the maximum number of directives on a single field. (default: no
     /// limit)
     pub fn limit_directives(mut self, max_directives: usize) -> Self {
-        self.max_directives = Some(max_directives);
+        self.limits.max_directives = Some(max_directives);
+        self
+    }
+
+    /// Set the maximum number of aliases a query can have, counting the
+    /// aliases of a fragment each time it is spread. (default: no limit)
+    #[must_use]
+    pub fn limit_aliases(mut self, max_aliases: usize) -> Self {
+        self.limits.max_aliases = Some(max_aliases);
+        self
+    }
+
+    /// Set the maximum number of aliases in a single selection set, including
+    /// the aliases of the fragments spread into it, unlike
+    /// [`ParseOptions::max_aliases`]. (default: no limit)
+    #[must_use]
+    pub fn limit_aliases_per_selection_set(mut self, max_aliases: usize) -> Self {
+        self.limits.max_aliases_per_selection_set = Some(max_aliases);
+        self
+    }
+
+    /// Set the maximum number of fields in the root selection set of an
+    /// operation. (default: no limit)
+    #[must_use]
+    pub fn limit_root_fields(mut self, max_root_fields: usize) -> Self {
+        self.limits.max_root_fields = Some(max_root_fields);
+        self
+    }
+
+    /// Set the maximum number of requests in a batch. (default: no limit)
+    ///
+    /// Larger batches are rejected with an error without executing any
+    /// request.
+    #[must_use]
+    pub fn limit_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.limits.max_batch_size = Some(max_batch_size);
+        self
+    }
+
+    /// Register a custom directive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the directive with the same name is already registered.
+    #[must_use]
+    pub fn directive<T: CustomDirectiveFactory>(mut self, directive: T) -> Self {
+        let name = directive.name();
+        let instance = Box::new(directive);
+
+        if name == "skip"
+            || name == "include"
+            || self
+                .custom_directives
+                .insert(name.clone().into(), instance)
+                .is_some()
+        {
+            panic!("Directive `{}` already exists", name);
+        }
+
         self
     }
 
@@ -105,6 +186,33 @@ impl SchemaBuilder {
         self
     }
 
+    /// Add a validation rule, which is run after the built-in rules in every
+    /// validation mode.
+    ///
+    /// The rule is a [`Visitor`](crate::Visitor) created for every request,
+    /// whose [`VisitorContext`](crate::VisitorContext) gives access to the
+    /// registry, the variables and the operation name of the request, and
+    /// reports errors the same way as the built-in rules.
+    #[must_use]
+    pub fn validation_rule(mut self, rule: impl ValidationRuleFactory) -> Self {
+        self.validation_rules.push(Box::new(rule));
+        self
+    }
+
+    /// Cache the parsed and validated documents, so that the requests with
+    /// the same query source, operation name and introspection mode skip the
+    /// parsing and the validation.
+    ///
+    /// The results of the validations that read the variables or the batch of
+    /// the request are not cached.
+    #[cfg(feature = "validation_cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "validation_cache")))]
+    #[must_use]
+    pub fn validation_cache(mut self, storage: impl ValidationCacheStorage) -> Self {
+        self.validation_cache = Some(Box::new(storage));
+        self
+    }
+
     /// Disable field suggestions.
     #[must_use]
     pub fn disable_suggestions(mut self) -> Self {
@@ -112,6 +220,16 @@ impl SchemaBuilder {
         self
     }
 
+    /// Compute the cache control of the responses with the rules of Apollo
+    /// Server.
+    ///
+    /// See [`crate::SchemaBuilder::enable_apollo_cache_control`].
+    #[must_use]
+    pub fn enable_apollo_cache_control(mut self) -> Self {
+        self.apollo_cache_control = true;
+        self
+    }
+
     /// Disable introspection queries.
     #[must_use]
     pub fn disable_introspection(mut self) -> Self {
@@ -160,9 +278,14 @@ impl SchemaBuilder {
             federation_subscription: false,
             ignore_name_conflicts: Default::default(),
             enable_suggestions: self.enable_suggestions,
+            apollo_cache_control: self.apollo_cache_control,
         };
         registry.add_system_types();
 
+        for directive in self.custom_directives.values() {
+            directive.register(&mut registry);
+        }
+
         for ty in self.types.values() {
             ty.register(&mut registry)?;
         }
@@ -205,14 +328,25 @@ impl SchemaBuilder {
 
         let inner = SchemaInner {
             env: SchemaEnv(Arc::new(SchemaEnvInner {
+                #[cfg(feature = "validation_cache")]
+                schema_fingerprint: self
+                    .validation_cache
+                    .as_ref()
+                    .map(|_| schema_fingerprint(&registry)),
                 registry,
                 data: self.data,
-                custom_directives: Default::default(),
+                custom_directives: self.custom_directives,
+                validation_rules: self.validation_rules,
+                #[cfg(feature = "validation_cache")]
+                validation_cache: self.validation_cache,
+                #[cfg(feature = "validation_cache")]
+                compile_operations: false,
+                parse_options: self.parse_options,
             })),
             extensions: self.extensions,
             types: self.types,
             recursive_depth: self.recursive_depth,
-            max_directives: self.max_directives,
+            limits: self.limits,
             complexity: self.complexity,
             depth: self.depth,
             validation_mode: self.validation_mode,
@@ -240,7 +374,7 @@ pub struct SchemaInner {
     pub(crate) types: IndexMap<String, Type>,
     extensions: Vec<Box<dyn ExtensionFactory>>,
     recursive_depth: usize,
-    max_directives: Option<usize>,
+    limits: QueryLimits,
     complexity: Option<usize>,
     depth: Option<usize>,
     validation_mode: ValidationMode,
@@ -259,12 +393,18 @@ impl Schema {
             extensions: Default::default(),
             validation_mode: ValidationMode::Strict,
             recursive_depth: 32,
-            max_directives: None,
+            parse_options: Default::default(),
+            limits: Default::default(),
             complexity: None,
             depth: None,
             enable_suggestions: true,
+            apollo_cache_control: false,
             introspection_mode: IntrospectionMode::Enabled,
             entity_resolver: None,
+            custom_directives: Default::default(),
+            validation_rules: Default::default(),
+            #[cfg(feature = "validation_cache")]
+            validation_cache: None,
             enable_federation: false,
         }
     }
@@ -330,36 +470,39 @@ impl Schema {
             &env.operation.node.selection_set,
             execute_data.as_ref(),
         );
-        let res = match &env.operation.node.ty {
-            OperationType::Query => {
-                async move { self.query_root() }
-                    .and_then(|query_root| {
-                        resolve_container(self, query_root, &ctx, root_value, false)
-                    })
-                    .await
-            }
-            OperationType::Mutation => {
-                async move { self.mutation_root() }
-                    .and_then(|query_root| {
-                        resolve_container(self, query_root, &ctx, root_value, true)
-                    })
-                    .await
-            }
-            OperationType::Subscription => Err(ServerError::new(
-                "Subscriptions are not supported on this transport.",
-                None,
-            )),
-        };
+        let execute = async {
+            let res = match &env.operation.node.ty {
+                OperationType::Query => {
+                    async move { self.query_root() }
+                        .and_then(|query_root| {
+                            resolve_container(self, query_root, &ctx, root_value, false)
+                        })
+                        .await
+                }
+                OperationType::Mutation => {
+                    async move { self.mutation_root() }
+                        .and_then(|query_root| {
+                            resolve_container(self, query_root, &ctx, root_value, true)
+                        })
+                        .await
+                }
+                OperationType::Subscription => Err(ServerError::new(
+                    "Subscriptions are not supported on this transport.",
+                    None,
+                )),
+            };
 
-        let mut resp = match res {
-            Ok(value) => Response::new(value.unwrap_or_default()),
-            Err(err) => Response::from_errors(vec![err]),
-        }
-        .http_headers(std::mem::take(&mut *env.http_headers.lock().unwrap()));
+            let mut resp = match res {
+                Ok(value) => Response::new(value.unwrap_or_default()),
+                Err(err) => Response::from_errors(vec![err]),
+            }
+            .http_headers(std::mem::take(&mut *env.http_headers.lock().unwrap()));
 
-        resp.errors
-            .extend(std::mem::take(&mut *env.errors.lock().unwrap()));
-        resp
+            resp.errors
+                .extend(std::mem::take(&mut *env.errors.lock().unwrap()));
+            resp.cache_control(*env.cache_control.lock().unwrap())
+        };
+        execute_operation_directives(&ctx, Box::pin(execute)).await
     }
 
     /// Execute a GraphQL query.
@@ -373,23 +516,22 @@ impl Schema {
                     extensions,
                     request.inner,
                     Default::default(),
-                    &self.0.env.registry,
+                    &self.0.env,
                     self.0.validation_mode,
                     self.0.recursive_depth,
-                    self.0.max_directives,
+                    self.0.limits,
                     self.0.complexity,
                     self.0.depth,
                 )
                 .await
                 {
-                    Ok((env, cache_control)) => {
+                    Ok(env) => {
                         let f = {
                             |execute_data| {
                                 let env = env.clone();
                                 async move {
                                     self.execute_once(env, &request.root_value, execute_data)
                                         .await
-                                        .cache_control(cache_control)
                                 }
                             }
                         };
@@ -405,6 +547,25 @@ impl Schema {
         extensions.request(&mut request_fut).await
     }
 
+    /// Execute a GraphQL batch query.
+    pub async fn execute_batch(&self, batch_request: BatchRequest) -> BatchResponse {
+        if let Err(err) = check_batch_size(&batch_request, &self.0.limits) {
+            return BatchResponse::Single(Response::from_errors(vec![err]));
+        }
+        match batch_request {
+            BatchRequest::Single(request) => BatchResponse::Single(self.execute(request).await),
+            BatchRequest::Batch(requests) => BatchResponse::Batch(
+                FuturesOrdered::from_iter(
+                    requests
+                        .into_iter()
+                        .map(|request| self.execute(request.mark_in_batch())),
+                )
+                .collect()
+                .await,
+            ),
+        }
+    }
+
     /// Execute a GraphQL subscription with session data.
     pub fn execute_stream_with_session_data(
         &self,
@@ -427,14 +588,14 @@ impl Schema {
                     }
                 };
 
-                let (env, _) = match prepare_request(
+                let env = match prepare_request(
                     extensions,
                     request.inner,
                     session_data,
-                    &schema.0.env.registry,
+                    &schema.0.env,
                     schema.0.validation_mode,
                     schema.0.recursive_depth,
-                    schema.0.max_directives,
+                    schema.0.limits,
                     schema.0.complexity,
                     schema.0.depth,
                 )
@@ -492,6 +653,10 @@ impl Executor for Schema {
         Schema::execute(self, request).await
     }
 
+    async fn execute_batch(&self, batch_request: BatchRequest) -> BatchResponse {
+        Schema::execute_batch(self, batch_request).await
+    }
+
     fn execute_stream(
         &self,
         request: Request,
@@ -635,6 +800,64 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn validation_rule() {
+        struct NoSecret;
+
+        impl<'a> crate::Visitor<'a> for NoSecret {
+            fn enter_field(
+                &mut self,
+                ctx: &mut crate::VisitorContext<'a>,
+                field: &'a async_graphql_parser::Positioned<async_graphql_parser::types::Field>,
+            ) {
+                if field.node.name.node == "secret" {
+                    ctx.report_error(vec![field.pos], "Field \"secret\" is forbidden.");
+                }
+            }
+        }
+
+        let query = Object::new("Query")
+            .field(Field::new("value", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(100))) })
+            }))
+            .field(Field::new("secret", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(200))) })
+            }));
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .validation_rule(|| NoSecret)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute("{ value }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": 100 })
+        );
+        assert_eq!(
+            schema
+                .execute("{ value secret }")
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: "Field \"secret\" is forbidden.".to_string(),
+                source: None,
+                locations: vec![Pos {
+                    line: 1,
+                    column: 9,
+                    ..Default::default()
+                }],
+                path: vec![],
+                extensions: None,
+            }]
+        );
+    }
+
     #[tokio::test]
     async fn fragment_spread() {
         let myobj = Object::new("MyObj")
@@ -749,7 +972,11 @@ mod tests {
             vec![ServerError {
                 message: "internal: non-null types require a return value".to_owned(),
                 source: None,
-                locations: vec![Pos { column: 3, line: 1 }],
+                locations: vec![Pos {
+                    column: 3,
+                    line: 1,
+                    ..Default::default()
+                }],
                 path: vec![PathSegment::Field("valueA".to_owned())],
                 extensions: None,
             }]
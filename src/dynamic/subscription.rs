@@ -9,8 +9,9 @@ use crate::{
     ContextSelectionSet, Data, Name, QueryPathNode, QueryPathSegment, Response, Result,
     ServerResult, Value,
     dynamic::{
-        FieldValue, InputValue, ObjectAccessor, ResolverContext, Schema, SchemaError, TypeRef,
-        resolve::resolve,
+        FieldValue, InputValue, ObjectAccessor, ResolverContext, Schema, SchemaError, Type,
+        TypeRef,
+        resolve::{resolve, validate_arguments},
     },
     extensions::ResolveInfo,
     parser::types::Selection,
@@ -156,6 +157,7 @@ impl Subscription {
                     ty: field.ty.to_string(),
                     deprecation: field.deprecation.clone(),
                     cache_control: Default::default(),
+                    guarded: false,
                     external: false,
                     requires: None,
                     provides: None,
@@ -209,6 +211,7 @@ impl Subscription {
                 && let Some(field_def) = self.fields.get(field.node.name.node.as_str())
             {
                 let schema = schema.clone();
+                let subscription_name = self.name.clone();
                 let field_type = field_def.ty.clone();
                 let resolver_fn = field_def.resolver_fn.clone();
                 let ctx = ctx.clone();
@@ -217,23 +220,35 @@ impl Subscription {
                     asynk_strim::try_stream_fn(move |mut yielder| async move {
                         let ctx_field = ctx.with_field(field);
                         let field_name = ctx_field.item.node.response_key().node.clone();
-                        let arguments = ObjectAccessor(Cow::Owned(
-                            field
-                                .node
-                                .arguments
-                                .iter()
-                                .map(|(name, value)| {
-                                    ctx_field
-                                        .resolve_input_value(value.clone())
-                                        .map(|value| (name.node.clone(), value))
-                                })
-                                .collect::<ServerResult<IndexMap<Name, Value>>>()?,
-                        ));
+                        let arguments = field
+                            .node
+                            .arguments
+                            .iter()
+                            .map(|(name, value)| {
+                                ctx_field
+                                    .resolve_input_value(value.clone())
+                                    .map(|value| (name.node.clone(), value))
+                            })
+                            .collect::<ServerResult<IndexMap<Name, Value>>>()?;
+                        if let Some(Type::Subscription(subscription)) =
+                            schema.0.types.get(&subscription_name)
+                            && let Some(field_def) =
+                                subscription.fields.get(field.node.name.node.as_str())
+                        {
+                            validate_arguments(
+                                &schema,
+                                &ctx_field,
+                                &field_def.arguments,
+                                &arguments,
+                            )?;
+                        }
+                        let arguments = ObjectAccessor(Cow::Owned(arguments));
 
                         let mut stream = resolver_fn(ResolverContext {
                             ctx: &ctx_field,
                             args: arguments,
                             parent_value: root_value,
+                            field_type: field_type.type_name(),
                         })
                         .0
                         .await
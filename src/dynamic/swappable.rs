@@ -0,0 +1,708 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use futures_util::stream::BoxStream;
+
+use crate::{
+    BatchRequest, BatchResponse, Data, Executor, Request, Response,
+    dynamic::{DynamicRequest, Schema},
+    registry::{MetaInputValue, MetaType, MetaTypeName, Registry},
+};
+
+/// The version of the schema published by a [`SwappableSchema`]
+///
+/// The version of the schema that executes a request is added to the data of
+/// the request, so extensions and resolvers can read it with
+/// `ctx.data_opt::<SchemaVersion>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SchemaVersion(pub u64);
+
+/// A change between two schemas that can break existing clients
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BreakingChange {
+    /// A root operation type was changed
+    RootTypeChanged {
+        /// The operation type, `query`, `mutation` or `subscription`
+        operation: &'static str,
+    },
+    /// A type was removed
+    TypeRemoved {
+        /// The name of the type
+        name: String,
+    },
+    /// A type was replaced by a type of another kind
+    TypeKindChanged {
+        /// The name of the type
+        name: String,
+    },
+    /// A field of an object, interface or input object was removed
+    FieldRemoved {
+        /// The name of the type
+        type_name: String,
+        /// The name of the field
+        field: String,
+    },
+    /// The type of a field was changed in an incompatible way
+    FieldTypeChanged {
+        /// The name of the type
+        type_name: String,
+        /// The name of the field
+        field: String,
+        /// The old type of the field
+        old_type: String,
+        /// The new type of the field
+        new_type: String,
+    },
+    /// A required input field was added to an input object
+    RequiredFieldAdded {
+        /// The name of the input object
+        type_name: String,
+        /// The name of the field
+        field: String,
+    },
+    /// An argument was removed
+    ArgumentRemoved {
+        /// The name of the type
+        type_name: String,
+        /// The name of the field
+        field: String,
+        /// The name of the argument
+        argument: String,
+    },
+    /// The type of an argument was changed in an incompatible way
+    ArgumentTypeChanged {
+        /// The name of the type
+        type_name: String,
+        /// The name of the field
+        field: String,
+        /// The name of the argument
+        argument: String,
+        /// The old type of the argument
+        old_type: String,
+        /// The new type of the argument
+        new_type: String,
+    },
+    /// A required argument was added
+    RequiredArgumentAdded {
+        /// The name of the type
+        type_name: String,
+        /// The name of the field
+        field: String,
+        /// The name of the argument
+        argument: String,
+    },
+    /// A value was removed from an enum
+    EnumValueRemoved {
+        /// The name of the enum
+        type_name: String,
+        /// The enum value
+        value: String,
+    },
+    /// A possible type was removed from a union or an interface
+    PossibleTypeRemoved {
+        /// The name of the union or interface
+        type_name: String,
+        /// The name of the possible type
+        possible_type: String,
+    },
+}
+
+impl Display for BreakingChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BreakingChange::RootTypeChanged { operation } => {
+                write!(f, "the {} root type was changed", operation)
+            }
+            BreakingChange::TypeRemoved { name } => write!(f, "type `{}` was removed", name),
+            BreakingChange::TypeKindChanged { name } => {
+                write!(f, "type `{}` changed its kind", name)
+            }
+            BreakingChange::FieldRemoved { type_name, field } => {
+                write!(f, "field `{}.{}` was removed", type_name, field)
+            }
+            BreakingChange::FieldTypeChanged {
+                type_name,
+                field,
+                old_type,
+                new_type,
+            } => write!(
+                f,
+                "field `{}.{}` changed type from `{}` to `{}`",
+                type_name, field, old_type, new_type
+            ),
+            BreakingChange::RequiredFieldAdded { type_name, field } => {
+                write!(
+                    f,
+                    "required input field `{}.{}` was added",
+                    type_name, field
+                )
+            }
+            BreakingChange::ArgumentRemoved {
+                type_name,
+                field,
+                argument,
+            } => write!(
+                f,
+                "argument `{}` of `{}.{}` was removed",
+                argument, type_name, field
+            ),
+            BreakingChange::ArgumentTypeChanged {
+                type_name,
+                field,
+                argument,
+                old_type,
+                new_type,
+            } => write!(
+                f,
+                "argument `{}` of `{}.{}` changed type from `{}` to `{}`",
+                argument, type_name, field, old_type, new_type
+            ),
+            BreakingChange::RequiredArgumentAdded {
+                type_name,
+                field,
+                argument,
+            } => write!(
+                f,
+                "required argument `{}` of `{}.{}` was added",
+                argument, type_name, field
+            ),
+            BreakingChange::EnumValueRemoved { type_name, value } => {
+                write!(f, "enum value `{}.{}` was removed", type_name, value)
+            }
+            BreakingChange::PossibleTypeRemoved {
+                type_name,
+                possible_type,
+            } => write!(
+                f,
+                "`{}` is no longer a possible type of `{}`",
+                possible_type, type_name
+            ),
+        }
+    }
+}
+
+/// A handle to a dynamic schema that can be replaced at runtime
+///
+/// Every request and subscription takes the schema that is current when it
+/// starts and keeps using it until it finishes, so publishing a new schema
+/// with [`SwappableSchema::swap`] never affects operations that are already
+/// running. Cloning the handle is cheap, and all clones share the same
+/// current schema.
+///
+/// # Examples
+///
+/// ```
+/// use async_graphql::{Value, dynamic::*};
+///
+/// fn build(value: i32) -> Schema {
+///     let query = Object::new("Query").field(Field::new(
+///         "value",
+///         TypeRef::named_nn(TypeRef::INT),
+///         move |_| FieldFuture::new(async move { Ok(Some(Value::from(value))) }),
+///     ));
+///     Schema::build("Query", None, None)
+///         .register(query)
+///         .finish()
+///         .unwrap()
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = SwappableSchema::new(build(1));
+/// let version = schema.swap(build(2));
+/// assert_eq!(version, SchemaVersion(1));
+/// assert_eq!(
+///     schema.execute("{ value }").await.data,
+///     async_graphql::value!({ "value": 2 })
+/// );
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct SwappableSchema(Arc<RwLock<(SchemaVersion, Schema)>>);
+
+impl SwappableSchema {
+    /// Create a handle that publishes `schema` as version `0`.
+    pub fn new(schema: Schema) -> Self {
+        Self(Arc::new(RwLock::new((SchemaVersion(0), schema))))
+    }
+
+    /// Returns the current schema and its version.
+    pub fn current(&self) -> (SchemaVersion, Schema) {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns the version of the current schema.
+    pub fn version(&self) -> SchemaVersion {
+        self.0.read().unwrap_or_else(PoisonError::into_inner).0
+    }
+
+    /// Publish a new schema, and returns its version.
+    pub fn swap(&self, schema: Schema) -> SchemaVersion {
+        let mut current = self.0.write().unwrap_or_else(PoisonError::into_inner);
+        let version = SchemaVersion(current.0.0 + 1);
+        *current = (version, schema);
+        version
+    }
+
+    /// Publish a new schema only if it has no breaking changes compared to the
+    /// current schema, and returns its version.
+    ///
+    /// Adding types, fields, optional arguments and enum values is allowed.
+    /// Removing them, or changing a type in a way that existing operations
+    /// may no longer validate against, is rejected.
+    pub fn try_swap(&self, schema: Schema) -> Result<SchemaVersion, Vec<BreakingChange>> {
+        let mut current = self.0.write().unwrap_or_else(PoisonError::into_inner);
+        let changes = breaking_changes(current.1.registry(), schema.registry());
+        if !changes.is_empty() {
+            return Err(changes);
+        }
+        let version = SchemaVersion(current.0.0 + 1);
+        *current = (version, schema);
+        Ok(version)
+    }
+
+    /// Execute a GraphQL query on the current schema.
+    pub async fn execute(&self, request: impl Into<DynamicRequest>) -> Response {
+        let mut request = request.into();
+        let (version, schema) = self.current();
+        request.inner.data.insert(version);
+        schema.execute(request).await
+    }
+
+    /// Execute a GraphQL batch query on the current schema.
+    ///
+    /// All the requests of the batch are executed by the same version of the
+    /// schema.
+    pub async fn execute_batch(&self, batch_request: BatchRequest) -> BatchResponse {
+        let (version, schema) = self.current();
+        schema.execute_batch(batch_request.data(version)).await
+    }
+
+    /// Execute a GraphQL subscription with session data on the current
+    /// schema.
+    pub fn execute_stream_with_session_data(
+        &self,
+        request: impl Into<DynamicRequest>,
+        session_data: Arc<Data>,
+    ) -> BoxStream<'static, Response> {
+        let mut request = request.into();
+        let (version, schema) = self.current();
+        request.inner.data.insert(version);
+        schema.execute_stream_with_session_data(request, session_data)
+    }
+
+    /// Execute a GraphQL subscription on the current schema.
+    pub fn execute_stream(
+        &self,
+        request: impl Into<DynamicRequest>,
+    ) -> BoxStream<'static, Response> {
+        self.execute_stream_with_session_data(request, Default::default())
+    }
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl Executor for SwappableSchema {
+    async fn execute(&self, request: Request) -> Response {
+        SwappableSchema::execute(self, request).await
+    }
+
+    async fn execute_batch(&self, batch_request: BatchRequest) -> BatchResponse {
+        SwappableSchema::execute_batch(self, batch_request).await
+    }
+
+    fn execute_stream(
+        &self,
+        request: Request,
+        session_data: Option<Arc<Data>>,
+    ) -> BoxStream<'static, Response> {
+        SwappableSchema::execute_stream_with_session_data(
+            self,
+            request,
+            session_data.unwrap_or_default(),
+        )
+    }
+}
+
+fn breaking_changes(old: &Registry, new: &Registry) -> Vec<BreakingChange> {
+    let mut changes = Vec::new();
+
+    for (operation, old_root, new_root) in [
+        ("query", Some(&old.query_type), Some(&new.query_type)),
+        (
+            "mutation",
+            old.mutation_type.as_ref(),
+            new.mutation_type.as_ref(),
+        ),
+        (
+            "subscription",
+            old.subscription_type.as_ref(),
+            new.subscription_type.as_ref(),
+        ),
+    ] {
+        if old_root.is_some() && old_root != new_root {
+            changes.push(BreakingChange::RootTypeChanged { operation });
+        }
+    }
+
+    for (name, old_ty) in &old.types {
+        let Some(new_ty) = new.types.get(name) else {
+            changes.push(BreakingChange::TypeRemoved { name: name.clone() });
+            continue;
+        };
+
+        match (old_ty, new_ty) {
+            (MetaType::Scalar { .. }, MetaType::Scalar { .. }) => {}
+            (
+                MetaType::Object {
+                    fields: old_fields, ..
+                },
+                MetaType::Object {
+                    fields: new_fields, ..
+                },
+            )
+            | (
+                MetaType::Interface {
+                    fields: old_fields, ..
+                },
+                MetaType::Interface {
+                    fields: new_fields, ..
+                },
+            ) => {
+                for (field_name, old_field) in old_fields {
+                    let Some(new_field) = new_fields.get(field_name) else {
+                        changes.push(BreakingChange::FieldRemoved {
+                            type_name: name.clone(),
+                            field: field_name.clone(),
+                        });
+                        continue;
+                    };
+
+                    // an output type may only become more specific
+                    if !MetaTypeName::create(&old_field.ty)
+                        .is_subtype(&MetaTypeName::create(&new_field.ty))
+                    {
+                        changes.push(BreakingChange::FieldTypeChanged {
+                            type_name: name.clone(),
+                            field: field_name.clone(),
+                            old_type: old_field.ty.clone(),
+                            new_type: new_field.ty.clone(),
+                        });
+                    }
+
+                    for (arg_name, old_arg) in &old_field.args {
+                        match new_field.args.get(arg_name) {
+                            Some(new_arg) if !is_input_compatible(old_arg, new_arg) => {
+                                changes.push(BreakingChange::ArgumentTypeChanged {
+                                    type_name: name.clone(),
+                                    field: field_name.clone(),
+                                    argument: arg_name.clone(),
+                                    old_type: old_arg.ty.clone(),
+                                    new_type: new_arg.ty.clone(),
+                                });
+                            }
+                            Some(_) => {}
+                            None => changes.push(BreakingChange::ArgumentRemoved {
+                                type_name: name.clone(),
+                                field: field_name.clone(),
+                                argument: arg_name.clone(),
+                            }),
+                        }
+                    }
+
+                    for (arg_name, new_arg) in &new_field.args {
+                        if !old_field.args.contains_key(arg_name) && is_required(new_arg) {
+                            changes.push(BreakingChange::RequiredArgumentAdded {
+                                type_name: name.clone(),
+                                field: field_name.clone(),
+                                argument: arg_name.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+            (
+                MetaType::Union {
+                    possible_types: old_possible_types,
+                    ..
+                },
+                MetaType::Union {
+                    possible_types: new_possible_types,
+                    ..
+                },
+            ) => {
+                for possible_type in old_possible_types {
+                    if !new_possible_types.contains(possible_type) {
+                        changes.push(BreakingChange::PossibleTypeRemoved {
+                            type_name: name.clone(),
+                            possible_type: possible_type.clone(),
+                        });
+                    }
+                }
+            }
+            (
+                MetaType::Enum {
+                    enum_values: old_values,
+                    ..
+                },
+                MetaType::Enum {
+                    enum_values: new_values,
+                    ..
+                },
+            ) => {
+                for value in old_values.keys() {
+                    if !new_values.contains_key(value) {
+                        changes.push(BreakingChange::EnumValueRemoved {
+                            type_name: name.clone(),
+                            value: value.clone(),
+                        });
+                    }
+                }
+            }
+            (
+                MetaType::InputObject {
+                    input_fields: old_fields,
+                    ..
+                },
+                MetaType::InputObject {
+                    input_fields: new_fields,
+                    ..
+                },
+            ) => {
+                for (field_name, old_field) in old_fields {
+                    match new_fields.get(field_name) {
+                        Some(new_field) if !is_input_compatible(old_field, new_field) => {
+                            changes.push(BreakingChange::FieldTypeChanged {
+                                type_name: name.clone(),
+                                field: field_name.clone(),
+                                old_type: old_field.ty.clone(),
+                                new_type: new_field.ty.clone(),
+                            });
+                        }
+                        Some(_) => {}
+                        None => changes.push(BreakingChange::FieldRemoved {
+                            type_name: name.clone(),
+                            field: field_name.clone(),
+                        }),
+                    }
+                }
+
+                for (field_name, new_field) in new_fields {
+                    if !old_fields.contains_key(field_name) && is_required(new_field) {
+                        changes.push(BreakingChange::RequiredFieldAdded {
+                            type_name: name.clone(),
+                            field: field_name.clone(),
+                        });
+                    }
+                }
+            }
+            _ => changes.push(BreakingChange::TypeKindChanged { name: name.clone() }),
+        }
+
+        // a possible type of an interface may have been selected with a fragment
+        if let (
+            MetaType::Interface {
+                possible_types: old_possible_types,
+                ..
+            },
+            MetaType::Interface {
+                possible_types: new_possible_types,
+                ..
+            },
+        ) = (old_ty, new_ty)
+        {
+            for possible_type in old_possible_types {
+                if !new_possible_types.contains(possible_type) {
+                    changes.push(BreakingChange::PossibleTypeRemoved {
+                        type_name: name.clone(),
+                        possible_type: possible_type.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    changes
+}
+
+/// An input type may only become less specific, so that every value that was
+/// accepted before is still accepted.
+fn is_input_compatible(old: &MetaInputValue, new: &MetaInputValue) -> bool {
+    MetaTypeName::create(&new.ty).is_subtype(&MetaTypeName::create(&old.ty))
+}
+
+fn is_required(input_value: &MetaInputValue) -> bool {
+    MetaTypeName::create(&input_value.ty).is_non_null() && input_value.default_value.is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+
+    use crate::{Value, dynamic::*, value};
+
+    fn build_schema(value: i32, argument: Option<TypeRef>) -> Schema {
+        let mut field = Field::new("value", TypeRef::named_nn(TypeRef::INT), move |_| {
+            FieldFuture::new(async move { Ok(Some(Value::from(value))) })
+        });
+        if let Some(ty) = argument {
+            field = field.argument(InputValue::new("arg", ty));
+        }
+        let subscription = Subscription::new("Subscription").field(SubscriptionField::new(
+            "values",
+            TypeRef::named_nn(TypeRef::INT),
+            move |_| {
+                SubscriptionFieldFuture::new(async move {
+                    Ok(futures_util::stream::iter(0..3).then(move |n| async move {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        Ok(FieldValue::value(value + n))
+                    }))
+                })
+            },
+        ));
+        Schema::build("Query", None, Some("Subscription"))
+            .register(Object::new("Query").field(field))
+            .register(subscription)
+            .finish()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn swap_schema() {
+        let schema = SwappableSchema::new(build_schema(1, None));
+        assert_eq!(schema.version(), SchemaVersion(0));
+
+        let mut stream = schema.execute_stream("subscription { values }");
+        assert_eq!(
+            stream.next().await.unwrap().into_result().unwrap().data,
+            value!({ "values": 1 })
+        );
+
+        assert_eq!(schema.swap(build_schema(10, None)), SchemaVersion(1));
+        assert_eq!(
+            schema
+                .execute("{ value }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": 10 })
+        );
+
+        // the running subscription keeps using the old schema
+        assert_eq!(
+            stream
+                .map(|resp| resp.into_result().unwrap().data)
+                .collect::<Vec<_>>()
+                .await,
+            vec![value!({ "values": 2 }), value!({ "values": 3 })]
+        );
+    }
+
+    #[tokio::test]
+    async fn schema_version_in_data() {
+        let query = Object::new("Query").field(Field::new(
+            "version",
+            TypeRef::named_nn(TypeRef::INT),
+            |ctx| {
+                FieldFuture::new(
+                    async move { Ok(Some(Value::from(ctx.data::<SchemaVersion>()?.0))) },
+                )
+            },
+        ));
+        let schema = SwappableSchema::new(build_schema(1, None));
+        schema.swap(
+            Schema::build("Query", None, None)
+                .register(query)
+                .finish()
+                .unwrap(),
+        );
+        assert_eq!(
+            schema
+                .execute("{ version }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "version": 1 })
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_batch() {
+        let schema = SwappableSchema::new(
+            Schema::build("Query", None, None)
+                .register(Object::new("Query").field(Field::new(
+                    "value",
+                    TypeRef::named_nn(TypeRef::INT),
+                    |_| FieldFuture::new(async move { Ok(Some(Value::from(1))) }),
+                )))
+                .limit_batch_size(1)
+                .finish()
+                .unwrap(),
+        );
+
+        let batch = crate::BatchRequest::Batch(vec!["{ value }".into(), "{ value }".into()]);
+        let resp = crate::Executor::execute_batch(&schema, batch).await;
+        assert!(!resp.is_ok());
+
+        let batch = crate::BatchRequest::Batch(vec!["{ value }".into()]);
+        let resp = crate::Executor::execute_batch(&schema, batch).await;
+        assert!(resp.is_ok());
+    }
+
+    #[tokio::test]
+    async fn try_swap_schema() {
+        let schema = SwappableSchema::new(build_schema(1, None));
+
+        assert_eq!(
+            schema.try_swap(build_schema(2, Some(TypeRef::named(TypeRef::INT)))),
+            Ok(SchemaVersion(1))
+        );
+        assert_eq!(
+            schema
+                .try_swap(build_schema(3, Some(TypeRef::named(TypeRef::STRING))))
+                .unwrap_err(),
+            vec![BreakingChange::ArgumentTypeChanged {
+                type_name: "Query".to_string(),
+                field: "value".to_string(),
+                argument: "arg".to_string(),
+                old_type: "Int".to_string(),
+                new_type: "String".to_string(),
+            }]
+        );
+        assert_eq!(
+            schema.try_swap(build_schema(4, None)).unwrap_err(),
+            vec![BreakingChange::ArgumentRemoved {
+                type_name: "Query".to_string(),
+                field: "value".to_string(),
+                argument: "arg".to_string(),
+            }]
+        );
+        assert_eq!(schema.version(), SchemaVersion(1));
+        assert_eq!(
+            schema
+                .execute("{ value }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": 2 })
+        );
+
+        let schema = SwappableSchema::new(build_schema(1, None));
+        let changes = schema
+            .try_swap(build_schema(2, Some(TypeRef::named_nn(TypeRef::INT))))
+            .unwrap_err();
+        assert_eq!(
+            changes[0].to_string(),
+            "required argument `arg` of `Query.value` was added"
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use indexmap::IndexSet;
 
-use super::{Directive, directive::to_meta_directive_invocation};
+use super::{
+    Directive,
+    base::{VisibleFn, to_meta_visible},
+    directive::to_meta_directive_invocation,
+};
 use crate::{
     dynamic::SchemaError,
     registry::{MetaType, Registry},
@@ -86,6 +90,7 @@ pub struct Union {
     inaccessible: bool,
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
+    visible: Option<VisibleFn>,
 }
 
 impl Union {
@@ -99,12 +104,14 @@ impl Union {
             inaccessible: false,
             tags: Vec::new(),
             directives: Vec::new(),
+            visible: None,
         }
     }
 
     impl_set_description!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_visible!();
     impl_directive!();
 
     /// Add a possible type to the union that must be an object
@@ -127,7 +134,7 @@ impl Union {
                 name: self.name.clone(),
                 description: self.description.clone(),
                 possible_types: self.possible_types.clone(),
-                visible: None,
+                visible: to_meta_visible(&self.visible),
                 inaccessible: self.inaccessible,
                 tags: self.tags.clone(),
                 rust_typename: None,
@@ -140,10 +147,85 @@ impl Union {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use async_graphql_parser::Pos;
 
     use crate::{PathSegment, Request, ServerError, Value, dynamic::*, value};
 
+    #[tokio::test]
+    async fn union_selection_fields_by_type() {
+        type Selected = std::sync::Mutex<Vec<(String, Vec<String>)>>;
+        let selected = Arc::new(Selected::default());
+
+        let obj_a = Object::new("MyObjA")
+            .field(Field::new("a", TypeRef::named_nn(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(100))) })
+            }))
+            .field(Field::new("b", TypeRef::named_nn(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(200))) })
+            }));
+        let obj_b =
+            Object::new("MyObjB").field(Field::new("c", TypeRef::named_nn(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(300))) })
+            }));
+        let union = Union::new("MyUnion")
+            .possible_type(obj_a.type_name())
+            .possible_type(obj_b.type_name());
+        let query = Object::new("Query").field(Field::new(
+            "value",
+            TypeRef::named_nn(union.type_name()),
+            |ctx| {
+                let selected = ctx
+                    .selection_fields_by_type()
+                    .into_iter()
+                    .map(|(type_name, fields)| {
+                        (
+                            type_name.to_string(),
+                            fields
+                                .iter()
+                                .map(|field| field.name().to_string())
+                                .collect(),
+                        )
+                    })
+                    .collect();
+                *ctx.data_unchecked::<Arc<Selected>>().lock().unwrap() = selected;
+                FieldFuture::new(async { Ok(Some(FieldValue::NULL.with_type("MyObjA"))) })
+            },
+        ));
+
+        let schema = Schema::build(query.type_name(), None, None)
+            .register(obj_a)
+            .register(obj_b)
+            .register(union)
+            .register(query)
+            .data(selected.clone())
+            .finish()
+            .unwrap();
+
+        let query = r#"
+            {
+                value { __typename ... on MyObjA { a } ...B ... on MyUnion { ... on MyObjA { b } } }
+            }
+
+            fragment B on MyObjB { c }
+        "#;
+        schema.execute(query).await.into_result().unwrap();
+        assert_eq!(
+            *selected.lock().unwrap(),
+            vec![
+                (
+                    "MyObjA".to_string(),
+                    vec!["__typename".to_string(), "a".to_string(), "b".to_string()]
+                ),
+                (
+                    "MyObjB".to_string(),
+                    vec!["__typename".to_string(), "c".to_string()]
+                ),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn basic_union() {
         let obj_a = Object::new("MyObjA")
@@ -256,7 +338,8 @@ mod tests {
                 source: None,
                 locations: vec![Pos {
                     column: 17,
-                    line: 3
+                    line: 3,
+                    ..Default::default()
                 }],
                 path: vec![PathSegment::Field("valueA".to_owned())],
                 extensions: None,
@@ -6,7 +6,7 @@ use serde::de::DeserializeOwned;
 use crate::{Error, Name, Result, Upload, Value};
 
 /// A value accessor
-pub struct ValueAccessor<'a>(&'a Value);
+pub struct ValueAccessor<'a>(pub(crate) &'a Value);
 
 impl<'a> ValueAccessor<'a> {
     /// Returns `true` if the value is null, otherwise returns `false`
@@ -175,6 +175,15 @@ impl<'a> ObjectAccessor<'a> {
     pub fn as_index_map(&'a self) -> &'a IndexMap<Name, Value> {
         &self.0
     }
+
+    /// Deserialize the object to `T`
+    ///
+    /// This is a convenient way to read all the arguments of a field into a
+    /// struct.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T> {
+        T::deserialize(Value::Object(self.0.clone().into_owned()))
+            .map_err(|err| format!("internal: {}", err).into())
+    }
 }
 
 /// A list accessor
@@ -227,4 +236,10 @@ impl<'a> ListAccessor<'a> {
     pub fn as_values_slice(&self) -> &'a [Value] {
         self.0
     }
+
+    /// Deserialize the list to `T`
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T> {
+        T::deserialize(Value::List(self.0.to_vec()))
+            .map_err(|err| format!("internal: {}", err).into())
+    }
 }
@@ -122,6 +122,53 @@ impl ServerError {
         self.source.as_ref().map(|err| err.downcast_ref()).flatten()
     }
 
+    /// Get the lines of the query source that the locations of the error
+    /// point to, in the same order as the locations.
+    ///
+    /// Locations outside of the source are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// for query in ["{\r\n  value\r\n  unknown\r\n}", "{\r  value\r  unknown\r}"] {
+    ///     let err = schema
+    ///         .execute(query)
+    ///         .await
+    ///         .into_result()
+    ///         .unwrap_err()
+    ///         .remove(0);
+    ///     assert_eq!(err.locations, vec![Pos::from((3, 3))]);
+    ///     assert_eq!(err.source_snippets(query), vec!["  unknown"]);
+    /// }
+    /// # });
+    /// ```
+    pub fn source_snippets<'a>(&self, source: &'a str) -> Vec<&'a str> {
+        self.locations
+            .iter()
+            .filter_map(|pos| {
+                // "\r\n", "\n" and "\r" terminate lines, as in the lexer
+                source
+                    .split('\n')
+                    .flat_map(|line| line.strip_suffix('\r').unwrap_or(line).split('\r'))
+                    .nth(pos.line.checked_sub(1)?)
+            })
+            .collect()
+    }
+
     #[doc(hidden)]
     #[must_use]
     pub fn with_path(self, path: Vec<PathSegment>) -> Self {
@@ -166,6 +213,18 @@ pub enum PathSegment {
     Index(usize),
 }
 
+/// Converts an input path to the value of the `inputPath` error extension.
+pub(crate) fn input_path_value(path: &[PathSegment]) -> Value {
+    Value::List(
+        path.iter()
+            .map(|segment| match segment {
+                PathSegment::Field(name) => Value::String(name.clone()),
+                PathSegment::Index(idx) => Value::from(*idx),
+            })
+            .collect(),
+    )
+}
+
 /// Alias for `Result<T, ServerError>`.
 pub type ServerResult<T> = std::result::Result<T, ServerError>;
 
@@ -177,6 +236,7 @@ pub type ServerResult<T> = std::result::Result<T, ServerError>;
 pub struct InputValueError<T> {
     message: String,
     extensions: Option<ErrorExtensionValues>,
+    input_path: Option<Vec<PathSegment>>,
     phantom: PhantomData<T>,
 }
 
@@ -185,6 +245,7 @@ impl<T: InputType> InputValueError<T> {
         Self {
             message,
             extensions,
+            input_path: None,
             phantom: PhantomData,
         }
     }
@@ -216,20 +277,71 @@ impl<T: InputType> InputValueError<T> {
 
     /// Propagate the error message to a different type.
     pub fn propagate<U: InputType>(self) -> InputValueError<U> {
-        if T::type_name() != U::type_name() {
-            InputValueError::new(
-                format!(
-                    r#"{} (occurred while parsing "{}")"#,
-                    self.message,
-                    U::type_name()
-                ),
-                self.extensions,
+        let message = if T::type_name() != U::type_name() {
+            format!(
+                r#"{} (occurred while parsing "{}")"#,
+                self.message,
+                U::type_name()
             )
         } else {
-            InputValueError::new(self.message, self.extensions)
+            self.message
+        };
+        InputValueError {
+            message,
+            extensions: self.extensions,
+            input_path: self.input_path,
+            phantom: PhantomData,
         }
     }
 
+    /// Propagate the error message to the input object containing the field
+    /// `name`, prepending `name` to the input path if one is being reported.
+    #[doc(hidden)]
+    pub fn propagate_field<U: InputType>(self, name: &str) -> InputValueError<U> {
+        self.propagate().prefix_input_path(name)
+    }
+
+    /// Set the input field that caused the error.
+    ///
+    /// The path of the field, starting from the argument that contains it, is
+    /// reported in the `inputPath` extension of the resulting server error.
+    /// Call this repeatedly to point at a field of a nested input object.
+    #[must_use]
+    pub fn with_field(mut self, name: impl Into<String>) -> Self {
+        self.input_path
+            .get_or_insert_with(Vec::new)
+            .push(PathSegment::Field(name.into()));
+        self
+    }
+
+    /// Report the input path of the error in the `inputPath` extension, even
+    /// if no field has been set with [`InputValueError::with_field`].
+    #[must_use]
+    pub fn with_input_path(mut self) -> Self {
+        self.input_path.get_or_insert_with(Vec::new);
+        self
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub fn prefix_input_path(mut self, name: &str) -> Self {
+        if let Some(path) = &mut self.input_path {
+            path.insert(0, PathSegment::Field(name.to_string()));
+        }
+        self
+    }
+
+    /// Prepend the index of a list item to the input path if one is being
+    /// reported.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn prefix_input_index(mut self, idx: usize) -> Self {
+        if let Some(path) = &mut self.input_path {
+            path.insert(0, PathSegment::Index(idx));
+        }
+        self
+    }
+
     /// Set an extension value.
     pub fn with_extension(mut self, name: impl AsRef<str>, value: impl Into<Value>) -> Self {
         self.extensions
@@ -238,10 +350,26 @@ impl<T: InputType> InputValueError<T> {
         self
     }
 
+    /// Returns the message of the error without the name of the type that
+    /// failed to parse.
+    #[cfg(feature = "dynamic-schema")]
+    pub(crate) fn into_raw_message(self) -> String {
+        let prefix = format!(r#"Failed to parse "{}": "#, T::type_name());
+        match self.message.strip_prefix(&prefix) {
+            Some(message) => message.to_string(),
+            None => self.message,
+        }
+    }
+
     /// Convert the error into a server error.
     pub fn into_server_error(self, pos: Pos) -> ServerError {
         let mut err = ServerError::new(self.message, Some(pos));
         err.extensions = self.extensions;
+        if let Some(path) = self.input_path.filter(|path| !path.is_empty()) {
+            err.extensions
+                .get_or_insert_with(ErrorExtensionValues::default)
+                .set("inputPath", input_path_value(&path));
+        }
         err
     }
 }
@@ -0,0 +1,465 @@
+use std::collections::HashMap;
+
+use async_graphql_value::Variables;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    Name, Pos, Positioned, Value,
+    custom_directive::contains_custom_directives,
+    parser::types::{
+        DocumentOperations, ExecutableDocument, Field, FragmentDefinition, OperationType,
+        Selection, SelectionSet,
+    },
+    registry::{MetaType, MetaTypeName, Registry},
+    schema::{SchemaEnv, remove_skipped_selection},
+};
+
+/// An operation compiled for repeated executions, see
+/// [`SchemaBuilder::enable_compiled_operations`](crate::SchemaBuilder::enable_compiled_operations).
+///
+/// The plan holds the fields collected by each selection set for each object
+/// type, with the fragments merged and the `@skip` and `@include` directives
+/// applied, and the arguments of the fields that do not use variables.
+#[derive(Debug)]
+pub struct ExecutionPlan {
+    selection_set: Box<Positioned<SelectionSet>>,
+    fragments: Vec<Positioned<FragmentDefinition>>,
+    /// The collected fields, by the address of the selection set.
+    selection_sets: FxHashMap<usize, Vec<CollectedFields>>,
+    /// The constant arguments, by the address of the field.
+    arguments: FxHashMap<usize, Vec<(Name, Pos, Value)>>,
+}
+
+/// The selections collected from a selection set, when it is resolved by a
+/// container of type `parent_type` whose object type is `type_name`.
+#[derive(Debug)]
+struct CollectedFields {
+    parent_type: String,
+    type_name: String,
+    selections: Vec<CollectedSelection>,
+}
+
+#[derive(Debug)]
+enum CollectedSelection {
+    /// A field resolved by the container.
+    Field(NodeRef),
+    /// A fragment whose fields are collected by the object of an interface or
+    /// a union.
+    Fragment(NodeRef),
+}
+
+/// The location of a node from the selection set that collects it, or from a
+/// fragment, through inline fragments.
+#[derive(Debug)]
+struct NodeRef {
+    fragment: Option<usize>,
+    path: Box<[usize]>,
+}
+
+/// A selection collected by an [`ExecutionPlan`].
+pub(crate) enum PlannedSelection<'a> {
+    Field(&'a Positioned<Field>),
+    Fragment(&'a Positioned<SelectionSet>),
+}
+
+fn address<T>(node: &T) -> usize {
+    node as *const T as usize
+}
+
+/// Returns the selection set at the end of `path`, and the index of the last
+/// selection if `field` is set.
+fn resolve_path<'a>(
+    fragments: &'a [Positioned<FragmentDefinition>],
+    selection_set: &'a Positioned<SelectionSet>,
+    node: &NodeRef,
+    field: bool,
+) -> (&'a Positioned<SelectionSet>, Option<usize>) {
+    let mut selection_set = match node.fragment {
+        Some(idx) => &fragments[idx].node.selection_set,
+        None => selection_set,
+    };
+    let (path, last) = match (field, node.path.split_last()) {
+        (true, Some((last, path))) => (path, Some(*last)),
+        _ => (&*node.path, None),
+    };
+    for idx in path {
+        if let Selection::InlineFragment(fragment) = &selection_set.node.items[*idx].node {
+            selection_set = &fragment.node.selection_set;
+        }
+    }
+    (selection_set, last)
+}
+
+fn resolve_field<'a>(
+    fragments: &'a [Positioned<FragmentDefinition>],
+    selection_set: &'a Positioned<SelectionSet>,
+    node: &NodeRef,
+) -> Option<&'a Positioned<Field>> {
+    let (selection_set, idx) = resolve_path(fragments, selection_set, node, true);
+    match &selection_set.node.items.get(idx?)?.node {
+        Selection::Field(field) => Some(field),
+        _ => None,
+    }
+}
+
+fn has_variable_conditions(selection_set: &SelectionSet) -> bool {
+    selection_set.items.iter().any(|selection| {
+        selection.node.directives().iter().any(|directive| {
+            matches!(directive.node.name.node.as_str(), "skip" | "include")
+                && directive
+                    .node
+                    .get_argument("if")
+                    .is_some_and(|value| value.node.clone().into_const().is_none())
+        }) || match &selection.node {
+            Selection::Field(field) => has_variable_conditions(&field.node.selection_set.node),
+            Selection::FragmentSpread(_) => false,
+            Selection::InlineFragment(fragment) => {
+                has_variable_conditions(&fragment.node.selection_set.node)
+            }
+        }
+    })
+}
+
+impl ExecutionPlan {
+    /// Compiles the operation of a validated document.
+    ///
+    /// Returns `None` for subscriptions, and for the operations that cannot be
+    /// planned without the variables of the request: the ones with `@skip`
+    /// or `@include` directives using variables, or with custom directives
+    /// that may transform the selections.
+    pub(crate) fn new(
+        schema_env: &SchemaEnv,
+        doc: &ExecutableDocument,
+        operation_name: Option<&str>,
+    ) -> Option<Self> {
+        let operation = match (&doc.operations, operation_name) {
+            (DocumentOperations::Single(operation), None) => operation,
+            (DocumentOperations::Multiple(operations), Some(name)) => operations.get(name)?,
+            (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => {
+                operations.values().next()?
+            }
+            _ => return None,
+        };
+        let registry = &schema_env.registry;
+        let root_type = match operation.node.ty {
+            OperationType::Query => registry.query_type.as_str(),
+            OperationType::Mutation => registry.mutation_type.as_deref()?,
+            OperationType::Subscription => return None,
+        };
+
+        let selection_sets = std::iter::once(&operation.node.selection_set).chain(
+            doc.fragments
+                .values()
+                .map(|fragment| &fragment.node.selection_set),
+        );
+        for selection_set in selection_sets {
+            if has_variable_conditions(&selection_set.node)
+                || contains_custom_directives(schema_env, &selection_set.node)
+            {
+                return None;
+            }
+        }
+
+        let variables = Variables::default();
+        let mut selection_set = Box::new(operation.node.selection_set.clone());
+        remove_skipped_selection(&mut selection_set.node, &variables);
+        let mut fragment_indexes = HashMap::new();
+        let mut fragments = Vec::with_capacity(doc.fragments.len());
+        for (name, fragment) in &doc.fragments {
+            let mut fragment = fragment.clone();
+            remove_skipped_selection(&mut fragment.node.selection_set.node, &variables);
+            fragment_indexes.insert(name.clone(), fragments.len());
+            fragments.push(fragment);
+        }
+
+        let mut compiler = Compiler {
+            registry,
+            fragments: &fragments,
+            fragment_indexes: &fragment_indexes,
+            selection_sets: Default::default(),
+            arguments: Default::default(),
+        };
+        compiler.compile_selection_set(&selection_set, root_type);
+        compiler.compile_arguments(&selection_set.node);
+        for fragment in &fragments {
+            compiler.compile_arguments(&fragment.node.selection_set.node);
+        }
+        let Compiler {
+            selection_sets,
+            arguments,
+            ..
+        } = compiler;
+
+        // the addresses are stable, the nodes are not moved out of their
+        // allocations
+        Some(Self {
+            selection_set,
+            fragments,
+            selection_sets,
+            arguments,
+        })
+    }
+
+    /// Returns the selection set of the operation.
+    pub(crate) fn selection_set(&self) -> &Positioned<SelectionSet> {
+        &self.selection_set
+    }
+
+    /// Returns the selections collected from `selection_set` by a container
+    /// of type `parent_type` whose object type is `type_name`, if the
+    /// selection set belongs to the plan.
+    pub(crate) fn collect<'a>(
+        &'a self,
+        selection_set: &'a Positioned<SelectionSet>,
+        parent_type: &str,
+        type_name: &str,
+    ) -> Option<impl Iterator<Item = PlannedSelection<'a>>> {
+        let collected = self
+            .selection_sets
+            .get(&address(selection_set))?
+            .iter()
+            .find(|collected| {
+                collected.parent_type == parent_type && collected.type_name == type_name
+            })?;
+        Some(
+            collected
+                .selections
+                .iter()
+                .filter_map(move |selection| match selection {
+                    CollectedSelection::Field(node) => {
+                        resolve_field(&self.fragments, selection_set, node)
+                            .map(PlannedSelection::Field)
+                    }
+                    CollectedSelection::Fragment(node) => Some(PlannedSelection::Fragment(
+                        resolve_path(&self.fragments, selection_set, node, false).0,
+                    )),
+                }),
+        )
+    }
+
+    /// Returns the value of the argument `name` of `field`, if the field
+    /// belongs to the plan and the argument does not use variables.
+    pub(crate) fn argument(&self, field: &Positioned<Field>, name: &str) -> Option<(Pos, &Value)> {
+        self.arguments
+            .get(&address(field))?
+            .iter()
+            .find(|(argument, _, _)| argument == name)
+            .map(|(_, pos, value)| (*pos, value))
+    }
+}
+
+struct Compiler<'a> {
+    registry: &'a Registry,
+    fragments: &'a [Positioned<FragmentDefinition>],
+    fragment_indexes: &'a HashMap<Name, usize>,
+    selection_sets: FxHashMap<usize, Vec<CollectedFields>>,
+    arguments: FxHashMap<usize, Vec<(Name, Pos, Value)>>,
+}
+
+impl<'a> Compiler<'a> {
+    /// Compiles a selection set for each object type of `type_name`.
+    fn compile_selection_set(
+        &mut self,
+        selection_set: &'a Positioned<SelectionSet>,
+        type_name: &str,
+    ) {
+        let Some(ty) = self.registry.types.get(type_name) else {
+            return;
+        };
+        match ty {
+            MetaType::Object { .. } => {
+                self.compile_collected_fields(selection_set, type_name, type_name)
+            }
+            MetaType::Interface { .. } | MetaType::Union { .. } => {
+                for object in ty.possible_types().into_iter().flatten() {
+                    if matches!(
+                        self.registry.types.get(object),
+                        Some(MetaType::Object { .. })
+                    ) {
+                        self.compile_collected_fields(selection_set, type_name, object);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn compile_collected_fields(
+        &mut self,
+        selection_set: &'a Positioned<SelectionSet>,
+        parent_type: &str,
+        type_name: &str,
+    ) {
+        if self
+            .selection_sets
+            .get(&address(selection_set))
+            .is_some_and(|collected| {
+                collected.iter().any(|collected| {
+                    collected.parent_type == parent_type && collected.type_name == type_name
+                })
+            })
+        {
+            return;
+        }
+
+        let mut selections = Vec::new();
+        self.collect(
+            &selection_set.node,
+            parent_type,
+            type_name,
+            None,
+            &mut Vec::new(),
+            &mut selections,
+        );
+
+        for selection in &selections {
+            match selection {
+                CollectedSelection::Field(node) => {
+                    let Some(field) = resolve_field(self.fragments, selection_set, node) else {
+                        continue;
+                    };
+                    let ty = self
+                        .registry
+                        .types
+                        .get(parent_type)
+                        .and_then(|ty| ty.field_by_name(&field.node.name.node))
+                        .map(|field| MetaTypeName::concrete_typename(&field.ty));
+                    if let Some(ty) = ty {
+                        self.compile_selection_set(&field.node.selection_set, ty);
+                    }
+                }
+                CollectedSelection::Fragment(node) => {
+                    let (fragment, _) = resolve_path(self.fragments, selection_set, node, false);
+                    self.compile_collected_fields(fragment, type_name, type_name);
+                }
+            }
+        }
+
+        self.selection_sets
+            .entry(address(selection_set))
+            .or_default()
+            .push(CollectedFields {
+                parent_type: parent_type.to_string(),
+                type_name: type_name.to_string(),
+                selections,
+            });
+    }
+
+    /// Collects the selections like `Fields::add_set`.
+    fn collect(
+        &self,
+        selection_set: &SelectionSet,
+        parent_type: &str,
+        type_name: &str,
+        fragment: Option<usize>,
+        path: &mut Vec<usize>,
+        selections: &mut Vec<CollectedSelection>,
+    ) {
+        for (idx, selection) in selection_set.items.iter().enumerate() {
+            let (type_condition, fragment_selection_set, fragment_idx) = match &selection.node {
+                Selection::Field(_) => {
+                    path.push(idx);
+                    selections.push(CollectedSelection::Field(NodeRef {
+                        fragment,
+                        path: path.as_slice().into(),
+                    }));
+                    path.pop();
+                    continue;
+                }
+                Selection::FragmentSpread(spread) => {
+                    let Some(&fragment_idx) =
+                        self.fragment_indexes.get(&spread.node.fragment_name.node)
+                    else {
+                        continue;
+                    };
+                    let definition = &self.fragments[fragment_idx].node;
+                    (
+                        Some(definition.type_condition.node.on.node.as_str()),
+                        &definition.selection_set.node,
+                        Some(fragment_idx),
+                    )
+                }
+                Selection::InlineFragment(inline_fragment) => (
+                    inline_fragment
+                        .node
+                        .type_condition
+                        .as_ref()
+                        .map(|condition| condition.node.on.node.as_str()),
+                    &inline_fragment.node.selection_set.node,
+                    None,
+                ),
+            };
+
+            let applies_concrete_object = type_condition.is_some_and(|condition| {
+                type_name == condition
+                    || self
+                        .registry
+                        .implements
+                        .get(type_name)
+                        .is_some_and(|interfaces| interfaces.contains(condition))
+            });
+            let applies = applies_concrete_object
+                || type_condition.is_none_or(|condition| parent_type == condition);
+            if !applies {
+                continue;
+            }
+
+            // the path of the fragment, from the fragment definition or the
+            // inline fragment
+            let (node_fragment, mut node_path) = match fragment_idx {
+                Some(fragment_idx) => (Some(fragment_idx), Vec::new()),
+                None => {
+                    let mut path = path.clone();
+                    path.push(idx);
+                    (fragment, path)
+                }
+            };
+
+            if applies_concrete_object && parent_type != type_name {
+                // collected by the object of the interface or the union
+                selections.push(CollectedSelection::Fragment(NodeRef {
+                    fragment: node_fragment,
+                    path: node_path.into(),
+                }));
+            } else {
+                self.collect(
+                    fragment_selection_set,
+                    parent_type,
+                    type_name,
+                    node_fragment,
+                    &mut node_path,
+                    selections,
+                );
+            }
+        }
+    }
+
+    fn compile_arguments(&mut self, selection_set: &'a SelectionSet) {
+        for selection in &selection_set.items {
+            match &selection.node {
+                Selection::Field(field) => {
+                    let arguments = field
+                        .node
+                        .arguments
+                        .iter()
+                        .filter_map(|(name, value)| {
+                            Some((
+                                name.node.clone(),
+                                value.pos,
+                                value.node.clone().into_const()?,
+                            ))
+                        })
+                        .collect::<Vec<_>>();
+                    if !arguments.is_empty() {
+                        self.arguments.insert(address(field), arguments);
+                    }
+                    self.compile_arguments(&field.node.selection_set.node);
+                }
+                Selection::FragmentSpread(_) => {}
+                Selection::InlineFragment(inline_fragment) => {
+                    self.compile_arguments(&inline_fragment.node.selection_set.node)
+                }
+            }
+        }
+    }
+}
@@ -24,7 +24,9 @@ pub trait Executor: Unpin + Clone + Send + Sync + 'static {
             BatchRequest::Single(request) => BatchResponse::Single(self.execute(request).await),
             BatchRequest::Batch(requests) => BatchResponse::Batch(
                 FuturesOrdered::from_iter(
-                    requests.into_iter().map(|request| self.execute(request)),
+                    requests
+                        .into_iter()
+                        .map(|request| self.execute(request.mark_in_batch())),
                 )
                 .collect()
                 .await,
@@ -43,7 +45,9 @@ pub trait Executor: Unpin + Clone + Send + Sync + 'static {
                 BatchRequest::Single(request) => BatchResponse::Single(self.execute(request).await),
                 BatchRequest::Batch(requests) => BatchResponse::Batch(
                     FuturesOrdered::from_iter(
-                        requests.into_iter().map(|request| self.execute(request)),
+                        requests
+                            .into_iter()
+                            .map(|request| self.execute(request.mark_in_batch())),
                     )
                     .collect()
                     .await,
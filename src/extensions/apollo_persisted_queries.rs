@@ -102,9 +102,11 @@ impl<T: CacheStorage> Extension for ApolloPersistedQueriesExtension<T> {
             }
 
             if request.query.is_empty() {
-                if let Some(doc) = self.storage.get(persisted_query.sha256_hash).await {
+                if let Some(doc) = self.storage.get(persisted_query.sha256_hash.clone()).await {
                     Ok(Request {
                         parsed_query: Some(doc),
+                        #[cfg(feature = "validation_cache")]
+                        query_hash: Some(persisted_query.sha256_hash),
                         ..request
                     })
                 } else {
@@ -116,11 +118,16 @@ impl<T: CacheStorage> Extension for ApolloPersistedQueriesExtension<T> {
                 if persisted_query.sha256_hash != sha256_hash {
                     Err(ServerError::new("provided sha does not match query", None))
                 } else {
-                    let doc = async_graphql_parser::parse_query(&request.query)?;
-                    self.storage.set(sha256_hash, doc.clone()).await;
+                    let doc = async_graphql_parser::parse_query_with_options(
+                        &request.query,
+                        ctx.schema_env.parse_options,
+                    )?;
+                    self.storage.set(sha256_hash.clone(), doc.clone()).await;
                     Ok(Request {
                         query: String::new(),
                         parsed_query: Some(doc),
+                        #[cfg(feature = "validation_cache")]
+                        query_hash: Some(sha256_hash),
                         ..request
                     })
                 }
@@ -198,4 +205,41 @@ mod tests {
             vec![ServerError::new("PersistedQueryNotFound", None)]
         );
     }
+
+    #[tokio::test]
+    async fn test_parse_options() {
+        use super::*;
+        use crate::*;
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self) -> i32 {
+                100
+            }
+        }
+
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .parse_options(ParseOptions::new().max_tokens(3))
+            .extension(ApolloPersistedQueries::new(LruCacheStorage::new(256)))
+            .finish();
+
+        let query = "{ value a: value }";
+        let mut request = Request::new(query);
+        request.extensions.insert(
+            "persistedQuery".to_string(),
+            value!({
+                "version": 1,
+                "sha256Hash": format!("{:x}", Sha256::digest(query.as_bytes())),
+            }),
+        );
+        let errors = schema.execute(request).await.into_result().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].message.contains("tokens"),
+            "{}",
+            errors[0].message
+        );
+    }
 }
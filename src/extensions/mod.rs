@@ -7,6 +7,8 @@ pub mod apollo_persisted_queries;
 mod apollo_tracing;
 #[cfg(feature = "log")]
 mod logger;
+#[cfg(feature = "response_cache")]
+pub mod response_cache;
 
 #[cfg(feature = "tracing")]
 mod tracing;
@@ -26,9 +28,11 @@ pub use self::apollo_tracing::ApolloTracing;
 pub use self::logger::Logger;
 #[cfg(feature = "tracing")]
 pub use self::tracing::Tracing;
+#[cfg(feature = "operation_signature")]
+use crate::OperationSignature;
 use crate::{
-    Data, DataContext, Error, QueryPathNode, Request, Response, Result, SDLExportOptions,
-    SchemaEnv, ServerError, ServerResult, ValidationResult, Value, Variables,
+    Data, DataContext, Error, NormalizeOptions, QueryPathNode, Request, Response, Result,
+    SDLExportOptions, SchemaEnv, ServerError, ServerResult, ValidationResult, Value, Variables,
     parser::types::{ExecutableDocument, Field},
 };
 
@@ -53,6 +57,9 @@ pub struct ExtensionContext<'a> {
     /// inside the [`Extension::request`], [`Extension::subscribe`], and
     /// [`Extension::prepare_request`] hooks.
     pub query_data: Option<&'a Data>,
+
+    #[cfg(feature = "response_cache")]
+    pub(crate) records: Option<&'a std::sync::Mutex<crate::context::ResolveRecords>>,
 }
 
 impl<'a> DataContext<'a> for ExtensionContext<'a> {
@@ -80,6 +87,42 @@ impl<'a> ExtensionContext<'a> {
             .unwrap_or_default()
     }
 
+    /// Normalize an operation of the [ExecutableDocument] into a canonical
+    /// query string, or returns `None` if the operation does not exist.
+    ///
+    /// The operation name is the one passed to [`Extension::execute`], it can
+    /// be omitted if the document has a single operation.
+    pub fn normalize_execute_doc(
+        &self,
+        doc: &ExecutableDocument,
+        operation_name: Option<&str>,
+        options: NormalizeOptions,
+    ) -> Option<String> {
+        self.schema_env
+            .registry
+            .normalize_exec_doc(doc, operation_name, options)
+    }
+
+    /// Returns the Apollo-style signature of an operation of the
+    /// [ExecutableDocument] and its hash, or `None` if the operation does not
+    /// exist.
+    ///
+    /// The operations with the same shape have the same signature, which can be
+    /// used to group metrics. Literals and aliases are stripped, so operations
+    /// returning different results can share a signature: to key caches or
+    /// persisted queries, use [`ExtensionContext::normalize_execute_doc`] with
+    /// `NormalizeOptions::default().keep_literals().keep_aliases()` instead.
+    #[cfg(feature = "operation_signature")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operation_signature")))]
+    pub fn operation_signature(
+        &self,
+        doc: &ExecutableDocument,
+        operation_name: Option<&str>,
+    ) -> Option<OperationSignature> {
+        self.normalize_execute_doc(doc, operation_name, Default::default())
+            .map(OperationSignature::new)
+    }
+
     /// Returns SDL(Schema Definition Language) of this schema.
     pub fn sdl(&self) -> String {
         self.schema_env.registry.export_sdl(Default::default())
@@ -459,6 +502,8 @@ pub struct Extensions {
     schema_env: SchemaEnv,
     session_data: Arc<Data>,
     query_data: Option<Arc<Data>>,
+    #[cfg(feature = "response_cache")]
+    records: Option<Arc<std::sync::Mutex<crate::context::ResolveRecords>>>,
 }
 
 #[doc(hidden)]
@@ -473,6 +518,8 @@ impl Extensions {
             schema_env,
             session_data,
             query_data: None,
+            #[cfg(feature = "response_cache")]
+            records: None,
         }
     }
 
@@ -481,6 +528,15 @@ impl Extensions {
         self.query_data = Some(data);
     }
 
+    #[cfg(feature = "response_cache")]
+    #[inline]
+    pub(crate) fn attach_records(
+        &mut self,
+        records: Arc<std::sync::Mutex<crate::context::ResolveRecords>>,
+    ) {
+        self.records = Some(records);
+    }
+
     #[inline]
     pub(crate) fn is_empty(&self) -> bool {
         self.extensions.is_empty()
@@ -492,6 +548,8 @@ impl Extensions {
             schema_env: &self.schema_env,
             session_data: &self.session_data,
             query_data: self.query_data.as_deref(),
+            #[cfg(feature = "response_cache")]
+            records: self.records.as_deref(),
         }
     }
 
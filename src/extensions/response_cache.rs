@@ -0,0 +1,707 @@
+//! Response cache extension.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_graphql_value::Value as InputValue;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    CacheControl, Name, Positioned, QueryPathSegment, Response, ServerError, ServerResult,
+    ValidationResult, Value, Variables,
+    extensions::{
+        Extension, ExtensionContext, ExtensionFactory, NextExecute, NextParseQuery, NextResolve,
+        NextValidation, ResolveInfo,
+    },
+    parser::types::{
+        Directive, DocumentOperations, ExecutableDocument, Field, FragmentDefinition,
+        OperationDefinition, OperationType, Selection, SelectionSet,
+    },
+    registry::{MetaType, MetaTypeName, Registry},
+};
+
+/// Cache storage for the response cache.
+#[async_trait::async_trait]
+pub trait CacheStorage: Send + Sync + Clone + 'static {
+    /// Load the value by `key`.
+    async fn get(&self, key: &str) -> Option<Value>;
+
+    /// Save the value by `key`, it expires after `ttl`.
+    async fn set(&self, key: String, value: Value, ttl: Duration);
+}
+
+/// Memory-based LRU cache.
+#[derive(Clone)]
+pub struct LruCacheStorage(Arc<Mutex<lru::LruCache<String, (Instant, Value)>>>);
+
+impl LruCacheStorage {
+    /// Creates a new LRU Cache that holds at most `cap` items.
+    pub fn new(cap: usize) -> Self {
+        Self(Arc::new(Mutex::new(lru::LruCache::new(
+            NonZeroUsize::new(cap).unwrap_or(NonZeroUsize::MIN),
+        ))))
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheStorage for LruCacheStorage {
+    async fn get(&self, key: &str) -> Option<Value> {
+        let mut cache = self.0.lock().unwrap();
+        match cache.get(key) {
+            Some((expires_at, value)) if *expires_at > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                cache.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    async fn set(&self, key: String, value: Value, ttl: Duration) {
+        self.0
+            .lock()
+            .unwrap()
+            .put(key, (Instant::now() + ttl, value));
+    }
+}
+
+type UserFn = dyn Fn(&ExtensionContext<'_>) -> Option<String> + Send + Sync;
+
+/// Response cache extension.
+///
+/// Caches the resolved values of the fields whose cache control hints have a
+/// `max_age` greater than zero, and serves them without invoking the
+/// resolvers until they expire. If the hint of the whole operation has a
+/// `max_age` greater than zero, the data of the response is cached as well.
+///
+/// The hint of a field is merged with the hints of all the fields in its
+/// selection set, so a field is only cached for the shortest `max_age` below
+/// it. A field is identified by the object it belongs to, its name and its
+/// arguments, and the cached value depends on its selection set. The fields
+/// of the root object are always identified, the fields of the other objects
+/// are only cached when the resolver that returned the object has given it a
+/// key with [`Context::set_cache_key`](crate::Context::set_cache_key) or
+/// [`Context::set_cache_keys`](crate::Context::set_cache_keys).
+///
+/// Values with a `private` hint, or below a field with a `private` hint, are
+/// only cached when the function given to [`ResponseCache::user`] identifies
+/// the current user, and are not shared between users. Mutations,
+/// subscriptions, values with errors and values with a `no_store` hint are
+/// never cached. Neither are the fields with a guard, or the fields and the
+/// operations that select them, since the guards are only checked when the
+/// resolvers run.
+///
+/// Hints added with [`Context::add_cache_hint`](crate::Context::add_cache_hint)
+/// are only known once the resolvers have run, so an operation or a field
+/// whose hint was lowered by a resolver below it is not cached. The hint of a
+/// response built from cached values is therefore the same as if they had
+/// been resolved again.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{
+///     extensions::response_cache::{LruCacheStorage, ResponseCache},
+///     *,
+/// };
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     #[graphql(cache_control(max_age = 60))]
+///     async fn value(&self, ctx: &Context<'_>) -> i32 {
+///         let counter = ctx.data_unchecked::<std::sync::atomic::AtomicI32>();
+///         counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .data(std::sync::atomic::AtomicI32::new(0))
+///     .extension(ResponseCache::new(LruCacheStorage::new(256)))
+///     .finish();
+/// for _ in 0..2 {
+///     let res = schema.execute("{ value }").await.into_result().unwrap();
+///     assert_eq!(res.data, value!({ "value": 0 }));
+/// }
+/// # });
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "response_cache")))]
+pub struct ResponseCache<T> {
+    storage: T,
+    user: Option<Arc<UserFn>>,
+}
+
+impl<T: CacheStorage> ResponseCache<T> {
+    /// Creates a response cache extension.
+    pub fn new(storage: T) -> ResponseCache<T> {
+        Self {
+            storage,
+            user: None,
+        }
+    }
+
+    /// Set the function that identifies the current user, used to cache the
+    /// values with a `private` hint.
+    #[must_use]
+    pub fn user<F>(self, f: F) -> Self
+    where
+        F: Fn(&ExtensionContext<'_>) -> Option<String> + Send + Sync + 'static,
+    {
+        Self {
+            user: Some(Arc::new(f)),
+            ..self
+        }
+    }
+}
+
+impl<T: CacheStorage> ExtensionFactory for ResponseCache<T> {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ResponseCacheExtension {
+            storage: self.storage.clone(),
+            user: self.user.clone(),
+            state: Default::default(),
+        })
+    }
+}
+
+#[derive(Default)]
+struct State {
+    document: Option<ExecutableDocument>,
+    variables: Variables,
+    cache_control: CacheControl,
+    operation_type: Option<OperationType>,
+    /// The paths of the fields that have been resolved with a `private` hint,
+    /// or below such a field.
+    private: HashSet<String>,
+}
+
+struct ResponseCacheExtension<T> {
+    storage: T,
+    user: Option<Arc<UserFn>>,
+    state: Mutex<State>,
+}
+
+#[async_trait::async_trait]
+impl<T: CacheStorage> Extension for ResponseCacheExtension<T> {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let document = next.run(ctx, query, variables).await?;
+        let mut state = self.state.lock().unwrap();
+        state.document = Some(document.clone());
+        state.variables = variables.clone();
+        Ok(document)
+    }
+
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let res = next.run(ctx).await?;
+        self.state.lock().unwrap().cache_control = res.cache_control;
+        Ok(res)
+    }
+
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let Some((key, cache_control)) = self.operation_key(ctx, operation_name) else {
+            return next.run(ctx, operation_name).await;
+        };
+        if let Some(data) = self.storage.get(&key).await {
+            return Response::new(data).cache_control(cache_control);
+        }
+        let resp = next.run(ctx, operation_name).await;
+        // the hint may have been lowered by the resolvers
+        if resp.is_ok() && resp.cache_control == cache_control {
+            self.storage
+                .set(key, resp.data.clone(), max_age(&cache_control))
+                .await;
+        }
+        resp
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        let Some(field) = self.field_key(ctx, &info) else {
+            return next.run(ctx, info).await;
+        };
+        if let Some(value) = self.storage.get(&field.key).await {
+            return Ok(Some(value));
+        }
+        let value = next.run(ctx, info).await?;
+        if let Some(value) = &value
+            && self.can_store(ctx, &field)
+        {
+            self.storage
+                .set(field.key, value.clone(), max_age(&field.cache_control))
+                .await;
+        }
+        Ok(value)
+    }
+}
+
+impl<T: CacheStorage> ResponseCacheExtension<T> {
+    /// Returns the scope part of the key, or `None` if the value cannot be
+    /// cached.
+    fn scope(&self, ctx: &ExtensionContext<'_>, cache_control: &CacheControl) -> Option<String> {
+        if cache_control.max_age <= 0 || cache_control.no_store {
+            return None;
+        }
+        if cache_control.public {
+            return Some("public".to_string());
+        }
+        let user = self.user.as_ref()?(ctx)?;
+        Some(format!("private:{user}"))
+    }
+
+    fn operation_key(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+    ) -> Option<(String, CacheControl)> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        let document = state.document.as_ref()?;
+        let operation = find_operation(document, operation_name)?;
+
+        // apply the default values of the variables
+        for variable_definition in &operation.node.variable_definitions {
+            let name = &variable_definition.node.name.node;
+            if !state.variables.contains_key(name)
+                && let Some(default_value) = &variable_definition.node.default_value
+            {
+                state
+                    .variables
+                    .insert(name.clone(), default_value.node.clone());
+            }
+        }
+        state.operation_type = Some(operation.node.ty);
+
+        if operation.node.ty != OperationType::Query {
+            return None;
+        }
+        let registry = &ctx.schema_env.registry;
+        let mut hints = Hints::default();
+        selection_set_hints(
+            registry,
+            &document.fragments,
+            &registry.query_type,
+            &operation.node.selection_set.node,
+            &mut hints,
+        );
+        // serving a cached response would skip the guards
+        if hints.guarded {
+            return None;
+        }
+        let scope = self.scope(ctx, &state.cache_control)?;
+        let mut selection = String::new();
+        Canonical {
+            fragments: &document.fragments,
+            variables: &state.variables,
+        }
+        .write_selection_set(&mut selection, &operation.node.selection_set.node);
+        Some((
+            hash(&["operation", &scope, &selection]),
+            state.cache_control,
+        ))
+    }
+
+    /// Returns `true` if the value of a field that has just been resolved can
+    /// be stored.
+    fn can_store(&self, ctx: &ExtensionContext<'_>, field: &FieldKey) -> bool {
+        let Some(records) = ctx.records else {
+            return false;
+        };
+        let records = records.lock().unwrap();
+        // the value of a field below may have been replaced by `null` because of an
+        // error
+        if records.errors != field.errors {
+            return false;
+        }
+        // the hint may have been lowered by the resolvers
+        let cache_control = records
+            .cache_hints
+            .iter()
+            .filter(|(path, _)| *path == field.path || is_ancestor(&field.path, path))
+            .fold(field.cache_control, |cache_control, (_, hint)| {
+                cache_control.merge(hint)
+            });
+        cache_control == field.cache_control
+    }
+
+    fn field_key(&self, ctx: &ExtensionContext<'_>, info: &ResolveInfo<'_>) -> Option<FieldKey> {
+        if info.is_for_introspection {
+            return None;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        if state.operation_type != Some(OperationType::Query) {
+            return None;
+        }
+        let document = state.document.as_ref()?;
+        let records = ctx.records?.lock().unwrap();
+        let registry = &ctx.schema_env.registry;
+        let path = info.path_node.to_string();
+
+        // a field is private if it or one of its ancestors is private
+        let private = info
+            .path_node
+            .parents()
+            .find(|node| matches!(node.segment, QueryPathSegment::Name(_)))
+            .is_some_and(|parent| state.private.contains(&parent.to_string()))
+            || records
+                .cache_hints
+                .iter()
+                .any(|(hint_path, hint)| !hint.public && is_ancestor(hint_path, &path))
+            || !own_cache_control(registry, info.parent_type, info.name).public;
+        if private {
+            state.private.insert(path.clone());
+        }
+
+        // a field is identified by the object it belongs to, which must have
+        // been given a key by the resolver that returned it, unless it is the
+        // root object
+        let parent = match info.path_node.parent {
+            Some(parent) => format!("object:{}", records.cache_keys.get(&parent.to_string())?),
+            None => "root".to_string(),
+        };
+
+        let mut hints = Hints::default();
+        field_hints(
+            registry,
+            &document.fragments,
+            info.parent_type,
+            info.field,
+            &mut hints,
+        );
+        // serving a cached value would skip the guards
+        if hints.guarded {
+            return None;
+        }
+        let mut cache_control = hints.cache_control;
+        cache_control.public &= !private;
+        let scope = self.scope(ctx, &cache_control)?;
+
+        let canonical = Canonical {
+            fragments: &document.fragments,
+            variables: &state.variables,
+        };
+        let mut arguments = String::new();
+        canonical.write_arguments(&mut arguments, &info.field.arguments);
+        let mut selection = String::new();
+        canonical.write_selection_set(&mut selection, &info.field.selection_set.node);
+        let key = hash(&[
+            "field",
+            &scope,
+            &parent,
+            info.parent_type,
+            info.name,
+            &arguments,
+            &selection,
+        ]);
+        Some(FieldKey {
+            key,
+            path,
+            cache_control,
+            errors: records.errors,
+        })
+    }
+}
+
+/// The key of a field in the cache.
+struct FieldKey {
+    key: String,
+    path: String,
+    cache_control: CacheControl,
+    /// The number of errors reported before the field was resolved.
+    errors: usize,
+}
+
+/// Returns `true` if the field at `path` is below the field at `ancestor`.
+fn is_ancestor(ancestor: &str, path: &str) -> bool {
+    ancestor.is_empty()
+        || path
+            .strip_prefix(ancestor)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+fn max_age(cache_control: &CacheControl) -> Duration {
+    Duration::from_secs(cache_control.max_age.max(0) as u64)
+}
+
+fn hash(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+fn find_operation<'a>(
+    document: &'a ExecutableDocument,
+    operation_name: Option<&str>,
+) -> Option<&'a Positioned<OperationDefinition>> {
+    match (&document.operations, operation_name) {
+        (DocumentOperations::Single(operation), _) => Some(operation),
+        (DocumentOperations::Multiple(operations), Some(name)) => operations.get(name),
+        (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => {
+            operations.values().next()
+        }
+        (DocumentOperations::Multiple(_), None) => None,
+    }
+}
+
+/// Merges the cache control hints of a field and the type it returns.
+fn own_cache_control(registry: &Registry, parent_type: &str, name: &str) -> CacheControl {
+    let Some(meta_field) = registry
+        .types
+        .get(parent_type)
+        .and_then(|ty| ty.field_by_name(name))
+    else {
+        return CacheControl::default();
+    };
+    match registry
+        .types
+        .get(MetaTypeName::concrete_typename(&meta_field.ty))
+    {
+        Some(MetaType::Object { cache_control, .. }) => {
+            meta_field.cache_control.merge(cache_control)
+        }
+        _ => meta_field.cache_control,
+    }
+}
+
+/// What the schema says about a field and all the fields in its selection
+/// set.
+#[derive(Default)]
+struct Hints {
+    /// The merged cache control hints.
+    cache_control: CacheControl,
+    /// One of the fields has a guard, which is only checked when its resolver
+    /// runs.
+    guarded: bool,
+}
+
+/// Merges the hints of a field and all the fields in its selection set.
+fn field_hints(
+    registry: &Registry,
+    fragments: &HashMap<Name, Positioned<FragmentDefinition>>,
+    parent_type: &str,
+    field: &Field,
+    hints: &mut Hints,
+) {
+    let Some(meta_field) = registry
+        .types
+        .get(parent_type)
+        .and_then(|ty| ty.field_by_name(&field.name.node))
+    else {
+        return;
+    };
+    hints.cache_control = hints.cache_control.merge(&meta_field.cache_control);
+    hints.guarded |= is_guarded(registry, parent_type, &field.name.node);
+    selection_set_hints(
+        registry,
+        fragments,
+        MetaTypeName::concrete_typename(&meta_field.ty),
+        &field.selection_set.node,
+        hints,
+    );
+}
+
+fn selection_set_hints(
+    registry: &Registry,
+    fragments: &HashMap<Name, Positioned<FragmentDefinition>>,
+    type_name: &str,
+    selection_set: &SelectionSet,
+    hints: &mut Hints,
+) {
+    if selection_set.items.is_empty() {
+        return;
+    }
+    if let Some(MetaType::Object { cache_control, .. }) = registry.types.get(type_name) {
+        hints.cache_control = hints.cache_control.merge(cache_control);
+    }
+
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => {
+                field_hints(registry, fragments, type_name, &field.node, hints)
+            }
+            Selection::FragmentSpread(spread) => {
+                if let Some(fragment) = fragments.get(&spread.node.fragment_name.node) {
+                    selection_set_hints(
+                        registry,
+                        fragments,
+                        &fragment.node.type_condition.node.on.node,
+                        &fragment.node.selection_set.node,
+                        hints,
+                    );
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => selection_set_hints(
+                registry,
+                fragments,
+                inline_fragment
+                    .node
+                    .type_condition
+                    .as_ref()
+                    .map(|condition| condition.node.on.node.as_str())
+                    .unwrap_or(type_name),
+                &inline_fragment.node.selection_set.node,
+                hints,
+            ),
+        }
+    }
+}
+
+/// Returns `true` if the field of the type has a guard, or the same field of an
+/// interface it implements or of a type that implements it.
+fn is_guarded(registry: &Registry, type_name: &str, name: &str) -> bool {
+    let field_guarded = |type_name: &str| {
+        registry
+            .types
+            .get(type_name)
+            .and_then(|ty| ty.field_by_name(name))
+            .is_some_and(|field| field.guarded)
+    };
+    field_guarded(type_name)
+        || registry
+            .implements
+            .get(type_name)
+            .is_some_and(|interfaces| interfaces.iter().any(|name| field_guarded(name)))
+        || registry
+            .types
+            .get(type_name)
+            .and_then(|ty| ty.possible_types())
+            .is_some_and(|types| types.iter().any(|name| field_guarded(name)))
+}
+
+/// Writes selection sets in a form that only depends on their meaning, with
+/// the fragments inlined and the variables replaced by their values.
+struct Canonical<'a> {
+    fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
+    variables: &'a Variables,
+}
+
+impl Canonical<'_> {
+    fn write_selection_set(&self, out: &mut String, selection_set: &SelectionSet) {
+        if selection_set.items.is_empty() {
+            return;
+        }
+        out.push('{');
+        for selection in &selection_set.items {
+            match &selection.node {
+                Selection::Field(field) => {
+                    if let Some(alias) = &field.node.alias {
+                        let _ = write!(out, "{}:", alias.node);
+                    }
+                    out.push_str(&field.node.name.node);
+                    self.write_arguments(out, &field.node.arguments);
+                    self.write_directives(out, &field.node.directives);
+                    self.write_selection_set(out, &field.node.selection_set.node);
+                }
+                Selection::FragmentSpread(spread) => {
+                    if let Some(fragment) = self.fragments.get(&spread.node.fragment_name.node) {
+                        let _ = write!(out, "...on {}", fragment.node.type_condition.node.on.node);
+                        self.write_directives(out, &spread.node.directives);
+                        self.write_directives(out, &fragment.node.directives);
+                        self.write_selection_set(out, &fragment.node.selection_set.node);
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    out.push_str("...");
+                    if let Some(condition) = &inline_fragment.node.type_condition {
+                        let _ = write!(out, "on {}", condition.node.on.node);
+                    }
+                    self.write_directives(out, &inline_fragment.node.directives);
+                    self.write_selection_set(out, &inline_fragment.node.selection_set.node);
+                }
+            }
+            out.push(' ');
+        }
+        out.push('}');
+    }
+
+    fn write_directives(&self, out: &mut String, directives: &[Positioned<Directive>]) {
+        for directive in directives {
+            let _ = write!(out, "@{}", directive.node.name.node);
+            self.write_arguments(out, &directive.node.arguments);
+        }
+    }
+
+    fn write_arguments(
+        &self,
+        out: &mut String,
+        arguments: &[(Positioned<Name>, Positioned<InputValue>)],
+    ) {
+        if arguments.is_empty() {
+            return;
+        }
+        let mut arguments = arguments.iter().collect::<Vec<_>>();
+        arguments.sort_by(|(a, _), (b, _)| a.node.cmp(&b.node));
+        out.push('(');
+        for (name, value) in arguments {
+            let value = value
+                .node
+                .clone()
+                .into_const_with(|name| {
+                    Ok::<_, std::convert::Infallible>(
+                        self.variables.get(&name).cloned().unwrap_or_default(),
+                    )
+                })
+                .unwrap_or_default();
+            let _ = write!(out, "{}:{} ", name.node, value);
+        }
+        out.push(')');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn lru_cache_storage_expires() {
+        let storage = LruCacheStorage::new(2);
+        storage
+            .set("a".to_string(), Value::from(1), Duration::from_secs(60))
+            .await;
+        storage
+            .set("b".to_string(), Value::from(2), Duration::ZERO)
+            .await;
+        assert_eq!(storage.get("a").await, Some(Value::from(1)));
+        assert_eq!(storage.get("b").await, None);
+
+        storage
+            .set("c".to_string(), Value::from(3), Duration::from_secs(60))
+            .await;
+        storage
+            .set("d".to_string(), Value::from(4), Duration::from_secs(60))
+            .await;
+        assert_eq!(storage.get("a").await, None);
+        assert_eq!(storage.get("c").await, Some(Value::from(3)));
+        assert_eq!(storage.get("d").await, Some(Value::from(4)));
+    }
+}
@@ -0,0 +1,135 @@
+use serde::Serialize;
+
+use super::multipart_subscribe::parse_accept;
+
+/// The content type of JSON encoded GraphQL responses.
+const JSON_CONTENT_TYPE: &str = "application/graphql-response+json";
+
+/// The content type of CBOR encoded GraphQL requests and responses.
+#[cfg(feature = "cbor")]
+const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
+/// The content type of MessagePack encoded GraphQL requests and responses.
+#[cfg(feature = "msgpack")]
+const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// The encoding of a GraphQL response body.
+///
+/// The binary encodings preserve [`Value::Binary`](crate::Value::Binary)
+/// values as byte strings, where JSON has to represent them as arrays of
+/// numbers.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ResponseEncoding {
+    /// `application/graphql-response+json`
+    #[default]
+    Json,
+    /// `application/cbor`
+    #[cfg(feature = "cbor")]
+    Cbor,
+    /// `application/msgpack`
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+}
+
+impl ResponseEncoding {
+    /// Returns the encoding of a request body with the specified content
+    /// type, or `None` if it is not one of the supported encodings.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime: mime::Mime = content_type.parse().ok()?;
+        Self::from_mime(&mime)
+    }
+
+    fn from_mime(mime: &mime::Mime) -> Option<Self> {
+        match mime.essence_str() {
+            "application/json" | "application/graphql-response+json" => Some(Self::Json),
+            #[cfg(feature = "cbor")]
+            "application/cbor" => Some(Self::Cbor),
+            #[cfg(feature = "msgpack")]
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Self::MessagePack)
+            }
+            _ => None,
+        }
+    }
+
+    /// Negotiates the response encoding from the value of an `Accept`
+    /// header.
+    ///
+    /// The supported media type with the highest quality wins. JSON is used
+    /// when the header is missing or accepts none of the supported encodings.
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        accept
+            .into_iter()
+            .flat_map(parse_accept)
+            .find_map(|mime| Self::from_mime(&mime))
+            .unwrap_or_default()
+    }
+
+    /// Returns the value of the `Content-Type` header for this encoding.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => JSON_CONTENT_TYPE,
+            #[cfg(feature = "cbor")]
+            Self::Cbor => CBOR_CONTENT_TYPE,
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => MSGPACK_CONTENT_TYPE,
+        }
+    }
+
+    /// Encodes a value, typically a [`Response`](crate::Response) or a
+    /// [`BatchResponse`](crate::BatchResponse), with this encoding.
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, std::io::Error> {
+        match self {
+            Self::Json => serde_json::to_vec(value).map_err(std::io::Error::other),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => serde_cbor::to_vec(value).map_err(std::io::Error::other),
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => rmp_serde::to_vec_named(value).map_err(std::io::Error::other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_accept() {
+        assert_eq!(ResponseEncoding::from_accept(None), ResponseEncoding::Json);
+        assert_eq!(
+            ResponseEncoding::from_accept(Some("application/graphql-response+json")),
+            ResponseEncoding::Json
+        );
+        assert_eq!(
+            ResponseEncoding::from_accept(Some("text/html, */*")),
+            ResponseEncoding::Json
+        );
+        #[cfg(feature = "cbor")]
+        {
+            assert_eq!(
+                ResponseEncoding::from_accept(Some("application/cbor")),
+                ResponseEncoding::Cbor
+            );
+            assert_eq!(
+                ResponseEncoding::from_accept(Some("application/json;q=0.9, application/cbor")),
+                ResponseEncoding::Cbor
+            );
+            assert_eq!(
+                ResponseEncoding::from_accept(Some("application/json, application/cbor;q=0.5")),
+                ResponseEncoding::Json
+            );
+            assert_eq!(
+                ResponseEncoding::from_accept(Some(
+                    "application/graphql-response+json, application/cbor;q=0.5"
+                )),
+                ResponseEncoding::Json
+            );
+        }
+        #[cfg(feature = "msgpack")]
+        assert_eq!(
+            ResponseEncoding::from_accept(Some("application/x-msgpack")),
+            ResponseEncoding::MessagePack
+        );
+    }
+}
@@ -1,11 +1,13 @@
 //! A helper module that supports HTTP
 
+mod encoding;
 #[cfg(feature = "graphiql")]
 mod graphiql_source;
 mod multipart;
 mod multipart_subscribe;
 mod websocket;
 
+pub use encoding::ResponseEncoding;
 use futures_util::io::{AsyncRead, AsyncReadExt};
 #[cfg(feature = "graphiql")]
 pub use graphiql_source::{Credentials, GraphiQLSource};
@@ -89,7 +91,7 @@ pub async fn receive_batch_body(
                 ))
             }
         }
-        // application/json (currently)
+        // application/json, application/cbor or application/msgpack
         _ => receive_batch_body_no_multipart(&content_type, body).await,
     }
 }
@@ -102,7 +104,14 @@ pub(super) async fn receive_batch_body_no_multipart(
     body: impl AsyncRead + Send,
 ) -> Result<BatchRequest, ParseRequestError> {
     assert_ne!(content_type.type_(), mime::MULTIPART, "received multipart");
-    receive_batch_json(body).await
+    match ResponseEncoding::from_content_type(content_type.essence_str()) {
+        #[cfg(feature = "cbor")]
+        Some(ResponseEncoding::Cbor) => receive_batch_cbor(body).await,
+        #[cfg(feature = "msgpack")]
+        Some(ResponseEncoding::MessagePack) => receive_batch_msgpack(body).await,
+        // default to json
+        _ => receive_batch_json(body).await,
+    }
 }
 
 /// Receive a GraphQL request from a body as JSON.
@@ -121,6 +130,44 @@ pub async fn receive_batch_json(body: impl AsyncRead) -> Result<BatchRequest, Pa
         .map_err(|e| ParseRequestError::InvalidRequest(Box::new(e)))
 }
 
+/// Receive a GraphQL request from a body as CBOR.
+#[cfg(feature = "cbor")]
+pub async fn receive_cbor(body: impl AsyncRead) -> Result<Request, ParseRequestError> {
+    receive_batch_cbor(body).await?.into_single()
+}
+
+/// Receive a GraphQL batch request from a body as CBOR.
+#[cfg(feature = "cbor")]
+pub async fn receive_batch_cbor(body: impl AsyncRead) -> Result<BatchRequest, ParseRequestError> {
+    let mut data = Vec::new();
+    futures_util::pin_mut!(body);
+    body.read_to_end(&mut data)
+        .await
+        .map_err(ParseRequestError::Io)?;
+    serde_cbor::from_slice::<BatchRequest>(&data)
+        .map_err(|e| ParseRequestError::InvalidRequest(Box::new(e)))
+}
+
+/// Receive a GraphQL request from a body as MessagePack.
+#[cfg(feature = "msgpack")]
+pub async fn receive_msgpack(body: impl AsyncRead) -> Result<Request, ParseRequestError> {
+    receive_batch_msgpack(body).await?.into_single()
+}
+
+/// Receive a GraphQL batch request from a body as MessagePack.
+#[cfg(feature = "msgpack")]
+pub async fn receive_batch_msgpack(
+    body: impl AsyncRead,
+) -> Result<BatchRequest, ParseRequestError> {
+    let mut data = Vec::new();
+    futures_util::pin_mut!(body);
+    body.read_to_end(&mut data)
+        .await
+        .map_err(ParseRequestError::Io)?;
+    rmp_serde::from_slice::<BatchRequest>(&data)
+        .map_err(|e| ParseRequestError::InvalidRequest(Box::new(e)))
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -151,4 +198,71 @@ mod tests {
             Variables::from_value(value!({ "a" : 10 }))
         );
     }
+
+    #[cfg(feature = "cbor")]
+    #[tokio::test]
+    async fn test_receive_cbor() {
+        use bytes::Bytes;
+
+        use crate::{EmptyMutation, EmptySubscription, Object, Schema, Value};
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn echo(&self, data: Bytes) -> Bytes {
+                data
+            }
+        }
+
+        let body = serde_cbor::to_vec(&value!({
+            "query": "query($data: Bytes!) { echo(data: $data) }",
+            "variables": { "data": Value::Binary(Bytes::from_static(&[0, 1, 255])) },
+        }))
+        .unwrap();
+        let request = receive_body(
+            Some("application/cbor"),
+            body.as_slice(),
+            MultipartOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            request.variables,
+            Variables::from_value(value!({
+                "data": Value::Binary(Bytes::from_static(&[0, 1, 255])),
+            }))
+        );
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let response = schema.execute(request).await;
+        let encoding = ResponseEncoding::from_accept(Some("application/cbor"));
+        let data: Value = serde_cbor::from_slice(&encoding.encode(&response).unwrap()).unwrap();
+        assert_eq!(
+            data,
+            value!({ "data": { "echo": Value::Binary(Bytes::from_static(&[0, 1, 255])) } })
+        );
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[tokio::test]
+    async fn test_receive_msgpack() {
+        let body = rmp_serde::to_vec_named(&value!({
+            "query": "{ a }",
+            "variables": { "a": 10 },
+        }))
+        .unwrap();
+        let request = receive_body(
+            Some("application/msgpack"),
+            body.as_slice(),
+            MultipartOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(request.query.as_str(), "{ a }");
+        assert_eq!(
+            request.variables,
+            Variables::from_value(value!({ "a": 10 }))
+        );
+    }
 }
@@ -58,7 +58,7 @@ where
     .boxed()
 }
 
-fn parse_accept(accept: &str) -> Vec<Mime> {
+pub(super) fn parse_accept(accept: &str) -> Vec<Mime> {
     let mut items = accept
         .split(',')
         .map(str::trim)
@@ -63,7 +63,9 @@
 //! |:-------------------------------|:----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
 //! | **`apollo_tracing`**           | Enable the [Apollo tracing extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/struct.ApolloTracing.html).                                                               |
 //! | **`apollo_persisted_queries`** | Enable the [Apollo persisted queries extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/apollo_persisted_queries/struct.ApolloPersistedQueries.html).                   |
+//! | **`bigdecimal`**               | Integrate with the [`bigdecimal` crate](https://crates.io/crates/bigdecimal).                                                                                                                 |
 //! | **`boxed-trait`**              | Enables [`async-trait`](https://crates.io/crates/async-trait) for all traits.                                                                                                                 |
+//! | **`cbor`**                     | Support CBOR request and response bodies (`application/cbor`).                                                                                                                                |
 //! | **`chrono`**                   | Integrate with the [`chrono` crate](https://crates.io/crates/chrono).                                                                                                                         |
 //! | **`chrono-tz`**                | Integrate with the [`chrono-tz` crate](https://crates.io/crates/chrono-tz).                                                                                                                   |
 //! | **`dataloader`**               | Support [DataLoader](dataloader/struct.DataLoader.html).                                                                                                                                      |
@@ -71,16 +73,25 @@
 //! | **`dynamic-schema`**           | Support dynamic schema                                                                                                                                                                        |
 //! | **`fast_chemail`**             | Integrate with the [`fast_chemail` crate](https://crates.io/crates/fast_chemail).                                                                                                             |
 //! | **`graphiql`**                 | Enables the [GraphiQL IDE](https://github.com/graphql/graphiql) integration                                                                                                                   |
+//! | **`int128`**                   | Support `i128` and `u128` as the `Int128` and `UInt128` scalars.                                                                                                                              |
 //! | **`jiff`**                     | Integrate with the [`jiff` crate](https://crates.io/crates/jiff).                                                                                                                             |
+//! | **`msgpack`**                  | Support MessagePack request and response bodies (`application/msgpack`).                                                                                                                      |
 //! | **`log`**                      | Enable the [Logger extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/struct.Logger.html).                                                                              |
+//! | **`operation_signature`**      | Enable [OperationSignature](struct.OperationSignature.html), the Apollo-style signature and hash of an operation.                                                                             |
 //! | **`opentelemetry`**            | Enable the [OpenTelemetry extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/struct.OpenTelemetry.html).                                                                |
+//! | **`password-strength-validator`** | Enable the `min_password_strength` input value validator, backed by the [`zxcvbn` crate](https://crates.io/crates/zxcvbn).                                                                     |
 //! | **`rawvalue`**                 | Support raw values from [`serde_json`](https://crates.io/crates/serde_json)                                                                                                                   |
+//! | **`response_cache`**           | Enable the [response cache extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/response_cache/struct.ResponseCache.html).                                                |
 //! | **`secrecy`**                  | Integrate with the [`secrecy` crate](https://crates.io/crates/secrecy).                                                                                                                       |
+//! | **`std-duration`**             | Support `std::time::Duration` as the `Duration` scalar (ISO8601).                                                                                                                             |
+//! | **`std-net`**                  | Support `std::net::IpAddr`, `Ipv4Addr` and `Ipv6Addr` as scalars.                                                                                                                             |
+//! | **`std-system-time`**          | Support `std::time::SystemTime` as the `DateTime` scalar (RFC3339), backed by the [`time` crate](https://github.com/time-rs/time).                                                            |
 //! | **`string_number`**            | Enable the [StringNumber](types/struct.StringNumber.html).                                                                                                                                    |
 //! | **`time`**                     | Integrate with the [`time` crate](https://github.com/time-rs/time).                                                                                                                           |
 //! | **`tracing`**                  | Enable the [Tracing extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/struct.Tracing.html).                                                                            |
 //! | **`tempfile`**                 | Save the uploaded content in the temporary file.                                                                                                                                              |
 //! | **`unblock`**                  | Support [Asynchronous reader for Upload](types/struct.Upload.html)                                                                                                                            |
+//! | **`validation_cache`**         | Enable the validation cache with [LruValidationCache](struct.LruValidationCache.html), an LRU storage for it.                                                                                 |
 //! | **`uuid`**                     | Integrate with the [`uuid` crate](https://crates.io/crates/uuid).                                                                                                                             |
 //! | **`url`**                      | Integrate with the [`url` crate](https://crates.io/crates/url).                                                                                                                               |
 //!
@@ -174,10 +185,14 @@
 mod base;
 mod custom_directive;
 mod error;
+// the plans are only compiled for the documents in the validation cache
+#[cfg_attr(not(feature = "validation_cache"), allow(dead_code))]
+mod execution_plan;
 mod executor;
 mod guard;
 mod look_ahead;
 mod model;
+mod projection;
 mod request;
 mod response;
 mod schema;
@@ -216,11 +231,14 @@ pub use base::{
 #[doc(hidden)]
 pub use context::ContextSelectionSet;
 pub use context::*;
-pub use custom_directive::{CustomDirective, CustomDirectiveFactory, TypeDirective};
+pub use custom_directive::{
+    CustomDirective, CustomDirectiveFactory, ExecuteOperationFut, TypeDirective,
+};
 pub use error::{
     Error, ErrorExtensionValues, ErrorExtensions, InputValueError, InputValueResult,
     ParseRequestError, PathSegment, Result, ResultExt, ServerError, ServerResult,
 };
+pub use execution_plan::ExecutionPlan;
 pub use executor::Executor;
 pub use extensions::ResolveFut;
 #[doc(hidden)]
@@ -230,8 +248,12 @@ pub use guard::{Guard, GuardExt};
 pub use indexmap;
 pub use look_ahead::Lookahead;
 #[doc(no_inline)]
-pub use parser::{Pos, Positioned};
-pub use registry::{CacheControl, SDLExportOptions};
+pub use parser::{ParseOptions, Pos, Positioned};
+pub use projection::{Projectable, Projection};
+#[cfg(feature = "operation_signature")]
+#[cfg_attr(docsrs, doc(cfg(feature = "operation_signature")))]
+pub use registry::OperationSignature;
+pub use registry::{CacheControl, NormalizeOptions, SDLExportOptions};
 pub use request::{BatchRequest, Request};
 #[doc(no_inline)]
 pub use resolver_utils::{ContainerType, EnumType, ScalarType};
@@ -241,8 +263,16 @@ pub use schema::{IntrospectionMode, Schema, SchemaBuilder, SchemaEnv};
 pub use static_assertions_next;
 pub use subscription::SubscriptionType;
 pub use types::*;
-pub use validation::{ValidationMode, ValidationResult, VisitorContext};
-pub use validators::CustomValidator;
+#[cfg(feature = "validation_cache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "validation_cache")))]
+pub use validation::{
+    LruValidationCache, ValidatedDocument, ValidationCacheKey, ValidationCacheStorage,
+};
+pub use validation::{
+    ValidationMode, ValidationResult, ValidationRuleFactory, VisitMode, Visitor, VisitorContext,
+    fold, visit,
+};
+pub use validators::{AsyncCustomValidator, CustomValidator};
 
 /// An alias of [async_graphql::Error](struct.Error.html). Present for backward
 /// compatibility reasons.
@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 
+use indexmap::IndexSet;
+
 use crate::{
     Context, Name, Positioned, SelectionField,
     parser::types::{Field, FragmentDefinition, Selection, SelectionSet},
+    registry::Registry,
 };
 
 /// A selection performed by a query.
@@ -10,6 +13,7 @@ pub struct Lookahead<'a> {
     fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
     fields: Vec<&'a Field>,
     context: &'a Context<'a>,
+    type_name: Option<String>,
 }
 
 impl<'a> Lookahead<'a> {
@@ -22,6 +26,7 @@ impl<'a> Lookahead<'a> {
             fragments,
             fields: vec![field],
             context,
+            type_name: None,
         }
     }
 
@@ -32,15 +37,36 @@ impl<'a> Lookahead<'a> {
     /// lookahead that represents `{ b }`.
     #[must_use]
     pub fn field(&self, name: &str) -> Self {
-        let mut fields = Vec::new();
-        for field in &self.fields {
-            filter(&mut fields, self.fragments, &field.selection_set.node, name)
+        Self {
+            fragments: self.fragments,
+            fields: self
+                .child_fields()
+                .into_iter()
+                .filter(|field| field.name.node == name)
+                .collect(),
+            context: self.context,
+            type_name: None,
         }
+    }
 
+    /// Only consider the fields that are requested for the object type
+    /// `type_name`.
+    ///
+    /// Inline fragments and fragment spreads are skipped unless their type
+    /// condition is `type_name`, or an interface or union that `type_name`
+    /// belongs to. This is useful for fields that return an interface or a
+    /// union.
+    ///
+    /// For example, calling `.for_type("Admin").field("a")` on
+    /// `{ ... on Admin { a } ... on Customer { b } }` returns a lookahead that
+    /// exists, while `.for_type("Customer").field("a")` does not.
+    #[must_use]
+    pub fn for_type(&self, type_name: impl Into<String>) -> Self {
         Self {
             fragments: self.fragments,
-            fields,
+            fields: self.fields.clone(),
             context: self.context,
+            type_name: Some(type_name.into()),
         }
     }
 
@@ -65,6 +91,57 @@ impl<'a> Lookahead<'a> {
             })
             .collect()
     }
+
+    /// Get the fields selected below the fields covered by this `Lookahead`,
+    /// in the order of the query and with fragments expanded.
+    ///
+    /// Unlike [`Lookahead::field`], this keeps every aliased field, so use
+    /// [`SelectionField::alias`] or [`SelectionField::response_key`] to tell
+    /// them apart.
+    pub fn selection_set(&self) -> Vec<SelectionField<'a>> {
+        self.child_fields()
+            .into_iter()
+            .map(|field| SelectionField {
+                fragments: self.fragments,
+                field,
+                context: self.context,
+            })
+            .collect()
+    }
+
+    /// Get the paths of all the leaf fields requested below the fields covered
+    /// by this `Lookahead`.
+    ///
+    /// Paths are made of field names, aliases are ignored, and every path is
+    /// returned once. For example, on `{ a x: b { c d } b { c } }` this
+    /// returns `[["a"], ["b", "c"], ["b", "d"]]`, which can be used to build
+    /// the projection of a database query.
+    pub fn leaf_paths(&self) -> Vec<Vec<&'a str>> {
+        let mut paths = IndexSet::new();
+        collect_leaf_paths(
+            &mut paths,
+            &mut Vec::new(),
+            self.fragments,
+            &self.context.schema_env.registry,
+            &self.fields,
+            self.type_name.as_deref(),
+        );
+        paths.into_iter().collect()
+    }
+
+    fn child_fields(&self) -> Vec<&'a Field> {
+        let mut fields = Vec::new();
+        for field in &self.fields {
+            collect_fields(
+                &mut fields,
+                self.fragments,
+                &self.context.schema_env.registry,
+                &field.selection_set.node,
+                self.type_name.as_deref(),
+            );
+        }
+        fields
+    }
 }
 
 impl<'a> From<SelectionField<'a>> for Lookahead<'a> {
@@ -73,6 +150,7 @@ impl<'a> From<SelectionField<'a>> for Lookahead<'a> {
             fragments: selection_field.fragments,
             fields: vec![selection_field.field],
             context: selection_field.context,
+            type_name: None,
         }
     }
 }
@@ -96,36 +174,96 @@ impl<'a> TryFrom<&[SelectionField<'a>]> for Lookahead<'a> {
                     .map(|selection_field| selection_field.field)
                     .collect(),
                 context: selection_fields[0].context,
+                type_name: None,
             })
         }
     }
 }
 
-fn filter<'a>(
+fn collect_fields<'a>(
     fields: &mut Vec<&'a Field>,
     fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
+    registry: &Registry,
     selection_set: &'a SelectionSet,
-    name: &str,
+    type_name: Option<&str>,
 ) {
+    let applies = |type_condition: &str| match type_name {
+        Some(type_name) => {
+            type_condition == type_name
+                || registry
+                    .types
+                    .get(type_condition)
+                    .is_some_and(|ty| ty.is_possible_type(type_name))
+        }
+        None => true,
+    };
+
     for item in &selection_set.items {
         match &item.node {
-            Selection::Field(field) => {
-                if field.node.name.node == name {
-                    fields.push(&field.node)
-                }
-            }
+            Selection::Field(field) => fields.push(&field.node),
             Selection::InlineFragment(fragment) => {
-                filter(fields, fragments, &fragment.node.selection_set.node, name)
+                let fragment = &fragment.node;
+                if fragment
+                    .type_condition
+                    .as_ref()
+                    .is_none_or(|type_condition| applies(&type_condition.node.on.node))
+                {
+                    collect_fields(
+                        fields,
+                        fragments,
+                        registry,
+                        &fragment.selection_set.node,
+                        type_name,
+                    )
+                }
             }
             Selection::FragmentSpread(spread) => {
-                if let Some(fragment) = fragments.get(&spread.node.fragment_name.node) {
-                    filter(fields, fragments, &fragment.node.selection_set.node, name)
+                if let Some(fragment) = fragments.get(&spread.node.fragment_name.node)
+                    && applies(&fragment.node.type_condition.node.on.node)
+                {
+                    collect_fields(
+                        fields,
+                        fragments,
+                        registry,
+                        &fragment.node.selection_set.node,
+                        type_name,
+                    )
                 }
             }
         }
     }
 }
 
+fn collect_leaf_paths<'a>(
+    paths: &mut IndexSet<Vec<&'a str>>,
+    path: &mut Vec<&'a str>,
+    fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
+    registry: &Registry,
+    fields: &[&'a Field],
+    type_name: Option<&str>,
+) {
+    let mut children = Vec::new();
+    for field in fields {
+        collect_fields(
+            &mut children,
+            fragments,
+            registry,
+            &field.selection_set.node,
+            type_name,
+        );
+    }
+
+    for child in children {
+        path.push(child.name.node.as_str());
+        if child.selection_set.node.items.is_empty() {
+            paths.insert(path.clone());
+        } else {
+            collect_leaf_paths(paths, path, fragments, registry, &[child], None);
+        }
+        path.pop();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -365,4 +503,99 @@ mod tests {
                 .is_ok()
         );
     }
+
+    #[tokio::test]
+    async fn test_look_ahead_for_type() {
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Order {
+            id: i32,
+            total: i32,
+        }
+
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Admin {
+            id: i32,
+            level: i32,
+        }
+
+        struct Customer;
+
+        #[Object(internal)]
+        impl Customer {
+            async fn id(&self) -> i32 {
+                0
+            }
+
+            async fn orders(&self, _limit: Option<i32>) -> Vec<Order> {
+                Vec::new()
+            }
+        }
+
+        #[derive(Union)]
+        #[graphql(internal)]
+        enum User {
+            Admin(Admin),
+            Customer(Customer),
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn users(&self, ctx: &Context<'_>) -> Vec<User> {
+                let look_ahead = ctx.look_ahead();
+                assert!(look_ahead.field("level").exists());
+                assert!(look_ahead.for_type("Admin").field("level").exists());
+                assert!(!look_ahead.for_type("Customer").field("level").exists());
+
+                assert_eq!(
+                    look_ahead.for_type("Admin").leaf_paths(),
+                    vec![vec!["id"], vec!["level"]]
+                );
+                assert_eq!(
+                    look_ahead.for_type("Customer").leaf_paths(),
+                    vec![vec!["id"], vec!["orders", "total"], vec!["orders", "id"]]
+                );
+                assert_eq!(
+                    look_ahead
+                        .for_type("Customer")
+                        .selection_set()
+                        .iter()
+                        .map(SelectionField::response_key)
+                        .collect::<Vec<_>>(),
+                    vec!["id", "o", "orders", "id"]
+                );
+
+                let orders = look_ahead.field("orders").selection_fields();
+                assert_eq!(orders[0].argument::<Option<i32>>("limit").unwrap(), Some(2));
+                assert_eq!(orders[1].argument::<Option<i32>>("limit").unwrap(), None);
+                assert!(orders[0].argument::<String>("limit").is_err());
+
+                Vec::new()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let query = r#"
+            query($limit: Int) {
+                users {
+                    ... on Admin { id level }
+                    ... on Customer { id o: orders(limit: $limit) { total } orders { id } }
+                    ...C
+                }
+            }
+
+            fragment C on Customer { id }
+        "#;
+        assert!(
+            schema
+                .execute(
+                    Request::new(query).variables(Variables::from_value(value!({ "limit": 2 })))
+                )
+                .await
+                .is_ok()
+        );
+    }
 }
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::SelectionField;
+
+/// A type whose fields can be mapped to the data that is needed to resolve
+/// them.
+///
+/// Derive it for a `SimpleObject` with `#[graphql(projection)]`. See
+/// [`Projection`] for details.
+pub trait Projectable {
+    /// Add the columns and joins needed to resolve the selected `fields` of
+    /// this type to `projection`.
+    fn project(projection: &mut Projection, fields: &[SelectionField<'_>]);
+}
+
+impl<T: Projectable + ?Sized> Projectable for &T {
+    fn project(projection: &mut Projection, fields: &[SelectionField<'_>]) {
+        T::project(projection, fields)
+    }
+}
+
+impl<T: Projectable> Projectable for Option<T> {
+    fn project(projection: &mut Projection, fields: &[SelectionField<'_>]) {
+        T::project(projection, fields)
+    }
+}
+
+impl<T: Projectable> Projectable for Vec<T> {
+    fn project(projection: &mut Projection, fields: &[SelectionField<'_>]) {
+        T::project(projection, fields)
+    }
+}
+
+impl<T: Projectable> Projectable for [T] {
+    fn project(projection: &mut Projection, fields: &[SelectionField<'_>]) {
+        T::project(projection, fields)
+    }
+}
+
+impl<T: Projectable + ?Sized> Projectable for Box<T> {
+    fn project(projection: &mut Projection, fields: &[SelectionField<'_>]) {
+        T::project(projection, fields)
+    }
+}
+
+impl<T: Projectable + ?Sized> Projectable for Arc<T> {
+    fn project(projection: &mut Projection, fields: &[SelectionField<'_>]) {
+        T::project(projection, fields)
+    }
+}
+
+/// The columns and nested joins needed to resolve a selection
+///
+/// A projection is computed from the selection of a field whose type derives
+/// [`Projectable`], and can be used to load only the data that the query
+/// needs. The derive macro maps every selected field of the object to:
+///
+/// - the column named after the Rust field, or `#[graphql(projection(column =
+///   "name"))]`,
+/// - a nested projection with `#[graphql(projection(join))]` or
+///   `#[graphql(projection(join = "name"))]`, for fields whose type is also
+///   `Projectable`,
+/// - nothing with `#[graphql(projection(skip))]`.
+///
+/// Fields that are computed from other columns, for example in a
+/// `ComplexObject`, declare the columns they require with
+/// `#[graphql(projection(computed(field = "fullName", requires = "first_name,
+/// last_name")))]` on the object.
+///
+/// # Examples
+///
+/// ```
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// #[graphql(projection)]
+/// struct Order {
+///     id: i32,
+///     total: i32,
+/// }
+///
+/// #[derive(SimpleObject)]
+/// #[graphql(
+///     complex,
+///     projection(computed(field = "fullName", requires = "first_name, last_name"))
+/// )]
+/// struct User {
+///     id: i32,
+///     #[graphql(projection(column = "email_address"))]
+///     email: String,
+///     #[graphql(skip)]
+///     first_name: String,
+///     #[graphql(skip)]
+///     last_name: String,
+///     #[graphql(projection(join))]
+///     orders: Vec<Order>,
+/// }
+///
+/// #[ComplexObject]
+/// impl User {
+///     async fn full_name(&self) -> String {
+///         format!("{} {}", self.first_name, self.last_name)
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn user(&self, ctx: &Context<'_>) -> User {
+///         let projection = Projection::from_field::<User>(ctx.field());
+///         assert_eq!(
+///             projection.columns().collect::<Vec<_>>(),
+///             vec!["email_address", "first_name", "last_name"]
+///         );
+///         let orders = projection.join("orders").unwrap();
+///         assert_eq!(orders.columns().collect::<Vec<_>>(), vec!["total"]);
+///         # User {
+///         #     id: 1,
+///         #     email: String::new(),
+///         #     first_name: String::new(),
+///         #     last_name: String::new(),
+///         #     orders: Vec::new(),
+///         # }
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let res = schema.execute("{ user { email fullName orders { total } } }").await;
+/// assert!(res.is_ok());
+/// # });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Projection {
+    columns: IndexSet<String>,
+    joins: IndexMap<String, Projection>,
+}
+
+impl Projection {
+    /// Create an empty projection.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create the projection of the fields selected below `field`, which is
+    /// a field of type `T`.
+    ///
+    /// Use `ctx.field()` to get the field that is currently being resolved.
+    pub fn from_field<T: Projectable + ?Sized>(field: SelectionField<'_>) -> Self {
+        let mut projection = Self::new();
+        projection.add_fields::<T>(field);
+        projection
+    }
+
+    /// Add the fields selected below `field`, which is a field of type `T`.
+    pub fn add_fields<T: Projectable + ?Sized>(&mut self, field: SelectionField<'_>) {
+        T::project(self, &field.selection_set().collect::<Vec<_>>());
+    }
+
+    /// Add a column.
+    pub fn add_column(&mut self, column: impl Into<String>) {
+        self.columns.insert(column.into());
+    }
+
+    /// Returns the nested projection of a join, creating it if it does not
+    /// exist.
+    pub fn join_mut(&mut self, name: impl Into<String>) -> &mut Projection {
+        self.joins.entry(name.into()).or_default()
+    }
+
+    /// Returns the columns, in the order they were first selected.
+    pub fn columns(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().map(String::as_str)
+    }
+
+    /// Returns `true` if the projection contains the column.
+    pub fn contains_column(&self, column: &str) -> bool {
+        self.columns.contains(column)
+    }
+
+    /// Returns the joins and their nested projections, in the order they were
+    /// first selected.
+    pub fn joins(&self) -> impl Iterator<Item = (&str, &Projection)> {
+        self.joins
+            .iter()
+            .map(|(name, projection)| (name.as_str(), projection))
+    }
+
+    /// Returns the nested projection of a join.
+    pub fn join(&self, name: &str) -> Option<&Projection> {
+        self.joins.get(name)
+    }
+
+    /// Returns `true` if the projection has no columns and no joins.
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty() && self.joins.is_empty()
+    }
+}
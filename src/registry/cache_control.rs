@@ -36,7 +36,8 @@
 ///         .cache_control,
 ///     CacheControl {
 ///         public: true,
-///         max_age: 30
+///         max_age: 30,
+///         ..Default::default()
 ///     }
 /// );
 ///
@@ -49,7 +50,8 @@
 ///         .cache_control,
 ///     CacheControl {
 ///         public: false,
-///         max_age: 60
+///         max_age: 60,
+///         ..Default::default()
 ///     }
 /// );
 ///
@@ -62,7 +64,8 @@
 ///         .cache_control,
 ///     CacheControl {
 ///         public: false,
-///         max_age: 30
+///         max_age: 30,
+///         ..Default::default()
 ///     }
 /// );
 ///
@@ -75,7 +78,8 @@
 ///         .cache_control,
 ///     CacheControl {
 ///         public: false,
-///         max_age: -1
+///         max_age: -1,
+///         ..Default::default()
 ///     }
 /// );
 /// # });
@@ -87,6 +91,30 @@ pub struct CacheControl {
 
     /// Cache max age, `-1` represent `no-cache`, default is 0.
     pub max_age: i32,
+
+    /// Cache max age for shared caches, default is 0.
+    pub s_max_age: i32,
+
+    /// The number of seconds a stale response can be served while it is
+    /// revalidated in the background, default is 0.
+    pub stale_while_revalidate: i32,
+
+    /// The number of seconds a stale response can be served if revalidating
+    /// it fails, default is 0.
+    pub stale_if_error: i32,
+
+    /// The response will not change while it is fresh, default is false.
+    pub immutable: bool,
+
+    /// The response must not be stored by any cache, default is false.
+    pub no_store: bool,
+
+    /// Inherit the max age of the parent field instead of using the default
+    /// max age, default is false.
+    ///
+    /// Only used when the Apollo cache control rules are enabled with
+    /// [`SchemaBuilder::enable_apollo_cache_control`](crate::SchemaBuilder::enable_apollo_cache_control).
+    pub inherit_max_age: bool,
 }
 
 impl Default for CacheControl {
@@ -94,6 +122,12 @@ impl Default for CacheControl {
         Self {
             public: true,
             max_age: 0,
+            s_max_age: 0,
+            stale_while_revalidate: 0,
+            stale_if_error: 0,
+            immutable: false,
+            no_store: false,
+            inherit_max_age: false,
         }
     }
 }
@@ -102,37 +136,79 @@ impl CacheControl {
     /// Get 'Cache-Control' header value.
     #[must_use]
     pub fn value(&self) -> Option<String> {
-        let mut value = if self.max_age > 0 {
-            format!("max-age={}", self.max_age)
+        if self.no_store {
+            return Some("no-store".to_string());
+        }
+
+        let mut directives = Vec::new();
+
+        if self.max_age > 0 {
+            directives.push(format!("max-age={}", self.max_age));
         } else if self.max_age == -1 {
-            "no-cache".to_string()
-        } else {
-            String::new()
-        };
+            directives.push("no-cache".to_string());
+        }
 
-        if !self.public {
-            if !value.is_empty() {
-                value += ", ";
+        if self.max_age != -1 {
+            if self.public && self.s_max_age > 0 {
+                directives.push(format!("s-maxage={}", self.s_max_age));
+            }
+            if self.stale_while_revalidate > 0 {
+                directives.push(format!(
+                    "stale-while-revalidate={}",
+                    self.stale_while_revalidate
+                ));
+            }
+            if self.stale_if_error > 0 {
+                directives.push(format!("stale-if-error={}", self.stale_if_error));
             }
-            value += "private";
+            if self.immutable && self.max_age > 0 {
+                directives.push("immutable".to_string());
+            }
+        }
+
+        if !self.public {
+            directives.push("private".to_string());
         }
 
-        if !value.is_empty() { Some(value) } else { None }
+        if !directives.is_empty() {
+            Some(directives.join(", "))
+        } else {
+            None
+        }
     }
 }
 
 impl CacheControl {
     #[must_use]
     pub(crate) fn merge(self, other: &CacheControl) -> CacheControl {
+        fn merge_age(a: i32, b: i32) -> i32 {
+            match (a, b) {
+                (a, 0) => a,
+                (0, b) => b,
+                (a, b) => a.min(b),
+            }
+        }
+
         CacheControl {
             public: self.public && other.public,
             max_age: match (self.max_age, other.max_age) {
                 (-1, _) => -1,
                 (_, -1) => -1,
-                (a, 0) => a,
-                (0, b) => b,
-                (a, b) => a.min(b),
+                (a, b) => merge_age(a, b),
             },
+            s_max_age: merge_age(self.s_max_age, other.s_max_age),
+            stale_while_revalidate: merge_age(
+                self.stale_while_revalidate,
+                other.stale_while_revalidate,
+            ),
+            stale_if_error: merge_age(self.stale_if_error, other.stale_if_error),
+            immutable: match (self.max_age, other.max_age) {
+                (_, 0) => self.immutable,
+                (0, _) => other.immutable,
+                _ => self.immutable && other.immutable,
+            },
+            no_store: self.no_store || other.no_store,
+            inherit_max_age: self.inherit_max_age && other.inherit_max_age,
         }
     }
 }
@@ -147,6 +223,7 @@ mod tests {
             CacheControl {
                 public: true,
                 max_age: 0,
+                ..Default::default()
             }
             .value(),
             None
@@ -156,6 +233,7 @@ mod tests {
             CacheControl {
                 public: false,
                 max_age: 0,
+                ..Default::default()
             }
             .value(),
             Some("private".to_string())
@@ -165,6 +243,7 @@ mod tests {
             CacheControl {
                 public: false,
                 max_age: 10,
+                ..Default::default()
             }
             .value(),
             Some("max-age=10, private".to_string())
@@ -174,6 +253,7 @@ mod tests {
             CacheControl {
                 public: true,
                 max_age: 10,
+                ..Default::default()
             }
             .value(),
             Some("max-age=10".to_string())
@@ -183,6 +263,7 @@ mod tests {
             CacheControl {
                 public: true,
                 max_age: -1,
+                ..Default::default()
             }
             .value(),
             Some("no-cache".to_string())
@@ -192,9 +273,107 @@ mod tests {
             CacheControl {
                 public: false,
                 max_age: -1,
+                ..Default::default()
             }
             .value(),
             Some("no-cache, private".to_string())
         );
     }
+
+    #[test]
+    fn to_value_with_directives() {
+        assert_eq!(
+            CacheControl {
+                max_age: 60,
+                s_max_age: 300,
+                stale_while_revalidate: 30,
+                stale_if_error: 600,
+                immutable: true,
+                ..Default::default()
+            }
+            .value(),
+            Some(
+                "max-age=60, s-maxage=300, stale-while-revalidate=30, stale-if-error=600, \
+                 immutable"
+                    .to_string()
+            )
+        );
+
+        assert_eq!(
+            CacheControl {
+                public: false,
+                max_age: 60,
+                s_max_age: 300,
+                ..Default::default()
+            }
+            .value(),
+            Some("max-age=60, private".to_string())
+        );
+
+        assert_eq!(
+            CacheControl {
+                max_age: -1,
+                stale_while_revalidate: 30,
+                immutable: true,
+                ..Default::default()
+            }
+            .value(),
+            Some("no-cache".to_string())
+        );
+
+        assert_eq!(
+            CacheControl {
+                max_age: 60,
+                no_store: true,
+                ..Default::default()
+            }
+            .value(),
+            Some("no-store".to_string())
+        );
+    }
+
+    #[test]
+    fn merge() {
+        let a = CacheControl {
+            max_age: 60,
+            s_max_age: 300,
+            stale_while_revalidate: 30,
+            immutable: true,
+            ..Default::default()
+        };
+        let b = CacheControl {
+            public: false,
+            max_age: 30,
+            stale_while_revalidate: 60,
+            stale_if_error: 600,
+            immutable: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            a.merge(&b),
+            CacheControl {
+                public: false,
+                max_age: 30,
+                s_max_age: 300,
+                stale_while_revalidate: 30,
+                stale_if_error: 600,
+                immutable: true,
+                no_store: false,
+                inherit_max_age: false,
+            }
+        );
+
+        let c = a.merge(&CacheControl {
+            no_store: true,
+            ..Default::default()
+        });
+        assert!(c.no_store);
+        assert!(c.immutable);
+
+        let d = a.merge(&CacheControl {
+            max_age: 120,
+            ..Default::default()
+        });
+        assert!(!d.immutable);
+    }
 }
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, fmt::Write};
 
 use super::SemanticNullability;
-use crate::registry::{Deprecation, MetaField, MetaInputValue, MetaType, Registry};
+use crate::registry::{CacheControl, Deprecation, MetaField, MetaInputValue, MetaType, Registry};
 
 const SYSTEM_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
 const FEDERATION_SCALARS: &[&str] = &["Any"];
@@ -16,6 +16,7 @@ pub struct SDLExportOptions {
     prefer_single_line_descriptions: bool,
     include_specified_by: bool,
     compose_directive: bool,
+    include_cache_control: bool,
     use_space_ident: bool,
     indent_width: u8,
 }
@@ -30,6 +31,7 @@ impl Default for SDLExportOptions {
             prefer_single_line_descriptions: false,
             include_specified_by: false,
             compose_directive: false,
+            include_cache_control: false,
             use_space_ident: false,
             indent_width: 2,
         }
@@ -109,6 +111,14 @@ impl SDLExportOptions {
         }
     }
 
+    /// Includes the Apollo `cacheControl` directive in SDL
+    pub fn include_cache_control(self) -> Self {
+        Self {
+            include_cache_control: true,
+            ..self
+        }
+    }
+
     /// Use spaces for indentation instead of tabs
     pub fn use_space_ident(self) -> Self {
         Self {
@@ -202,6 +212,14 @@ impl Registry {
             writeln!(sdl, "{}", directive.sdl(&options)).ok();
         });
 
+        if options.include_cache_control {
+            writeln!(sdl, "enum CacheControlScope {{").ok();
+            writeln!(sdl, "{}PUBLIC", tab(&options)).ok();
+            writeln!(sdl, "{}PRIVATE", tab(&options)).ok();
+            writeln!(sdl, "}}\n").ok();
+            writeln!(sdl, "directive @cacheControl(maxAge: Int, scope: CacheControlScope, inheritMaxAge: Boolean) on FIELD_DEFINITION | OBJECT | INTERFACE | UNION").ok();
+        }
+
         if options.federation {
             writeln!(sdl, "extend schema @link(").ok();
             writeln!(
@@ -344,6 +362,10 @@ impl Registry {
                 write!(sdl, " {}", directive.sdl()).ok();
             }
 
+            if options.include_cache_control {
+                write_cache_control(sdl, &field.cache_control);
+            }
+
             if options.federation {
                 if field.external {
                     write!(sdl, " @external").ok();
@@ -441,6 +463,7 @@ impl Registry {
                 tags,
                 directive_invocations: raw_directives,
                 requires_scopes,
+                cache_control,
                 ..
             } => {
                 if Some(name.as_str()) == self.subscription_type.as_deref()
@@ -482,6 +505,10 @@ impl Registry {
                     write!(sdl, " {}", directive_invocation.sdl()).ok();
                 }
 
+                if options.include_cache_control {
+                    write_cache_control(sdl, cache_control);
+                }
+
                 if options.federation {
                     if let Some(keys) = keys {
                         for key in keys {
@@ -838,6 +865,24 @@ fn escape_string(s: &str) -> String {
     res
 }
 
+fn write_cache_control(sdl: &mut String, cache_control: &CacheControl) {
+    let mut args = Vec::new();
+    if cache_control.max_age > 0 {
+        args.push(format!("maxAge: {}", cache_control.max_age));
+    } else if cache_control.max_age == -1 {
+        args.push("maxAge: 0".to_string());
+    }
+    if !cache_control.public {
+        args.push("scope: PRIVATE".to_string());
+    }
+    if cache_control.inherit_max_age {
+        args.push("inheritMaxAge: true".to_string());
+    }
+    if !args.is_empty() {
+        write!(sdl, " @cacheControl({})", args.join(", ")).ok();
+    }
+}
+
 fn tab(options: &SDLExportOptions) -> String {
     if options.use_space_ident {
         " ".repeat(options.indent_width.into())
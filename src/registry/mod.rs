@@ -11,6 +11,9 @@ use std::{
 pub use cache_control::CacheControl;
 pub use export_sdl::SDLExportOptions;
 use indexmap::{map::IndexMap, set::IndexSet};
+pub use stringify_exec_doc::NormalizeOptions;
+#[cfg(feature = "operation_signature")]
+pub use stringify_exec_doc::OperationSignature;
 
 pub use crate::model::{__DirectiveLocation, location_traits};
 use crate::{
@@ -161,12 +164,35 @@ pub struct MetaInputValue {
     pub directive_invocations: Vec<MetaDirectiveInvocation>,
 }
 
-type ComputeComplexityFn = fn(
-    &VisitorContext<'_>,
-    &[Positioned<VariableDefinition>],
-    &Field,
-    usize,
-) -> ServerResult<usize>;
+/// A function that computes the complexity of a field from its arguments and
+/// the complexity of its children.
+pub type ComputeComplexityFn = Arc<
+    dyn Fn(
+            &VisitorContext<'_>,
+            &[Positioned<VariableDefinition>],
+            &Field,
+            usize,
+        ) -> ServerResult<usize>
+        + Send
+        + Sync,
+>;
+
+/// Wraps a complexity function generated by the derive macros.
+#[inline]
+pub fn complexity_fn<F>(f: F) -> ComputeComplexityFn
+where
+    F: Fn(
+            &VisitorContext<'_>,
+            &[Positioned<VariableDefinition>],
+            &Field,
+            usize,
+        ) -> ServerResult<usize>
+        + Send
+        + Sync
+        + 'static,
+{
+    Arc::new(f)
+}
 
 #[derive(Debug, Clone, Default)]
 pub enum Deprecation {
@@ -216,6 +242,9 @@ pub struct MetaField {
     pub deprecation: Deprecation,
     /// Used to create HTTP `Cache-Control` header
     pub cache_control: CacheControl,
+    /// The resolver of the field checks a guard, so its value must not be
+    /// served without running it
+    pub guarded: bool,
     /// Mark a field as owned by another service. This allows service A to use
     /// fields from service B while also knowing at runtime the types of that
     /// field.
@@ -266,7 +295,15 @@ pub struct MetaEnumValue {
     pub directive_invocations: Vec<MetaDirectiveInvocation>,
 }
 
-type MetaVisibleFn = fn(&Context<'_>) -> bool;
+/// A function that checks whether a type, field or value is visible in
+/// introspection.
+pub type MetaVisibleFn = Arc<dyn Fn(&Context<'_>) -> bool + Send + Sync>;
+
+/// Wraps a visibility function generated by the derive macros.
+#[inline]
+pub fn visible_fn(f: fn(&Context<'_>) -> bool) -> MetaVisibleFn {
+    Arc::new(f)
+}
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MetaTypeId {
@@ -819,6 +856,7 @@ pub struct Registry {
     pub federation_subscription: bool,
     pub ignore_name_conflicts: HashSet<String>,
     pub enable_suggestions: bool,
+    pub apollo_cache_control: bool,
 }
 
 impl Registry {
@@ -1199,6 +1237,7 @@ Passing a negative level or a level greater than the list dimension is an error.
                     ty: "_Service!".to_string(),
                     deprecation: Default::default(),
                     cache_control: Default::default(),
+                    guarded: false,
                     external: false,
                     requires: None,
                     provides: None,
@@ -1258,6 +1297,7 @@ Passing a negative level or a level greater than the list dimension is an error.
                         ty: "[_Entity]!".to_string(),
                         deprecation: Default::default(),
                         cache_control: Default::default(),
+                        guarded: false,
                         external: false,
                         requires: None,
                         provides: None,
@@ -1289,6 +1329,7 @@ Passing a negative level or a level greater than the list dimension is an error.
                     ty: "__Schema".to_string(),
                     deprecation: Default::default(),
                     cache_control: Default::default(),
+                    guarded: false,
                     external: false,
                     requires: None,
                     provides: None,
@@ -1331,6 +1372,7 @@ Passing a negative level or a level greater than the list dimension is an error.
                     ty: "__Type".to_string(),
                     deprecation: Default::default(),
                     cache_control: Default::default(),
+                    guarded: false,
                     external: false,
                     requires: None,
                     provides: None,
@@ -1367,6 +1409,7 @@ Passing a negative level or a level greater than the list dimension is an error.
                             ty: "String".to_string(),
                             deprecation: Default::default(),
                             cache_control: Default::default(),
+                            guarded: false,
                             external: false,
                             requires: None,
                             provides: None,
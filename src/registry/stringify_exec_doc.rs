@@ -1,15 +1,94 @@
-use std::fmt::{Error, Result as FmtResult, Write};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{Error, Result as FmtResult, Write},
+};
 
-use async_graphql_value::ConstValue;
+use async_graphql_value::{ConstValue, Name, Value};
+#[cfg(feature = "operation_signature")]
+use sha2::{Digest, Sha256};
 
 use crate::{
-    Variables,
+    Positioned, Variables,
     parser::types::{
-        ExecutableDocument, FragmentDefinition, OperationType, Selection, SelectionSet,
+        Directive, DocumentOperations, ExecutableDocument, FragmentDefinition, OperationDefinition,
+        OperationType, Selection, SelectionSet,
     },
     registry::{MetaInputValue, MetaType, MetaTypeName, Registry},
 };
 
+/// Options for normalizing an operation
+///
+/// By default, an operation is normalized into an Apollo-style signature: the
+/// unused fragments are dropped, the aliases are removed, the literals are
+/// replaced by placeholders (`0`, `""`, `[]` and `{}`), the fields,
+/// arguments, variables, directives and fragments are sorted, and the
+/// whitespace is reduced to the minimum.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct NormalizeOptions {
+    inline_fragments: bool,
+    keep_aliases: bool,
+    keep_literals: bool,
+}
+
+impl NormalizeOptions {
+    /// Create a `NormalizeOptions`
+    #[inline]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Replace the fragment spreads with inline fragments, instead of
+    /// printing the sorted fragment definitions before the operation
+    #[inline]
+    #[must_use]
+    pub fn inline_fragments(self) -> Self {
+        Self {
+            inline_fragments: true,
+            ..self
+        }
+    }
+
+    /// Keep the aliases of the fields
+    #[inline]
+    #[must_use]
+    pub fn keep_aliases(self) -> Self {
+        Self {
+            keep_aliases: true,
+            ..self
+        }
+    }
+
+    /// Keep the literals, except for the secret arguments
+    #[inline]
+    #[must_use]
+    pub fn keep_literals(self) -> Self {
+        Self {
+            keep_literals: true,
+            ..self
+        }
+    }
+}
+
+/// The signature of an operation, which is the same for all the operations
+/// that have the same shape.
+#[cfg(feature = "operation_signature")]
+#[cfg_attr(docsrs, doc(cfg(feature = "operation_signature")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OperationSignature {
+    /// The operation, normalized with the default [`NormalizeOptions`]
+    pub signature: String,
+    /// The SHA-256 hash of the signature, in hexadecimal
+    pub hash: String,
+}
+
+#[cfg(feature = "operation_signature")]
+impl OperationSignature {
+    pub(crate) fn new(signature: String) -> Self {
+        let hash = format!("{:x}", Sha256::digest(signature.as_bytes()));
+        Self { signature, hash }
+    }
+}
+
 impl Registry {
     pub(crate) fn stringify_exec_doc(
         &self,
@@ -218,6 +297,354 @@ impl Registry {
     }
 }
 
+impl Registry {
+    /// Normalizes an operation of the document, or returns `None` if the
+    /// operation does not exist.
+    pub(crate) fn normalize_exec_doc(
+        &self,
+        doc: &ExecutableDocument,
+        operation_name: Option<&str>,
+        options: NormalizeOptions,
+    ) -> Option<String> {
+        let (name, operation) = match (&doc.operations, operation_name) {
+            (DocumentOperations::Single(operation), _) => (None, operation),
+            (DocumentOperations::Multiple(operations), Some(name)) => {
+                let (name, operation) = operations.get_key_value(name)?;
+                (Some(name), operation)
+            }
+            (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => {
+                let (name, operation) = operations.iter().next()?;
+                (Some(name), operation)
+            }
+            (DocumentOperations::Multiple(_), None) => return None,
+        };
+        let normalizer = Normalizer {
+            registry: self,
+            fragments: &doc.fragments,
+            options,
+        };
+
+        let mut output = String::new();
+        if !options.inline_fragments {
+            let mut used_fragments = BTreeSet::new();
+            normalizer.collect_fragments(&operation.node.selection_set.node, &mut used_fragments);
+            for name in used_fragments {
+                let fragment = &doc.fragments[name];
+                push_token(&mut output, "fragment");
+                push_token(&mut output, name);
+                push_token(&mut output, "on");
+                push_token(&mut output, &fragment.node.type_condition.node.on.node);
+                normalizer.write_directives(&mut output, &fragment.node.directives);
+                let parent_type = self
+                    .types
+                    .get(fragment.node.type_condition.node.on.node.as_str());
+                normalizer.write_selection_set(
+                    &mut output,
+                    &fragment.node.selection_set.node,
+                    parent_type,
+                    &mut Vec::new(),
+                );
+            }
+        }
+        normalizer.write_operation(&mut output, name, &operation.node);
+        Some(output)
+    }
+}
+
+struct Normalizer<'a> {
+    registry: &'a Registry,
+    fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
+    options: NormalizeOptions,
+}
+
+impl<'a> Normalizer<'a> {
+    fn collect_fragments(&self, selection_set: &'a SelectionSet, used: &mut BTreeSet<&'a Name>) {
+        for selection in &selection_set.items {
+            match &selection.node {
+                Selection::Field(field) => {
+                    self.collect_fragments(&field.node.selection_set.node, used)
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    let name = &fragment_spread.node.fragment_name.node;
+                    if let Some((name, fragment)) = self.fragments.get_key_value(name)
+                        && used.insert(name)
+                    {
+                        self.collect_fragments(&fragment.node.selection_set.node, used);
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    self.collect_fragments(&inline_fragment.node.selection_set.node, used)
+                }
+            }
+        }
+    }
+
+    fn write_operation(
+        &self,
+        output: &mut String,
+        name: Option<&Name>,
+        operation: &OperationDefinition,
+    ) {
+        let root_type = match operation.ty {
+            OperationType::Query => self.registry.types.get(&self.registry.query_type),
+            OperationType::Mutation => self
+                .registry
+                .mutation_type
+                .as_ref()
+                .and_then(|name| self.registry.types.get(name)),
+            OperationType::Subscription => self
+                .registry
+                .subscription_type
+                .as_ref()
+                .and_then(|name| self.registry.types.get(name)),
+        };
+
+        if name.is_some()
+            || operation.ty != OperationType::Query
+            || !operation.variable_definitions.is_empty()
+            || !operation.directives.is_empty()
+        {
+            push_token(output, &operation.ty.to_string());
+            if let Some(name) = name {
+                push_token(output, name);
+            }
+
+            let mut variable_definitions =
+                operation.variable_definitions.iter().collect::<Vec<_>>();
+            variable_definitions.sort_by(|a, b| a.node.name.node.cmp(&b.node.name.node));
+            for (idx, variable_definition) in variable_definitions.into_iter().enumerate() {
+                push_token(output, if idx == 0 { "(" } else { "," });
+                push_token(output, &format!("${}", variable_definition.node.name.node));
+                push_token(output, ":");
+                push_token(output, &variable_definition.node.var_type.node.to_string());
+                if let Some(default_value) = &variable_definition.node.default_value {
+                    push_token(output, "=");
+                    self.write_value(output, None, &default_value.node.clone().into_value());
+                }
+                self.write_directives(output, &variable_definition.node.directives);
+            }
+            if !operation.variable_definitions.is_empty() {
+                push_token(output, ")");
+            }
+            self.write_directives(output, &operation.directives);
+        }
+
+        self.write_selection_set(
+            output,
+            &operation.selection_set.node,
+            root_type,
+            &mut Vec::new(),
+        );
+    }
+
+    fn write_selection_set(
+        &self,
+        output: &mut String,
+        selection_set: &'a SelectionSet,
+        parent_type: Option<&MetaType>,
+        inlining: &mut Vec<&'a str>,
+    ) {
+        // the selections are sorted by kind, by name and then by their output
+        let mut items = Vec::new();
+        for selection in &selection_set.items {
+            let mut item = String::new();
+            let (kind, name) = match &selection.node {
+                Selection::Field(field) => {
+                    if self.options.keep_aliases
+                        && let Some(alias) = &field.node.alias
+                    {
+                        push_token(&mut item, &alias.node);
+                        push_token(&mut item, ":");
+                    }
+                    push_token(&mut item, &field.node.name.node);
+
+                    let meta_field = parent_type
+                        .and_then(|parent_type| parent_type.field_by_name(&field.node.name.node));
+                    let mut arguments = field.node.arguments.iter().collect::<Vec<_>>();
+                    arguments.sort_by(|(a, _), (b, _)| a.node.cmp(&b.node));
+                    for (idx, (name, value)) in arguments.into_iter().enumerate() {
+                        push_token(&mut item, if idx == 0 { "(" } else { "," });
+                        push_token(&mut item, &name.node);
+                        push_token(&mut item, ":");
+                        let meta_input_value =
+                            meta_field.and_then(|field| field.args.get(name.node.as_str()));
+                        self.write_value(&mut item, meta_input_value, &value.node);
+                    }
+                    if !field.node.arguments.is_empty() {
+                        push_token(&mut item, ")");
+                    }
+                    self.write_directives(&mut item, &field.node.directives);
+
+                    if !field.node.selection_set.node.items.is_empty() {
+                        let parent_type = meta_field.and_then(|field| {
+                            self.registry
+                                .types
+                                .get(MetaTypeName::concrete_typename(&field.ty))
+                        });
+                        self.write_selection_set(
+                            &mut item,
+                            &field.node.selection_set.node,
+                            parent_type,
+                            inlining,
+                        );
+                    }
+                    (0, field.node.name.node.as_str())
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    let name = fragment_spread.node.fragment_name.node.as_str();
+                    match self.fragments.get(name) {
+                        Some(fragment)
+                            if self.options.inline_fragments && !inlining.contains(&name) =>
+                        {
+                            let on = fragment.node.type_condition.node.on.node.as_str();
+                            push_token(&mut item, "...");
+                            push_token(&mut item, "on");
+                            push_token(&mut item, on);
+                            self.write_directives(&mut item, &fragment_spread.node.directives);
+                            self.write_directives(&mut item, &fragment.node.directives);
+                            inlining.push(name);
+                            self.write_selection_set(
+                                &mut item,
+                                &fragment.node.selection_set.node,
+                                self.registry.types.get(on),
+                                inlining,
+                            );
+                            inlining.pop();
+                            (2, on)
+                        }
+                        _ => {
+                            push_token(&mut item, "...");
+                            push_token(&mut item, name);
+                            self.write_directives(&mut item, &fragment_spread.node.directives);
+                            (1, name)
+                        }
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    push_token(&mut item, "...");
+                    let (name, parent_type) = match &inline_fragment.node.type_condition {
+                        Some(type_condition) => {
+                            let on = type_condition.node.on.node.as_str();
+                            push_token(&mut item, "on");
+                            push_token(&mut item, on);
+                            (on, self.registry.types.get(on))
+                        }
+                        None => ("", parent_type),
+                    };
+                    self.write_directives(&mut item, &inline_fragment.node.directives);
+                    self.write_selection_set(
+                        &mut item,
+                        &inline_fragment.node.selection_set.node,
+                        parent_type,
+                        inlining,
+                    );
+                    (2, name)
+                }
+            };
+            items.push((kind, name, item));
+        }
+        items.sort();
+
+        push_token(output, "{");
+        for (_, _, item) in items {
+            push_token(output, &item);
+        }
+        push_token(output, "}");
+    }
+
+    fn write_directives(&self, output: &mut String, directives: &[Positioned<Directive>]) {
+        let mut directives = directives.iter().collect::<Vec<_>>();
+        directives.sort_by(|a, b| a.node.name.node.cmp(&b.node.name.node));
+        for directive in directives {
+            push_token(output, &format!("@{}", directive.node.name.node));
+            let mut arguments = directive.node.arguments.iter().collect::<Vec<_>>();
+            arguments.sort_by(|(a, _), (b, _)| a.node.cmp(&b.node));
+            for (idx, (name, value)) in arguments.into_iter().enumerate() {
+                push_token(output, if idx == 0 { "(" } else { "," });
+                push_token(output, &name.node);
+                push_token(output, ":");
+                self.write_value(output, None, &value.node);
+            }
+            if !directive.node.arguments.is_empty() {
+                push_token(output, ")");
+            }
+        }
+    }
+
+    fn write_value(
+        &self,
+        output: &mut String,
+        meta_input_value: Option<&MetaInputValue>,
+        value: &Value,
+    ) {
+        match value {
+            Value::Variable(name) => push_token(output, &format!("${}", name)),
+            Value::Null | Value::Boolean(_) | Value::Enum(_) => {
+                push_token(output, &value.to_string())
+            }
+            _ if !self.options.keep_literals => push_token(
+                output,
+                match value {
+                    Value::Number(_) => "0",
+                    Value::List(_) => "[]",
+                    Value::Object(_) => "{}",
+                    _ => "\"\"",
+                },
+            ),
+            _ if meta_input_value.is_some_and(|input_value| input_value.is_secret) => {
+                push_token(output, "\"<secret>\"")
+            }
+            Value::List(items) => {
+                push_token(output, "[");
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        push_token(output, ",");
+                    }
+                    self.write_value(output, meta_input_value, item);
+                }
+                push_token(output, "]");
+            }
+            Value::Object(fields) => {
+                let input_fields = meta_input_value
+                    .and_then(|input_value| {
+                        self.registry
+                            .types
+                            .get(MetaTypeName::concrete_typename(&input_value.ty))
+                    })
+                    .and_then(|ty| match ty {
+                        MetaType::InputObject { input_fields, .. } => Some(input_fields),
+                        _ => None,
+                    });
+                let mut fields = fields.iter().collect::<Vec<_>>();
+                fields.sort_by_key(|(name, _)| *name);
+                push_token(output, "{");
+                for (idx, (name, value)) in fields.into_iter().enumerate() {
+                    if idx > 0 {
+                        push_token(output, ",");
+                    }
+                    push_token(output, name);
+                    push_token(output, ":");
+                    let meta_input_value =
+                        input_fields.and_then(|input_fields| input_fields.get(name.as_str()));
+                    self.write_value(output, meta_input_value, value);
+                }
+                push_token(output, "}");
+            }
+            _ => push_token(output, &value.to_string()),
+        }
+    }
+}
+
+/// Appends a token, separated by a space only if both sides would otherwise
+/// be read as a single name or number.
+fn push_token(output: &mut String, token: &str) {
+    let is_word = |c: char| c == '_' || c.is_ascii_alphanumeric();
+    if output.ends_with(is_word) && token.starts_with(is_word) {
+        output.push(' ');
+    }
+    output.push_str(token);
+}
+
 #[cfg(test)]
 #[allow(clippy::diverging_sub_expression)]
 mod tests {
@@ -265,6 +692,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_normalize() {
+        let registry = Registry::default();
+        let doc = parse_query(
+            r#"
+            query Foo($b: Int, $a: Boolean) {
+              user(name: "hello", age: 5) {
+                ...Bar
+                ... on User {
+                  hello
+                  bee
+                }
+                tz
+                aliased: name
+              }
+            }
+            fragment Baz on User { asd }
+            fragment Bar on User { age @skip(if: $a) ...Nested }
+            fragment Nested on User { blah }
+            query Other { a }
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            registry
+                .normalize_exec_doc(&doc, Some("Foo"), NormalizeOptions::new())
+                .unwrap(),
+            r#"fragment Bar on User{age@skip(if:$a)...Nested}fragment Nested on User{blah}query Foo($a:Boolean,$b:Int){user(age:0,name:""){name tz...Bar...on User{bee hello}}}"#
+        );
+        assert_eq!(
+            registry
+                .normalize_exec_doc(
+                    &doc,
+                    Some("Foo"),
+                    NormalizeOptions::new()
+                        .inline_fragments()
+                        .keep_aliases()
+                        .keep_literals()
+                )
+                .unwrap(),
+            r#"query Foo($a:Boolean,$b:Int){user(age:5,name:"hello"){aliased:name tz...on User{age@skip(if:$a)...on User{blah}}...on User{bee hello}}}"#
+        );
+        assert_eq!(
+            registry
+                .normalize_exec_doc(&doc, Some("Other"), NormalizeOptions::new())
+                .unwrap(),
+            "query Other{a}"
+        );
+        assert!(
+            registry
+                .normalize_exec_doc(&doc, None, NormalizeOptions::new())
+                .is_none()
+        );
+
+        let doc =
+            parse_query("{ b(x: [1, 2], y: { b: 1.5, a: null }) a(e: ENUM, b: true) }").unwrap();
+        assert_eq!(
+            registry
+                .normalize_exec_doc(&doc, None, NormalizeOptions::new())
+                .unwrap(),
+            "{a(b:true,e:ENUM)b(x:[],y:{})}"
+        );
+        assert_eq!(
+            registry
+                .normalize_exec_doc(&doc, None, NormalizeOptions::new().keep_literals())
+                .unwrap(),
+            "{a(b:true,e:ENUM)b(x:[1,2],y:{a:null,b:1.5})}"
+        );
+    }
+
+    #[cfg(feature = "operation_signature")]
+    #[test]
+    fn test_operation_signature() {
+        let registry = Registry::default();
+        let signature = |query: &str| {
+            OperationSignature::new(
+                registry
+                    .normalize_exec_doc(&parse_query(query).unwrap(), None, Default::default())
+                    .unwrap(),
+            )
+        };
+
+        let a = signature("query Q($id: ID!) { user(id: $id) { name, id } }");
+        let b =
+            signature("query Q($id: ID!) {\n  user(id: $id) {\n    id\n    myName: name\n  }\n}");
+        assert_eq!(a.signature, "query Q($id:ID!){user(id:$id){id name}}");
+        assert_eq!(a, b);
+        assert_eq!(
+            a.hash,
+            "ae7ca9a125c245bcd9351303617f66a6ced5f60cde8c9c20905e981d5441510c"
+        );
+        assert_ne!(a, signature("query Q($id: ID!) { user(id: $id) { id } }"));
+    }
+
     #[test]
     fn test_stringify_secret() {
         #[derive(InputObject)]
@@ -313,5 +835,18 @@ mod tests {
             s,
             r#"query { value(a: 10, b: "<secret>", c: {v1: 1, v2: "<secret>", v3: {v4: 4, v5: "<secret>"}}) }"#
         );
+
+        let s = registry
+            .normalize_exec_doc(
+                &parse_query("{ value(c: { v3: { v5: 5, v4: 4 }, v2: 2, v1: 1 }, b: 20, a: 10) }")
+                    .unwrap(),
+                None,
+                NormalizeOptions::new().keep_literals(),
+            )
+            .unwrap();
+        assert_eq!(
+            s,
+            r#"{value(a:10,b:"<secret>",c:{v1:1,v2:"<secret>",v3:{v4:4,v5:"<secret>"}})}"#
+        );
     }
 }
@@ -53,6 +53,15 @@ pub struct Request {
     /// [IntrospectionMode::Enabled]).
     #[serde(skip)]
     pub introspection_mode: IntrospectionMode,
+
+    #[serde(skip)]
+    pub(crate) in_batch: bool,
+
+    /// The SHA-256 hash of the query source, if it is known without the
+    /// source, such as for a persisted query.
+    #[cfg(feature = "validation_cache")]
+    #[serde(skip)]
+    pub(crate) query_hash: Option<String>,
 }
 
 impl Request {
@@ -67,6 +76,9 @@ impl Request {
             extensions: Default::default(),
             parsed_query: None,
             introspection_mode: IntrospectionMode::Enabled,
+            in_batch: false,
+            #[cfg(feature = "validation_cache")]
+            query_hash: None,
         }
     }
 
@@ -106,6 +118,12 @@ impl Request {
         self
     }
 
+    /// Marks this request as one of the requests of a batch.
+    pub(crate) fn mark_in_batch(mut self) -> Self {
+        self.in_batch = true;
+        self
+    }
+
     #[inline]
     /// Performs parsing of query ahead of execution.
     ///
@@ -114,7 +132,13 @@ impl Request {
     pub fn parsed_query(&mut self) -> Result<&ExecutableDocument, ServerError> {
         if self.parsed_query.is_none() {
             match parse_query(&self.query) {
-                Ok(parsed) => self.parsed_query = Some(parsed),
+                Ok(parsed) => {
+                    self.parsed_query = Some(parsed);
+                    #[cfg(feature = "validation_cache")]
+                    {
+                        self.query_hash = Some(crate::validation::cache::query_hash(&self.query));
+                    }
+                }
                 Err(error) => return Err(error.into()),
             }
         }
@@ -131,6 +155,10 @@ impl Request {
     /// the query again.
     pub fn set_parsed_query(&mut self, doc: ExecutableDocument) {
         self.parsed_query = Some(doc);
+        #[cfg(feature = "validation_cache")]
+        {
+            self.query_hash = None;
+        }
     }
 
     /// Set a variable to an upload value.
@@ -4,8 +4,11 @@ use futures_util::{FutureExt, StreamExt as _, stream::FuturesUnordered};
 use indexmap::IndexMap;
 
 use crate::{
-    Context, ContextBase, ContextSelectionSet, Error, Name, OutputType, ServerError, ServerResult,
-    Value, extensions::ResolveInfo, parser::types::Selection,
+    Context, ContextBase, ContextSelectionSet, Error, Name, OutputType, Positioned, ServerError,
+    ServerResult, Value,
+    execution_plan::PlannedSelection,
+    extensions::ResolveInfo,
+    parser::types::{Field, Selection},
 };
 
 /// Represents a GraphQL container object.
@@ -220,154 +223,24 @@ impl<'a> Fields<'a> {
         ctx: &ContextSelectionSet<'a>,
         root: &'a T,
     ) -> ServerResult<()> {
-        for selection in &ctx.item.node.items {
-            match &selection.node {
-                Selection::Field(field) => {
-                    if field.node.name.node == "__typename" {
-                        // Get the typename
-                        let ctx_field = ctx.with_field(field);
-                        let field_name = ctx_field.item.node.response_key().node.clone();
-                        let typename = root.introspection_type_name().into_owned();
-
-                        self.0.push(Box::pin(async move {
-                            Ok((field_name, Value::String(typename)))
-                        }));
-                        continue;
+        if let Some(plan) = ctx.query_env.plan.as_deref()
+            && let Some(selections) =
+                plan.collect(ctx.item, &T::type_name(), &root.introspection_type_name())
+        {
+            for selection in selections {
+                match selection {
+                    PlannedSelection::Field(field) => self.add_field(ctx, root, field),
+                    PlannedSelection::Fragment(selection_set) => {
+                        root.collect_all_fields(&ctx.with_selection_set(selection_set), self)?
                     }
-
-                    let resolve_fut = Box::pin({
-                        let ctx = ctx.clone();
-                        async move {
-                            let ctx_field = ctx.with_field(field);
-                            let field_name = ctx_field.item.node.response_key().node.clone();
-                            let extensions = &ctx.query_env.extensions;
-
-                            if extensions.is_empty() && field.node.directives.is_empty() {
-                                Ok((
-                                    field_name,
-                                    match root.resolve_field(&ctx_field).await {
-                                        Ok(value) => value.unwrap_or_default(),
-                                        Err(err) => {
-                                            if cfg!(feature = "nullable-result") {
-                                                ctx_field.add_error(err);
-                                                Value::Null
-                                            } else {
-                                                return Err(err);
-                                            }
-                                        }
-                                    },
-                                ))
-                            } else {
-                                let type_name = T::type_name();
-                                let resolve_info = ResolveInfo {
-                                    path_node: ctx_field.path_node.as_ref().unwrap(),
-                                    parent_type: &type_name,
-                                    return_type: match ctx_field
-                                        .schema_env
-                                        .registry
-                                        .types
-                                        .get(type_name.as_ref())
-                                        .and_then(|ty| {
-                                            ty.field_by_name(field.node.name.node.as_str())
-                                        })
-                                        .map(|field| &field.ty)
-                                    {
-                                        Some(ty) => &ty,
-                                        None => {
-                                            return Err(ServerError::new(
-                                                format!(
-                                                    r#"Cannot query field "{}" on type "{}"."#,
-                                                    field_name, type_name
-                                                ),
-                                                Some(ctx_field.item.pos),
-                                            ));
-                                        }
-                                    },
-                                    name: field.node.name.node.as_str(),
-                                    alias: field
-                                        .node
-                                        .alias
-                                        .as_ref()
-                                        .map(|alias| alias.node.as_str()),
-                                    is_for_introspection: ctx_field.is_for_introspection,
-                                    field: &field.node,
-                                };
-
-                                let resolve_fut = root.resolve_field(&ctx_field);
-
-                                if field.node.directives.is_empty() {
-                                    futures_util::pin_mut!(resolve_fut);
-                                    Ok((
-                                        field_name,
-                                        match extensions
-                                            .resolve(resolve_info, &mut resolve_fut)
-                                            .await
-                                        {
-                                            Ok(value) => value.unwrap_or_default(),
-                                            Err(err) => {
-                                                if cfg!(feature = "nullable-result") {
-                                                    ctx_field.add_error(err);
-                                                    Value::Null
-                                                } else {
-                                                    return Err(err);
-                                                }
-                                            }
-                                        },
-                                    ))
-                                } else {
-                                    let mut resolve_fut = resolve_fut.boxed();
-
-                                    for directive in &field.node.directives {
-                                        if let Some(directive_factory) = ctx
-                                            .schema_env
-                                            .custom_directives
-                                            .get(directive.node.name.node.as_str())
-                                        {
-                                            let ctx_directive = ContextBase {
-                                                path_node: ctx_field.path_node,
-                                                is_for_introspection: false,
-                                                item: directive,
-                                                schema_env: ctx_field.schema_env,
-                                                query_env: ctx_field.query_env,
-                                                execute_data: ctx_field.execute_data,
-                                            };
-                                            let directive_instance = directive_factory
-                                                .create(&ctx_directive, &directive.node)?;
-                                            resolve_fut = Box::pin({
-                                                let ctx_field = ctx_field.clone();
-                                                async move {
-                                                    directive_instance
-                                                        .resolve_field(&ctx_field, &mut resolve_fut)
-                                                        .await
-                                                }
-                                            });
-                                        }
-                                    }
-
-                                    Ok((
-                                        field_name,
-                                        match extensions
-                                            .resolve(resolve_info, &mut resolve_fut)
-                                            .await
-                                        {
-                                            Ok(value) => value.unwrap_or_default(),
-                                            Err(err) => {
-                                                if cfg!(feature = "nullable-result") {
-                                                    ctx_field.add_error(err);
-                                                    Value::Null
-                                                } else {
-                                                    return Err(err);
-                                                }
-                                            }
-                                        },
-                                    ))
-                                }
-                            }
-                        }
-                    });
-
-                    self.0.push(resolve_fut);
                 }
+            }
+            return Ok(());
+        }
+
+        for selection in &ctx.item.node.items {
+            match &selection.node {
+                Selection::Field(field) => self.add_field(ctx, root, field),
                 selection => {
                     let (type_condition, selection_set) = match selection {
                         Selection::Field(_) => unreachable!(),
@@ -421,4 +294,144 @@ impl<'a> Fields<'a> {
         }
         Ok(())
     }
+
+    fn add_field<T: ContainerType + ?Sized>(
+        &mut self,
+        ctx: &ContextSelectionSet<'a>,
+        root: &'a T,
+        field: &'a Positioned<Field>,
+    ) {
+        if field.node.name.node == "__typename" {
+            // Get the typename
+            let ctx_field = ctx.with_field(field);
+            let field_name = ctx_field.item.node.response_key().node.clone();
+            let typename = root.introspection_type_name().into_owned();
+
+            self.0.push(Box::pin(async move {
+                Ok((field_name, Value::String(typename)))
+            }));
+            return;
+        }
+
+        let resolve_fut = Box::pin({
+            let ctx = ctx.clone();
+            async move {
+                let ctx_field = ctx.with_field(field);
+                let field_name = ctx_field.item.node.response_key().node.clone();
+                let extensions = &ctx.query_env.extensions;
+
+                if extensions.is_empty() && field.node.directives.is_empty() {
+                    Ok((
+                        field_name,
+                        match root.resolve_field(&ctx_field).await {
+                            Ok(value) => value.unwrap_or_default(),
+                            Err(err) => {
+                                if cfg!(feature = "nullable-result") {
+                                    ctx_field.add_error(err);
+                                    Value::Null
+                                } else {
+                                    return Err(err);
+                                }
+                            }
+                        },
+                    ))
+                } else {
+                    let type_name = T::type_name();
+                    let resolve_info = ResolveInfo {
+                        path_node: ctx_field.path_node.as_ref().unwrap(),
+                        parent_type: &type_name,
+                        return_type: match ctx_field
+                            .schema_env
+                            .registry
+                            .types
+                            .get(type_name.as_ref())
+                            .and_then(|ty| ty.field_by_name(field.node.name.node.as_str()))
+                            .map(|field| &field.ty)
+                        {
+                            Some(ty) => &ty,
+                            None => {
+                                return Err(ServerError::new(
+                                    format!(
+                                        r#"Cannot query field "{}" on type "{}"."#,
+                                        field_name, type_name
+                                    ),
+                                    Some(ctx_field.item.pos),
+                                ));
+                            }
+                        },
+                        name: field.node.name.node.as_str(),
+                        alias: field.node.alias.as_ref().map(|alias| alias.node.as_str()),
+                        is_for_introspection: ctx_field.is_for_introspection,
+                        field: &field.node,
+                    };
+
+                    let resolve_fut = root.resolve_field(&ctx_field);
+
+                    if field.node.directives.is_empty() {
+                        futures_util::pin_mut!(resolve_fut);
+                        Ok((
+                            field_name,
+                            match extensions.resolve(resolve_info, &mut resolve_fut).await {
+                                Ok(value) => value.unwrap_or_default(),
+                                Err(err) => {
+                                    if cfg!(feature = "nullable-result") {
+                                        ctx_field.add_error(err);
+                                        Value::Null
+                                    } else {
+                                        return Err(err);
+                                    }
+                                }
+                            },
+                        ))
+                    } else {
+                        let mut resolve_fut = resolve_fut.boxed();
+
+                        for directive in &field.node.directives {
+                            if let Some(directive_factory) = ctx
+                                .schema_env
+                                .custom_directives
+                                .get(directive.node.name.node.as_str())
+                            {
+                                let ctx_directive = ContextBase {
+                                    path_node: ctx_field.path_node,
+                                    is_for_introspection: false,
+                                    item: directive,
+                                    schema_env: ctx_field.schema_env,
+                                    query_env: ctx_field.query_env,
+                                    execute_data: ctx_field.execute_data,
+                                };
+                                let directive_instance =
+                                    directive_factory.create(&ctx_directive, &directive.node)?;
+                                resolve_fut = Box::pin({
+                                    let ctx_field = ctx_field.clone();
+                                    async move {
+                                        directive_instance
+                                            .resolve_field(&ctx_field, &mut resolve_fut)
+                                            .await
+                                    }
+                                });
+                            }
+                        }
+
+                        Ok((
+                            field_name,
+                            match extensions.resolve(resolve_info, &mut resolve_fut).await {
+                                Ok(value) => value.unwrap_or_default(),
+                                Err(err) => {
+                                    if cfg!(feature = "nullable-result") {
+                                        ctx_field.add_error(err);
+                                        Value::Null
+                                    } else {
+                                        return Err(err);
+                                    }
+                                }
+                            },
+                        ))
+                    }
+                }
+            }
+        });
+
+        self.0.push(resolve_fut);
+    }
 }
@@ -2,32 +2,40 @@ use std::{
     any::{Any, TypeId},
     collections::{HashMap, HashSet},
     ops::Deref,
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
 use async_graphql_parser::types::ExecutableDocument;
 use futures_util::stream::{self, BoxStream, FuturesOrdered, StreamExt};
 
 use crate::{
-    BatchRequest, BatchResponse, CacheControl, ContextBase, EmptyMutation, EmptySubscription,
-    Executor, InputType, ObjectType, OutputType, QueryEnv, Request, Response, ServerError,
-    ServerResult, SubscriptionType, Variables,
+    BatchRequest, BatchResponse, ContextBase, EmptyMutation, EmptySubscription, Executor,
+    InputType, ObjectType, OutputType, QueryEnv, Request, Response, ServerError, ServerResult,
+    SubscriptionType, Variables,
     context::{Data, QueryEnvInner},
-    custom_directive::CustomDirectiveFactory,
+    custom_directive::{
+        CustomDirectiveFactory, apply_selection_directives, apply_variable_directives,
+        execute_operation_directives,
+    },
     extensions::{ExtensionFactory, Extensions},
     parser::{
-        Positioned, parse_query,
+        ParseOptions, Pos, Positioned, parse_query_with_options,
         types::{Directive, DocumentOperations, OperationType, Selection, SelectionSet},
     },
     registry::{Registry, SDLExportOptions},
     resolver_utils::{resolve_container, resolve_container_serial},
     subscription::collect_subscription_streams,
     types::QueryRoot,
-    validation::{ValidationMode, check_rules},
+    validation::{ValidationMode, ValidationRuleFactory, check_rules, check_variables},
+};
+#[cfg(feature = "validation_cache")]
+use crate::{
+    execution_plan::ExecutionPlan,
+    validation::{self, ValidatedDocument, ValidationCacheKey, ValidationCacheStorage},
 };
 
 /// Introspection mode
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 pub enum IntrospectionMode {
     /// Introspection only
     IntrospectionOnly,
@@ -38,6 +46,16 @@ pub enum IntrospectionMode {
     Disabled,
 }
 
+/// Limits on the size of the queries, checked before validation.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct QueryLimits {
+    pub(crate) max_directives: Option<usize>,
+    pub(crate) max_aliases: Option<usize>,
+    pub(crate) max_aliases_per_selection_set: Option<usize>,
+    pub(crate) max_root_fields: Option<usize>,
+    pub(crate) max_batch_size: Option<usize>,
+}
+
 /// Schema builder
 pub struct SchemaBuilder<Query, Mutation, Subscription> {
     validation_mode: ValidationMode,
@@ -49,9 +67,15 @@ pub struct SchemaBuilder<Query, Mutation, Subscription> {
     complexity: Option<usize>,
     depth: Option<usize>,
     recursive_depth: usize,
-    max_directives: Option<usize>,
+    parse_options: ParseOptions,
+    limits: QueryLimits,
     extensions: Vec<Box<dyn ExtensionFactory>>,
     custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
+    validation_rules: Vec<Box<dyn ValidationRuleFactory>>,
+    #[cfg(feature = "validation_cache")]
+    validation_cache: Option<Box<dyn ValidationCacheStorage>>,
+    #[cfg(feature = "validation_cache")]
+    compile_operations: bool,
 }
 
 impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription> {
@@ -115,10 +139,55 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Set the limits checked while parsing a query, such as the maximum
+    /// number of tokens. (default: no limit)
+    ///
+    /// Queries exceeding them are rejected before they are fully parsed.
+    #[must_use]
+    pub fn parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+
     /// Set the maximum number of directives on a single field. (default: no
     /// limit)
     pub fn limit_directives(mut self, max_directives: usize) -> Self {
-        self.max_directives = Some(max_directives);
+        self.limits.max_directives = Some(max_directives);
+        self
+    }
+
+    /// Set the maximum number of aliases a query can have, counting the
+    /// aliases of a fragment each time it is spread. (default: no limit)
+    #[must_use]
+    pub fn limit_aliases(mut self, max_aliases: usize) -> Self {
+        self.limits.max_aliases = Some(max_aliases);
+        self
+    }
+
+    /// Set the maximum number of aliases in a single selection set, including
+    /// the aliases of the fragments spread into it, unlike
+    /// [`ParseOptions::max_aliases`]. (default: no limit)
+    #[must_use]
+    pub fn limit_aliases_per_selection_set(mut self, max_aliases: usize) -> Self {
+        self.limits.max_aliases_per_selection_set = Some(max_aliases);
+        self
+    }
+
+    /// Set the maximum number of fields in the root selection set of an
+    /// operation. (default: no limit)
+    #[must_use]
+    pub fn limit_root_fields(mut self, max_root_fields: usize) -> Self {
+        self.limits.max_root_fields = Some(max_root_fields);
+        self
+    }
+
+    /// Set the maximum number of requests in a batch. (default: no limit)
+    ///
+    /// Larger batches are rejected with an error without executing any
+    /// request.
+    #[must_use]
+    pub fn limit_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.limits.max_batch_size = Some(max_batch_size);
         self
     }
 
@@ -139,7 +208,7 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
     /// }
     ///
     /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
-    ///     .extension(extensions::Logger)
+    ///     .extension(extensions::Analyzer)
     ///     .finish();
     /// ```
     #[must_use]
@@ -163,6 +232,91 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Add a validation rule, which is run after the built-in rules in every
+    /// validation mode.
+    ///
+    /// The rule is a [`Visitor`](crate::Visitor) created for every request,
+    /// whose [`VisitorContext`](crate::VisitorContext) gives access to the
+    /// registry, the variables and the operation name of the request, and
+    /// reports errors the same way as the built-in rules.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::{parser::types::Field, *};
+    ///
+    /// #[derive(Default)]
+    /// struct MaxFields(usize);
+    ///
+    /// impl<'a> Visitor<'a> for MaxFields {
+    ///     fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+    ///         self.0 += 1;
+    ///         if self.0 == 3 {
+    ///             ctx.report_error(vec![field.pos], "Too many fields.");
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .validation_rule(MaxFields::default)
+    ///     .finish();
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async move {
+    /// assert!(schema.execute("{ a: value b: value }").await.is_ok());
+    /// let errors = schema.execute("{ a: value b: value c: value }").await.errors;
+    /// assert_eq!(errors[0].message, "Too many fields.");
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn validation_rule(mut self, rule: impl ValidationRuleFactory) -> Self {
+        self.validation_rules.push(Box::new(rule));
+        self
+    }
+
+    /// Cache the parsed and validated documents, so that the requests with
+    /// the same query source, operation name and introspection mode skip the
+    /// parsing and the validation.
+    ///
+    /// The results of the validations that read the variables or the batch of
+    /// the request, such as a complexity computed from the arguments, are not
+    /// cached. The variables are checked against the types of the arguments
+    /// for every request. See
+    /// [`LruValidationCache`](struct.LruValidationCache.html) for a
+    /// memory-based storage.
+    #[cfg(feature = "validation_cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "validation_cache")))]
+    #[must_use]
+    pub fn validation_cache(mut self, storage: impl ValidationCacheStorage) -> Self {
+        self.validation_cache = Some(Box::new(storage));
+        self
+    }
+
+    /// Compile the operations stored in the validation cache into execution
+    /// plans, so that their executions do not collect the fields of the
+    /// selection sets, merge the fragments or apply the `@skip` and
+    /// `@include` directives again.
+    ///
+    /// The plans are stored with the documents, so this requires a
+    /// [`validation_cache`](Self::validation_cache). The operations whose
+    /// `@skip` or `@include` directives use variables, or with custom
+    /// directives on their selections, are executed without a plan.
+    #[cfg(feature = "validation_cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "validation_cache")))]
+    #[must_use]
+    pub fn enable_compiled_operations(mut self) -> Self {
+        self.compile_operations = true;
+        self
+    }
+
     /// Enable federation, which is automatically enabled if the Query has least
     /// one entity definition.
     #[must_use]
@@ -227,6 +381,19 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Compute the cache control of the responses with the rules of Apollo
+    /// Server.
+    ///
+    /// The hint of a field takes precedence over the hint of its type. Root
+    /// fields and fields returning an object, interface or union without a
+    /// hint make the response uncacheable, unless they have
+    /// `inherit_max_age`. Other fields inherit the max age of their parent.
+    #[must_use]
+    pub fn enable_apollo_cache_control(mut self) -> Self {
+        self.registry.apollo_cache_control = true;
+        self
+    }
+
     /// Make all fields sorted on introspection queries.
     pub fn with_sorted_fields(mut self) -> Self {
         use crate::registry::MetaType;
@@ -272,12 +439,23 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
             complexity: self.complexity,
             depth: self.depth,
             recursive_depth: self.recursive_depth,
-            max_directives: self.max_directives,
+            limits: self.limits,
             extensions: self.extensions,
             env: SchemaEnv(Arc::new(SchemaEnvInner {
+                #[cfg(feature = "validation_cache")]
+                schema_fingerprint: self
+                    .validation_cache
+                    .as_ref()
+                    .map(|_| validation::cache::schema_fingerprint(&self.registry)),
                 registry: self.registry,
                 data: self.data,
                 custom_directives: self.custom_directives,
+                validation_rules: self.validation_rules,
+                #[cfg(feature = "validation_cache")]
+                validation_cache: self.validation_cache,
+                #[cfg(feature = "validation_cache")]
+                compile_operations: self.compile_operations,
+                parse_options: self.parse_options,
             })),
         }))
     }
@@ -288,6 +466,14 @@ pub struct SchemaEnvInner {
     pub registry: Registry,
     pub data: Data,
     pub custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
+    pub validation_rules: Vec<Box<dyn ValidationRuleFactory>>,
+    #[cfg(feature = "validation_cache")]
+    pub validation_cache: Option<Box<dyn ValidationCacheStorage>>,
+    #[cfg(feature = "validation_cache")]
+    pub schema_fingerprint: Option<String>,
+    #[cfg(feature = "validation_cache")]
+    pub compile_operations: bool,
+    pub parse_options: ParseOptions,
 }
 
 #[doc(hidden)]
@@ -311,7 +497,7 @@ pub struct SchemaInner<Query, Mutation, Subscription> {
     pub(crate) complexity: Option<usize>,
     pub(crate) depth: Option<usize>,
     pub(crate) recursive_depth: usize,
-    pub(crate) max_directives: Option<usize>,
+    pub(crate) limits: QueryLimits,
     pub(crate) extensions: Vec<Box<dyn ExtensionFactory>>,
     pub(crate) env: SchemaEnv,
 }
@@ -390,9 +576,15 @@ where
             complexity: None,
             depth: None,
             recursive_depth: 32,
-            max_directives: None,
+            parse_options: Default::default(),
+            limits: Default::default(),
             extensions: Default::default(),
             custom_directives: Default::default(),
+            validation_rules: Default::default(),
+            #[cfg(feature = "validation_cache")]
+            validation_cache: None,
+            #[cfg(feature = "validation_cache")]
+            compile_operations: false,
         }
     }
 
@@ -417,6 +609,7 @@ where
             federation_subscription: false,
             ignore_name_conflicts,
             enable_suggestions: true,
+            apollo_cache_control: false,
         };
         registry.add_system_types();
 
@@ -441,9 +634,10 @@ where
         Self::build(query, mutation, subscription).finish()
     }
 
+    /// Returns the registry of this schema, which can be used to visit or fold
+    /// a document with the type information of the schema.
     #[inline]
-    #[allow(unused)]
-    pub(crate) fn registry(&self) -> &Registry {
+    pub fn registry(&self) -> &Registry {
         &self.0.env.registry
     }
 
@@ -480,38 +674,45 @@ where
         let ctx = ContextBase {
             path_node: None,
             is_for_introspection: false,
-            item: &env.operation.node.selection_set,
+            item: match env.plan.as_deref() {
+                Some(plan) => plan.selection_set(),
+                None => &env.operation.node.selection_set,
+            },
             schema_env: &self.0.env,
             query_env: &env,
             execute_data,
         };
 
-        let res = match &env.operation.node.ty {
-            OperationType::Query => resolve_container(&ctx, &self.0.query).await,
-            OperationType::Mutation => {
-                if self.0.env.registry.introspection_mode == IntrospectionMode::IntrospectionOnly
-                    || env.introspection_mode == IntrospectionMode::IntrospectionOnly
-                {
-                    resolve_container_serial(&ctx, &EmptyMutation).await
-                } else {
-                    resolve_container_serial(&ctx, &self.0.mutation).await
+        let execute = async {
+            let res = match &env.operation.node.ty {
+                OperationType::Query => resolve_container(&ctx, &self.0.query).await,
+                OperationType::Mutation => {
+                    if self.0.env.registry.introspection_mode
+                        == IntrospectionMode::IntrospectionOnly
+                        || env.introspection_mode == IntrospectionMode::IntrospectionOnly
+                    {
+                        resolve_container_serial(&ctx, &EmptyMutation).await
+                    } else {
+                        resolve_container_serial(&ctx, &self.0.mutation).await
+                    }
                 }
-            }
-            OperationType::Subscription => Err(ServerError::new(
-                "Subscriptions are not supported on this transport.",
-                None,
-            )),
-        };
+                OperationType::Subscription => Err(ServerError::new(
+                    "Subscriptions are not supported on this transport.",
+                    None,
+                )),
+            };
 
-        let mut resp = match res {
-            Ok(value) => Response::new(value),
-            Err(err) => Response::from_errors(vec![err]),
-        }
-        .http_headers(std::mem::take(&mut *env.http_headers.lock().unwrap()));
+            let mut resp = match res {
+                Ok(value) => Response::new(value),
+                Err(err) => Response::from_errors(vec![err]),
+            }
+            .http_headers(std::mem::take(&mut *env.http_headers.lock().unwrap()));
 
-        resp.errors
-            .extend(std::mem::take(&mut *env.errors.lock().unwrap()));
-        resp
+            resp.errors
+                .extend(std::mem::take(&mut *env.errors.lock().unwrap()));
+            resp.cache_control(*env.cache_control.lock().unwrap())
+        };
+        execute_operation_directives(&ctx, Box::pin(execute)).await
     }
 
     /// Execute a GraphQL query.
@@ -525,23 +726,19 @@ where
                     extensions,
                     request,
                     Default::default(),
-                    &self.0.env.registry,
+                    &self.0.env,
                     self.0.validation_mode,
                     self.0.recursive_depth,
-                    self.0.max_directives,
+                    self.0.limits,
                     self.0.complexity,
                     self.0.depth,
                 )
                 .await
                 {
-                    Ok((env, cache_control)) => {
+                    Ok(env) => {
                         let f = |execute_data: Option<Data>| {
                             let env = env.clone();
-                            async move {
-                                self.execute_once(env, execute_data.as_ref())
-                                    .await
-                                    .cache_control(cache_control)
-                            }
+                            async move { self.execute_once(env, execute_data.as_ref()).await }
                         };
                         env.extensions
                             .execute(env.operation_name.as_deref(), f)
@@ -557,11 +754,16 @@ where
 
     /// Execute a GraphQL batch query.
     pub async fn execute_batch(&self, batch_request: BatchRequest) -> BatchResponse {
+        if let Err(err) = check_batch_size(&batch_request, &self.0.limits) {
+            return BatchResponse::Single(Response::from_errors(vec![err]));
+        }
         match batch_request {
             BatchRequest::Single(request) => BatchResponse::Single(self.execute(request).await),
             BatchRequest::Batch(requests) => BatchResponse::Batch(
                 FuturesOrdered::from_iter(
-                    requests.into_iter().map(|request| self.execute(request)),
+                    requests
+                        .into_iter()
+                        .map(|request| self.execute(request.mark_in_batch())),
                 )
                 .collect()
                 .await,
@@ -583,14 +785,14 @@ where
             let extensions = extensions.clone();
             let env = self.0.env.clone();
             asynk_strim::stream_fn(|mut yielder| async move {
-                let (env, cache_control) = match prepare_request(
+                let env = match prepare_request(
                     extensions,
                     request,
                     session_data,
-                    &env.registry,
+                    &env,
                     schema.0.validation_mode,
                     schema.0.recursive_depth,
-                    schema.0.max_directives,
+                    schema.0.limits,
                     schema.0.complexity,
                     schema.0.depth,
                 )
@@ -607,19 +809,13 @@ where
                     let f = |execute_data: Option<Data>| {
                         let env = env.clone();
                         let schema = schema.clone();
-                        async move {
-                            schema
-                                .execute_once(env, execute_data.as_ref())
-                                .await
-                                .cache_control(cache_control)
-                        }
+                        async move { schema.execute_once(env, execute_data.as_ref()).await }
                     };
                     yielder
                         .yield_item(
                             env.extensions
                                 .execute(env.operation_name.as_deref(), f)
-                                .await
-                                .cache_control(cache_control),
+                                .await,
                         )
                         .await;
                     return;
@@ -680,6 +876,10 @@ where
         Schema::execute(self, request).await
     }
 
+    async fn execute_batch(&self, batch_request: BatchRequest) -> BatchResponse {
+        Schema::execute_batch(self, batch_request).await
+    }
+
     fn execute_stream(
         &self,
         request: Request,
@@ -736,6 +936,171 @@ fn check_max_directives(doc: &ExecutableDocument, max_directives: usize) -> Serv
     Ok(())
 }
 
+pub(crate) fn check_batch_size(
+    batch_request: &BatchRequest,
+    limits: &QueryLimits,
+) -> ServerResult<()> {
+    if let BatchRequest::Batch(requests) = batch_request
+        && let Some(max_batch_size) = limits.max_batch_size
+        && requests.len() > max_batch_size
+    {
+        return Err(ServerError::new(
+            format!(
+                "The number of requests in a batch cannot be greater than `{}`",
+                max_batch_size
+            ),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+fn check_aliases_and_root_fields(
+    doc: &ExecutableDocument,
+    limits: &QueryLimits,
+) -> ServerResult<()> {
+    /// The number of aliases and fields of a selection set.
+    #[derive(Default, Clone, Copy)]
+    struct Counts {
+        /// The aliases in the selection set and all the nested selection sets.
+        aliases: usize,
+        /// The aliases merged into the selection set itself.
+        own_aliases: usize,
+        /// The fields merged into the selection set itself.
+        own_fields: usize,
+    }
+
+    impl Counts {
+        fn add(&mut self, other: Counts) {
+            self.aliases = self.aliases.saturating_add(other.aliases);
+            self.own_aliases = self.own_aliases.saturating_add(other.own_aliases);
+            self.own_fields = self.own_fields.saturating_add(other.own_fields);
+        }
+    }
+
+    fn check_own_aliases(
+        counts: Counts,
+        pos: Pos,
+        max_aliases_per_selection_set: Option<usize>,
+    ) -> ServerResult<()> {
+        if let Some(max_aliases) = max_aliases_per_selection_set
+            && counts.own_aliases > max_aliases
+        {
+            return Err(ServerError::new(
+                format!(
+                    "The number of aliases in a selection set cannot be greater than `{}`",
+                    max_aliases
+                ),
+                Some(pos),
+            ));
+        }
+        Ok(())
+    }
+
+    // The counts of the fragments are memoized, so that a fragment spread many
+    // times is only visited once.
+    fn count_selection_set<'a>(
+        doc: &'a ExecutableDocument,
+        selection_set: &'a Positioned<SelectionSet>,
+        limits: &QueryLimits,
+        fragments: &mut HashMap<&'a str, Option<Counts>>,
+    ) -> ServerResult<Counts> {
+        let mut counts = Counts::default();
+        for selection in &selection_set.node.items {
+            match &selection.node {
+                Selection::Field(field) => {
+                    let nested =
+                        count_selection_set(doc, &field.node.selection_set, limits, fragments)?;
+                    check_own_aliases(nested, field.pos, limits.max_aliases_per_selection_set)?;
+                    if field.node.alias.is_some() {
+                        counts.aliases = counts.aliases.saturating_add(1);
+                        counts.own_aliases += 1;
+                    }
+                    counts.own_fields += 1;
+                    counts.aliases = counts.aliases.saturating_add(nested.aliases);
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    let name = fragment_spread.node.fragment_name.node.as_str();
+                    let fragment_counts = match fragments.get(name) {
+                        Some(fragment_counts) => *fragment_counts,
+                        None => match doc.fragments.get(name) {
+                            Some(fragment) => {
+                                // Fragment cycles are reported by the validation
+                                fragments.insert(name, None);
+                                let fragment_counts = count_selection_set(
+                                    doc,
+                                    &fragment.node.selection_set,
+                                    limits,
+                                    fragments,
+                                )?;
+                                fragments.insert(name, Some(fragment_counts));
+                                Some(fragment_counts)
+                            }
+                            None => None,
+                        },
+                    };
+                    if let Some(fragment_counts) = fragment_counts {
+                        counts.add(fragment_counts);
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    counts.add(count_selection_set(
+                        doc,
+                        &inline_fragment.node.selection_set,
+                        limits,
+                        fragments,
+                    )?);
+                }
+            }
+        }
+        Ok(counts)
+    }
+
+    if limits.max_aliases.is_none()
+        && limits.max_aliases_per_selection_set.is_none()
+        && limits.max_root_fields.is_none()
+    {
+        return Ok(());
+    }
+
+    let mut fragments = HashMap::new();
+    for (_, operation) in doc.operations.iter() {
+        let counts =
+            count_selection_set(doc, &operation.node.selection_set, limits, &mut fragments)?;
+        check_own_aliases(
+            counts,
+            operation.node.selection_set.pos,
+            limits.max_aliases_per_selection_set,
+        )?;
+
+        if let Some(max_aliases) = limits.max_aliases
+            && counts.aliases > max_aliases
+        {
+            return Err(ServerError::new(
+                format!(
+                    "The number of aliases of the query cannot be greater than `{}`",
+                    max_aliases
+                ),
+                Some(operation.pos),
+            ));
+        }
+
+        if let Some(max_root_fields) = limits.max_root_fields
+            && counts.own_fields > max_root_fields
+        {
+            return Err(ServerError::new(
+                format!(
+                    "The number of root fields of the query cannot be greater than `{}`",
+                    max_root_fields
+                ),
+                Some(operation.pos),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn check_recursive_depth(doc: &ExecutableDocument, max_depth: usize) -> ServerResult<()> {
     fn check_selection_set(
         doc: &ExecutableDocument,
@@ -798,7 +1163,7 @@ fn check_recursive_depth(doc: &ExecutableDocument, max_depth: usize) -> ServerRe
     Ok(())
 }
 
-fn remove_skipped_selection(selection_set: &mut SelectionSet, variables: &Variables) {
+pub(crate) fn remove_skipped_selection(selection_set: &mut SelectionSet, variables: &Variables) {
     fn is_skipped(directives: &[Positioned<Directive>], variables: &Variables) -> bool {
         for directive in directives {
             let include = match &*directive.node.name.node {
@@ -851,29 +1216,71 @@ pub(crate) async fn prepare_request(
     mut extensions: Extensions,
     request: Request,
     session_data: Arc<Data>,
-    registry: &Registry,
+    schema_env: &SchemaEnv,
     validation_mode: ValidationMode,
     recursive_depth: usize,
-    max_directives: Option<usize>,
+    limits: QueryLimits,
     complexity: Option<usize>,
     depth: Option<usize>,
-) -> Result<(QueryEnv, CacheControl), Vec<ServerError>> {
+) -> Result<QueryEnv, Vec<ServerError>> {
     let mut request = extensions.prepare_request(request).await?;
     let query_data = Arc::new(std::mem::take(&mut request.data));
     extensions.attach_query_data(query_data.clone());
 
-    let mut document = {
+    // look up the document in the validation cache
+    #[cfg(feature = "validation_cache")]
+    let cache_key = schema_env
+        .schema_fingerprint
+        .as_ref()
+        .and_then(|schema_fingerprint| {
+            let query_hash = match (&request.query_hash, &request.parsed_query) {
+                (Some(query_hash), _) => query_hash.clone(),
+                (None, None) => validation::cache::query_hash(&request.query),
+                (None, Some(_)) => return None,
+            };
+            Some(ValidationCacheKey {
+                schema_fingerprint: schema_fingerprint.clone(),
+                query_hash,
+                operation_name: request.operation_name.clone(),
+                introspection_mode: request.introspection_mode,
+            })
+        });
+    #[cfg(feature = "validation_cache")]
+    let cached = match (&schema_env.validation_cache, &cache_key) {
+        (Some(cache), Some(key)) => cache.get(key).await,
+        _ => None,
+    };
+    #[cfg(feature = "validation_cache")]
+    let is_cached = cached.is_some();
+    #[cfg(feature = "validation_cache")]
+    let (cached_document, cached_result, plan) = match cached {
+        Some(cached) => (
+            Some(cached.document),
+            Some(cached.validation_result),
+            cached.plan,
+        ),
+        None => (None, None, None),
+    };
+    #[cfg(not(feature = "validation_cache"))]
+    let (cached_document, cached_result, plan) = (None::<Arc<ExecutableDocument>>, None, None);
+
+    let document = {
         let query = &request.query;
         let parsed_doc = request.parsed_query.take();
         let fut_parse = async move {
+            if let Some(doc) = cached_document {
+                // the document is rewritten for the variables of the request
+                return Ok(Arc::unwrap_or_clone(doc));
+            }
             let doc = match parsed_doc {
                 Some(parsed_doc) => parsed_doc,
-                None => parse_query(query)?,
+                None => parse_query_with_options(query, schema_env.parse_options)?,
             };
             check_recursive_depth(&doc, recursive_depth)?;
-            if let Some(max_directives) = max_directives {
+            if let Some(max_directives) = limits.max_directives {
                 check_max_directives(&doc, max_directives)?;
             }
+            check_aliases_and_root_fields(&doc, &limits)?;
             Ok(doc)
         };
         futures_util::pin_mut!(fut_parse);
@@ -883,22 +1290,58 @@ pub(crate) async fn prepare_request(
     };
 
     // check rules
+    #[cfg(feature = "validation_cache")]
+    let mut reads_request = true;
     let validation_result = {
         let validation_fut = async {
-            check_rules(
-                registry,
+            if let Some(validation_result) = cached_result {
+                check_variables(&schema_env.registry, &document, &request, validation_mode)?;
+                return Ok(validation_result);
+            }
+            let (validation_result, _reads) = check_rules(
+                &schema_env.registry,
                 &document,
-                Some(&request.variables),
-                request.operation_name.as_deref(),
+                &request,
                 validation_mode,
+                &schema_env.validation_rules,
                 complexity,
                 depth,
-            )
+            )?;
+            #[cfg(feature = "validation_cache")]
+            {
+                reads_request = _reads;
+            }
+            Ok(validation_result)
         };
         futures_util::pin_mut!(validation_fut);
         extensions.validation(&mut validation_fut).await?
     };
 
+    // a validation depending on the variables or the batch cannot be reused
+    #[cfg(feature = "validation_cache")]
+    let plan = match (&schema_env.validation_cache, cache_key) {
+        (Some(cache), Some(key)) if !is_cached && !reads_request => {
+            let plan = if schema_env.compile_operations {
+                ExecutionPlan::new(schema_env, &document, request.operation_name.as_deref())
+                    .map(Arc::new)
+            } else {
+                None
+            };
+            cache
+                .set(
+                    key,
+                    ValidatedDocument {
+                        document: Arc::new(document.clone()),
+                        validation_result,
+                        plan: plan.clone(),
+                    },
+                )
+                .await;
+            plan
+        }
+        _ => plan,
+    };
+
     let operation = if let Some(operation_name) = &request.operation_name {
         match document.operations {
             DocumentOperations::Single(_) => None,
@@ -926,15 +1369,16 @@ pub(crate) async fn prepare_request(
         }
     };
 
-    let (operation_name, mut operation) = operation.map_err(|err| vec![err])?;
+    let (operation_name, operation) = operation.map_err(|err| vec![err])?;
 
-    // remove skipped fields
-    for fragment in document.fragments.values_mut() {
-        remove_skipped_selection(&mut fragment.node.selection_set.node, &request.variables);
-    }
-    remove_skipped_selection(&mut operation.node.selection_set.node, &request.variables);
+    #[cfg(feature = "response_cache")]
+    let records = {
+        let records = Arc::<Mutex<crate::context::ResolveRecords>>::default();
+        extensions.attach_records(records.clone());
+        records
+    };
 
-    let env = QueryEnvInner {
+    let mut env = QueryEnv::new(QueryEnvInner {
         extensions,
         variables: request.variables,
         operation_name,
@@ -946,6 +1390,24 @@ pub(crate) async fn prepare_request(
         http_headers: Default::default(),
         introspection_mode: request.introspection_mode,
         errors: Default::default(),
-    };
-    Ok((QueryEnv::new(env), validation_result.cache_control))
+        cache_control: Mutex::new(validation_result.cache_control),
+        plan,
+        #[cfg(feature = "response_cache")]
+        records,
+    });
+
+    apply_variable_directives(schema_env, &mut env).map_err(|err| vec![err])?;
+
+    // remove skipped fields
+    {
+        let env = env.get_mut();
+        for fragment in env.fragments.values_mut() {
+            remove_skipped_selection(&mut fragment.node.selection_set.node, &env.variables);
+        }
+        remove_skipped_selection(&mut env.operation.node.selection_set.node, &env.variables);
+    }
+
+    apply_selection_directives(schema_env, &mut env).map_err(|err| vec![err])?;
+
+    Ok(env)
 }
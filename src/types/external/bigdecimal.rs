@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// Implement the BigDecimal scalar
+///
+/// The output is a string so that no precision is lost. Both strings and
+/// numbers are accepted as input.
+#[Scalar(
+    internal,
+    name = "BigDecimal",
+    specified_by_url = "https://docs.rs/bigdecimal"
+)]
+impl ScalarType for BigDecimal {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => BigDecimal::from_str(s).map_err(|err| {
+                InputValueError::custom(format!("invalid decimal \"{}\": {}", s, err))
+            }),
+            Value::Number(n) => {
+                BigDecimal::from_str(&n.to_string()).map_err(InputValueError::custom)
+            }
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use crate::{ScalarType, Value};
+
+    #[test]
+    fn test_bigdecimal() {
+        let n = "123456789012345678901234567890.000000000000000000001";
+        assert_eq!(
+            BigDecimal::parse(Value::String(n.to_string())).unwrap(),
+            BigDecimal::from_str(n).unwrap()
+        );
+        assert_eq!(
+            BigDecimal::parse(Value::from(1.25)).unwrap(),
+            BigDecimal::from_str("1.25").unwrap()
+        );
+        assert!(BigDecimal::parse(Value::String("1.2.3".to_string())).is_err());
+        assert_eq!(
+            BigDecimal::from_str(n).unwrap().to_value(),
+            Value::String(n.to_string())
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// Implement the Int128 scalar
+///
+/// JSON numbers cannot represent every 128-bit integer, so the output is a
+/// string of decimal digits. Both strings and integer numbers are accepted
+/// as input.
+#[Scalar(
+    internal,
+    name = "Int128",
+    specified_by_url = "https://doc.rust-lang.org/std/primitive.i128.html"
+)]
+impl ScalarType for i128 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::Number(n) => {
+                if let Some(n) = n.as_i64() {
+                    Ok(n as Self)
+                } else if let Some(n) = n.as_u64() {
+                    Ok(n as Self)
+                } else {
+                    Err(InputValueError::from("Only integers are accepted."))
+                }
+            }
+            Value::String(s) => s.parse().map_err(|_| {
+                InputValueError::custom(format!(
+                    "\"{}\" is not an integer from {} to {}",
+                    s,
+                    Self::MIN,
+                    Self::MAX
+                ))
+            }),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::Number(n) => n.is_i64() || n.is_u64(),
+            Value::String(s) => s.parse::<Self>().is_ok(),
+            _ => false,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+/// Implement the UInt128 scalar
+///
+/// JSON numbers cannot represent every 128-bit integer, so the output is a
+/// string of decimal digits. Both strings and non-negative integer numbers
+/// are accepted as input.
+#[Scalar(
+    internal,
+    name = "UInt128",
+    specified_by_url = "https://doc.rust-lang.org/std/primitive.u128.html"
+)]
+impl ScalarType for u128 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::Number(n) => n.as_u64().map(Self::from).ok_or_else(|| {
+                InputValueError::from(format!(
+                    "Only integers from 0 to {} are accepted.",
+                    Self::MAX
+                ))
+            }),
+            Value::String(s) => s.parse().map_err(|_| {
+                InputValueError::custom(format!(
+                    "\"{}\" is not an integer from 0 to {}",
+                    s,
+                    Self::MAX
+                ))
+            }),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::Number(n) => n.is_u64(),
+            Value::String(s) => s.parse::<Self>().is_ok(),
+            _ => false,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ScalarType, Value};
+
+    #[test]
+    fn test_int128() {
+        assert_eq!(i128::parse(Value::from(-5)).unwrap(), -5);
+        assert_eq!(
+            i128::parse(Value::from(u64::MAX)).unwrap(),
+            u64::MAX as i128
+        );
+        assert_eq!(
+            i128::parse(Value::String(i128::MIN.to_string())).unwrap(),
+            i128::MIN
+        );
+        assert!(i128::parse(Value::from(1.5)).is_err());
+        assert!(i128::parse(Value::String(format!("{}0", i128::MAX))).is_err());
+        assert_eq!(
+            i128::MAX.to_value(),
+            Value::String("170141183460469231731687303715884105727".to_string())
+        );
+
+        assert_eq!(
+            u128::parse(Value::String(u128::MAX.to_string())).unwrap(),
+            u128::MAX
+        );
+        assert_eq!(u128::parse(Value::from(7)).unwrap(), 7);
+        assert!(u128::parse(Value::from(-1)).is_err());
+        assert!(u128::parse(Value::String("-1".to_string())).is_err());
+        assert!(!u128::is_valid(&Value::from(-1)));
+        assert!(u128::is_valid(&Value::String("1".to_string())));
+    }
+}
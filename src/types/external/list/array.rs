@@ -1,9 +1,11 @@
 use std::borrow::Cow;
 
-use super::wrap_semantic_nullability_in_list;
+use futures_util::future::BoxFuture;
+
+use super::{validate_list_async, wrap_semantic_nullability_in_list};
 use crate::{
-    ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType, Positioned,
-    ServerResult, Value, parser::types::Field, registry, resolver_utils::resolve_list,
+    Context, ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType,
+    Positioned, ServerResult, Value, parser::types::Field, registry, resolver_utils::resolve_list,
 };
 
 impl<T: InputType, const N: usize> InputType for [T; N] {
@@ -26,7 +28,10 @@ impl<T: InputType, const N: usize> InputType for [T; N] {
         if let Some(Value::List(values)) = value {
             let items: Vec<T> = values
                 .into_iter()
-                .map(|value| InputType::parse(Some(value)))
+                .enumerate()
+                .map(|(idx, value)| {
+                    InputType::parse(Some(value)).map_err(|err| err.prefix_input_index(idx))
+                })
                 .collect::<Result<_, _>>()
                 .map_err(InputValueError::propagate)?;
             let len = items.len();
@@ -51,6 +56,13 @@ impl<T: InputType, const N: usize> InputType for [T; N] {
     fn as_raw_value(&self) -> Option<&Self::RawValueType> {
         Some(self)
     }
+
+    fn validate_async<'a>(
+        &'a self,
+        ctx: &'a Context<'_>,
+    ) -> Option<BoxFuture<'a, Result<(), InputValueError<Self>>>> {
+        validate_list_async(self.iter(), ctx)
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -1,9 +1,12 @@
 use std::{borrow::Cow, collections::HashSet, hash::Hash};
 
-use super::wrap_semantic_nullability_in_list;
+use futures_util::future::BoxFuture;
+
+use super::{validate_list_async, wrap_semantic_nullability_in_list};
 use crate::{
-    ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType, Positioned,
-    Result, ServerResult, Value, parser::types::Field, registry, resolver_utils::resolve_list,
+    Context, ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType,
+    Positioned, Result, ServerResult, Value, parser::types::Field, registry,
+    resolver_utils::resolve_list,
 };
 
 impl<T: InputType + Hash + Eq> InputType for HashSet<T> {
@@ -26,7 +29,10 @@ impl<T: InputType + Hash + Eq> InputType for HashSet<T> {
         match value.unwrap_or_default() {
             Value::List(values) => values
                 .into_iter()
-                .map(|value| InputType::parse(Some(value)))
+                .enumerate()
+                .map(|(idx, value)| {
+                    InputType::parse(Some(value)).map_err(|err| err.prefix_input_index(idx))
+                })
                 .collect::<Result<_, _>>()
                 .map_err(InputValueError::propagate),
             value => Ok({
@@ -44,6 +50,13 @@ impl<T: InputType + Hash + Eq> InputType for HashSet<T> {
     fn as_raw_value(&self) -> Option<&Self::RawValueType> {
         Some(self)
     }
+
+    fn validate_async<'a>(
+        &'a self,
+        ctx: &'a Context<'_>,
+    ) -> Option<BoxFuture<'a, Result<(), InputValueError<Self>>>> {
+        validate_list_async(self.iter(), ctx)
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -1,4 +1,6 @@
-use crate::registry::SemanticNullability;
+use futures_util::{FutureExt, future::BoxFuture};
+
+use crate::{Context, InputType, InputValueError, registry::SemanticNullability};
 
 mod array;
 mod btree_set;
@@ -22,3 +24,28 @@ fn wrap_semantic_nullability_in_list(
         semantic_nullability
     }
 }
+
+/// Runs the asynchronous validators of the items of a list one after another.
+fn validate_list_async<'a, T: InputType + 'a, L: InputType>(
+    items: impl Iterator<Item = &'a T>,
+    ctx: &'a Context<'_>,
+) -> Option<BoxFuture<'a, Result<(), InputValueError<L>>>> {
+    let futures = items
+        .enumerate()
+        .filter_map(|(idx, item)| Some((idx, item.validate_async(ctx)?)))
+        .collect::<Vec<_>>();
+    if futures.is_empty() {
+        return None;
+    }
+    Some(
+        async move {
+            for (idx, fut) in futures {
+                fut.await
+                    .map_err(|err| err.prefix_input_index(idx))
+                    .map_err(InputValueError::propagate)?;
+            }
+            Ok(())
+        }
+        .boxed(),
+    )
+}
@@ -1,9 +1,11 @@
 use std::{borrow::Cow, sync::Arc};
 
-use super::wrap_semantic_nullability_in_list;
+use futures_util::future::BoxFuture;
+
+use super::{validate_list_async, wrap_semantic_nullability_in_list};
 use crate::{
-    ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType, Positioned,
-    ServerResult, Value, parser::types::Field, registry, resolver_utils::resolve_list,
+    Context, ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType,
+    Positioned, ServerResult, Value, parser::types::Field, registry, resolver_utils::resolve_list,
 };
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -91,7 +93,10 @@ macro_rules! impl_input_slice_for_smart_ptr {
                 match value.unwrap_or_default() {
                     Value::List(values) => values
                         .into_iter()
-                        .map(|value| InputType::parse(Some(value)))
+                        .enumerate()
+                        .map(|(idx, value)| {
+                            InputType::parse(Some(value)).map_err(|err| err.prefix_input_index(idx))
+                        })
                         .collect::<Result<_, _>>()
                         .map_err(InputValueError::propagate),
                     value => {
@@ -113,6 +118,13 @@ macro_rules! impl_input_slice_for_smart_ptr {
             fn as_raw_value(&self) -> Option<&Self::RawValueType> {
                 Some(self)
             }
+
+            fn validate_async<'a>(
+                &'a self,
+                ctx: &'a Context<'_>,
+            ) -> Option<BoxFuture<'a, Result<(), InputValueError<Self>>>> {
+                validate_list_async(self.iter(), ctx)
+            }
         }
     };
 }
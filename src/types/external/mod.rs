@@ -12,6 +12,8 @@ mod non_zero_integers;
 mod optional;
 mod string;
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "chrono-tz")]
 mod chrono_tz;
 #[cfg(feature = "chrono")]
@@ -20,12 +22,20 @@ mod datetime;
 mod decimal;
 #[cfg(feature = "chrono-duration")]
 mod duration;
+#[cfg(feature = "int128")]
+mod int128;
 #[cfg(feature = "jiff")]
 mod jiff;
 #[cfg(feature = "chrono")]
 mod naive_time;
 #[cfg(feature = "secrecy")]
 mod secrecy;
+#[cfg(feature = "std-duration")]
+mod std_duration;
+#[cfg(feature = "std-net")]
+mod std_net;
+#[cfg(feature = "std-system-time")]
+mod std_system_time;
 #[cfg(feature = "time")]
 mod time_date;
 #[cfg(feature = "time")]
@@ -1,8 +1,10 @@
 use std::borrow::Cow;
 
+use futures_util::{FutureExt, future::BoxFuture};
+
 use crate::{
-    ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType, Positioned,
-    ServerResult, Value, parser::types::Field, registry,
+    Context, ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType,
+    Positioned, ServerResult, Value, parser::types::Field, registry,
 };
 
 impl<T: InputType> InputType for Option<T> {
@@ -43,6 +45,17 @@ impl<T: InputType> InputType for Option<T> {
             None => None,
         }
     }
+
+    fn validate_async<'a>(
+        &'a self,
+        ctx: &'a Context<'_>,
+    ) -> Option<BoxFuture<'a, Result<(), InputValueError<Self>>>> {
+        let fut = self.as_ref()?.validate_async(ctx)?;
+        Some(
+            fut.map(|res| res.map_err(InputValueError::propagate))
+                .boxed(),
+        )
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
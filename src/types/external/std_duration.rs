@@ -0,0 +1,67 @@
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// Implement the Duration scalar for `std::time::Duration`
+///
+/// The input is a string in ISO8601 duration format, the output is a string
+/// in the `PTnS` form of that format, e.g. `PT1.5S`.
+#[Scalar(
+    internal,
+    name = "Duration",
+    specified_by_url = "https://en.wikipedia.org/wiki/ISO_8601#Durations"
+)]
+impl ScalarType for Duration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => Ok(Duration::from(iso8601::Duration::from_str(s).map_err(
+                |err| {
+                    InputValueError::custom(format!("invalid ISO8601 duration \"{}\": {}", s, err))
+                },
+            )?)),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let mut s = format!("PT{}", self.as_secs());
+        let nanos = self.subsec_nanos();
+        if nanos > 0 {
+            write!(s, ".{:09}", nanos).unwrap();
+            s.truncate(s.trim_end_matches('0').len());
+        }
+        s.push('S');
+        Value::String(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{ScalarType, Value};
+
+    #[test]
+    fn test_std_duration() {
+        assert_eq!(
+            Duration::parse(Value::String("PT1M30.5S".to_string())).unwrap(),
+            Duration::from_millis(90_500)
+        );
+        assert_eq!(
+            Duration::parse(Value::String("P1DT1H".to_string())).unwrap(),
+            Duration::from_secs(25 * 3600)
+        );
+        assert!(Duration::parse(Value::String("1 minute".to_string())).is_err());
+        assert!(Duration::parse(Value::from(60)).is_err());
+
+        assert_eq!(Duration::ZERO.to_value(), Value::String("PT0S".to_string()));
+        assert_eq!(
+            Duration::from_millis(90_500).to_value(),
+            Value::String("PT90.5S".to_string())
+        );
+        assert_eq!(
+            Duration::new(1, 1).to_value(),
+            Value::String("PT1.000000001S".to_string())
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// Implement the IPAddress scalar
+///
+/// The input/output is a string containing an IPv4 address in dotted decimal
+/// notation or an IPv6 address in the textual representation of RFC 4291.
+#[Scalar(
+    internal,
+    name = "IPAddress",
+    specified_by_url = "https://datatracker.ietf.org/doc/html/rfc4291#section-2.2"
+)]
+impl ScalarType for IpAddr {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s
+                .parse()
+                .map_err(|_| InputValueError::custom(format!("invalid IP address \"{}\"", s))),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+/// Implement the IPv4Address scalar
+///
+/// The input/output is a string containing an IPv4 address in dotted decimal
+/// notation.
+#[Scalar(
+    internal,
+    name = "IPv4Address",
+    specified_by_url = "https://datatracker.ietf.org/doc/html/rfc791"
+)]
+impl ScalarType for Ipv4Addr {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s
+                .parse()
+                .map_err(|_| InputValueError::custom(format!("invalid IPv4 address \"{}\"", s))),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+/// Implement the IPv6Address scalar
+///
+/// The input/output is a string containing an IPv6 address in the textual
+/// representation of RFC 4291.
+#[Scalar(
+    internal,
+    name = "IPv6Address",
+    specified_by_url = "https://datatracker.ietf.org/doc/html/rfc4291#section-2.2"
+)]
+impl ScalarType for Ipv6Addr {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s
+                .parse()
+                .map_err(|_| InputValueError::custom(format!("invalid IPv6 address \"{}\"", s))),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::{ScalarType, Value};
+
+    #[test]
+    fn test_ip_addr() {
+        assert_eq!(
+            IpAddr::parse(Value::String("127.0.0.1".to_string())).unwrap(),
+            IpAddr::V4(Ipv4Addr::LOCALHOST)
+        );
+        assert_eq!(
+            IpAddr::parse(Value::String("::1".to_string())).unwrap(),
+            IpAddr::V6(Ipv6Addr::LOCALHOST)
+        );
+        assert_eq!(
+            IpAddr::parse(Value::String("127.0.0.256".to_string()))
+                .unwrap_err()
+                .into_server_error(Default::default())
+                .message,
+            r#"Failed to parse "IPAddress": invalid IP address "127.0.0.256""#
+        );
+        assert!(Ipv4Addr::parse(Value::String("::1".to_string())).is_err());
+        assert!(Ipv6Addr::parse(Value::String("127.0.0.1".to_string())).is_err());
+        assert_eq!(
+            Ipv6Addr::LOCALHOST.to_value(),
+            Value::String("::1".to_string())
+        );
+    }
+}
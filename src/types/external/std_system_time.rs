@@ -0,0 +1,147 @@
+use std::time::SystemTime;
+
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// Implement the DateTime scalar for `std::time::SystemTime`
+///
+/// The input is a string in RFC3339 format with any offset, the output is a
+/// string in RFC3339 format in UTC.
+#[Scalar(
+    internal,
+    name = "DateTime",
+    specified_by_url = "https://datatracker.ietf.org/doc/html/rfc3339"
+)]
+impl ScalarType for SystemTime {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => OffsetDateTime::parse(s, &Rfc3339)
+                .map(SystemTime::from)
+                .map_err(|err| {
+                    InputValueError::custom(format!("invalid RFC3339 timestamp \"{}\": {}", s, err))
+                }),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(
+            OffsetDateTime::from(*self)
+                .format(&Rfc3339)
+                .unwrap_or_else(|e| panic!("Failed to format `SystemTime`: {}", e)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use crate::{ScalarType, Value};
+
+    fn parse(s: &str) -> SystemTime {
+        SystemTime::parse(Value::String(s.to_string())).unwrap()
+    }
+
+    fn format(time: SystemTime) -> String {
+        match time.to_value() {
+            Value::String(s) => s,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_system_time_parse() {
+        let cases = [
+            ("1970-01-01T00:00:00Z", UNIX_EPOCH),
+            // leap years
+            (
+                "2024-02-29T00:00:00Z",
+                UNIX_EPOCH + Duration::from_secs(1_709_164_800),
+            ),
+            (
+                "2000-02-29T00:00:00Z",
+                UNIX_EPOCH + Duration::from_secs(951_782_400),
+            ),
+            // offsets
+            (
+                "2024-02-29T12:30:45+02:00",
+                UNIX_EPOCH + Duration::from_secs(1_709_202_645),
+            ),
+            (
+                "2024-02-28T18:30:00-05:30",
+                UNIX_EPOCH + Duration::from_secs(1_709_164_800),
+            ),
+            // before 1970
+            ("1969-12-31T23:59:59Z", UNIX_EPOCH - Duration::from_secs(1)),
+            (
+                "1900-01-01T00:00:00Z",
+                UNIX_EPOCH - Duration::from_secs(2_208_988_800),
+            ),
+            // fractional seconds
+            (
+                "1970-01-01T00:00:00.5Z",
+                UNIX_EPOCH + Duration::from_millis(500),
+            ),
+            (
+                "1970-01-01T00:00:00.000000001Z",
+                UNIX_EPOCH + Duration::from_nanos(1),
+            ),
+            (
+                "1969-12-31T23:59:59.25Z",
+                UNIX_EPOCH - Duration::from_millis(750),
+            ),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(parse(value), expected, "{}", value);
+        }
+
+        for value in [
+            "2023-02-29T00:00:00Z",
+            "1900-02-29T00:00:00Z",
+            "2024-13-01T00:00:00Z",
+            "2024-01-01T24:00:00Z",
+            "2024-01-01T00:00:00",
+            "2024-01-01T00:00:00+0100",
+            "2024-01-01",
+            "24-01-01T00:00:00Z",
+        ] {
+            assert!(
+                SystemTime::parse(Value::String(value.to_string())).is_err(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_system_time_to_value() {
+        let cases = [
+            (UNIX_EPOCH, "1970-01-01T00:00:00Z"),
+            (
+                UNIX_EPOCH + Duration::from_secs(951_782_400),
+                "2000-02-29T00:00:00Z",
+            ),
+            (
+                UNIX_EPOCH + Duration::from_millis(1_709_202_645_500),
+                "2024-02-29T10:30:45.5Z",
+            ),
+            (
+                UNIX_EPOCH - Duration::from_millis(750),
+                "1969-12-31T23:59:59.25Z",
+            ),
+            (
+                UNIX_EPOCH - Duration::from_secs(2_208_988_800),
+                "1900-01-01T00:00:00Z",
+            ),
+            (
+                UNIX_EPOCH + Duration::from_nanos(1),
+                "1970-01-01T00:00:00.000000001Z",
+            ),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(format(value), expected);
+        }
+    }
+}
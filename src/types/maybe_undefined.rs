@@ -1,8 +1,9 @@
 use std::{borrow::Cow, ops::Deref};
 
+use futures_util::{FutureExt, future::BoxFuture};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{InputType, InputValueError, InputValueResult, Value, registry};
+use crate::{Context, InputType, InputValueError, InputValueResult, Value, registry};
 
 /// Similar to `Option`, but it has three states, `undefined`, `null` and `x`.
 ///
@@ -238,6 +239,20 @@ impl<T: InputType> InputType for MaybeUndefined<T> {
             None
         }
     }
+
+    fn validate_async<'a>(
+        &'a self,
+        ctx: &'a Context<'_>,
+    ) -> Option<BoxFuture<'a, Result<(), InputValueError<Self>>>> {
+        let MaybeUndefined::Value(value) = self else {
+            return None;
+        };
+        let fut = value.validate_async(ctx)?;
+        Some(
+            fut.map(|res| res.map_err(InputValueError::propagate))
+                .boxed(),
+        )
+    }
 }
 
 impl<T, E> MaybeUndefined<Result<T, E>> {
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    ExecutionPlan, IntrospectionMode, SDLExportOptions, ValidationResult,
+    parser::types::ExecutableDocument, registry::Registry,
+};
+
+/// The key of a validated document in a [`ValidationCacheStorage`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationCacheKey {
+    /// The SHA-256 hash of the SDL of the schema, in hexadecimal.
+    ///
+    /// It keeps the documents validated against different schemas apart when
+    /// a storage is shared, for example by the schemas published by a
+    /// [`SwappableSchema`](crate::dynamic::SwappableSchema). Custom validation
+    /// rules and complexity functions are not part of the SDL, so the schemas
+    /// that differ only by them must not share a storage.
+    pub schema_fingerprint: String,
+    /// The SHA-256 hash of the query source, in hexadecimal.
+    pub query_hash: String,
+    /// The name of the operation to execute.
+    pub operation_name: Option<String>,
+    /// The introspection mode of the request.
+    pub introspection_mode: IntrospectionMode,
+}
+
+/// A parsed document that has passed the validation, with the result of the
+/// validation.
+#[derive(Debug, Clone)]
+pub struct ValidatedDocument {
+    /// The parsed document.
+    pub document: Arc<ExecutableDocument>,
+    /// The complexity, depth and cache control computed by the validation.
+    pub validation_result: ValidationResult,
+    /// The execution plan of the operation, see
+    /// [`SchemaBuilder::enable_compiled_operations`](crate::SchemaBuilder::enable_compiled_operations).
+    pub plan: Option<Arc<ExecutionPlan>>,
+}
+
+/// Cache storage for validated documents, see
+/// [`SchemaBuilder::validation_cache`](crate::SchemaBuilder::validation_cache).
+#[async_trait::async_trait]
+pub trait ValidationCacheStorage: Send + Sync + 'static {
+    /// Load the validated document by `key`.
+    async fn get(&self, key: &ValidationCacheKey) -> Option<ValidatedDocument>;
+
+    /// Save the validated document by `key`.
+    async fn set(&self, key: ValidationCacheKey, document: ValidatedDocument);
+}
+
+/// Memory-based LRU cache of validated documents.
+///
+/// ```
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .validation_cache(LruValidationCache::new(1024))
+///     .finish();
+/// ```
+pub struct LruValidationCache(
+    std::sync::Mutex<lru::LruCache<ValidationCacheKey, ValidatedDocument>>,
+);
+
+impl LruValidationCache {
+    /// Creates a new LRU Cache that holds at most `cap` documents.
+    pub fn new(cap: usize) -> Self {
+        Self(std::sync::Mutex::new(lru::LruCache::new(
+            std::num::NonZeroUsize::new(cap).unwrap_or(std::num::NonZeroUsize::MIN),
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl ValidationCacheStorage for LruValidationCache {
+    async fn get(&self, key: &ValidationCacheKey) -> Option<ValidatedDocument> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+
+    async fn set(&self, key: ValidationCacheKey, document: ValidatedDocument) {
+        self.0.lock().unwrap().put(key, document);
+    }
+}
+
+pub(crate) fn query_hash(query: &str) -> String {
+    format!("{:x}", Sha256::digest(query.as_bytes()))
+}
+
+pub(crate) fn schema_fingerprint(registry: &Registry) -> String {
+    let sdl = registry.export_sdl(
+        SDLExportOptions::new()
+            .include_specified_by()
+            .include_cache_control(),
+    );
+    query_hash(&sdl)
+}
@@ -0,0 +1,471 @@
+//! Transforming executable documents.
+//!
+//! A [`Fold`] takes the ownership of every node of an [`ExecutableDocument`]
+//! and returns the node that replaces it, which is the mutable counterpart of
+//! a [`Visitor`](crate::Visitor). Every method of the trait calls the function
+//! of this module with the same name by default, which folds the children of
+//! the node, so an implementation overrides the methods of the nodes it
+//! rewrites and calls these functions to keep folding the children.
+//!
+//! ```
+//! use async_graphql::{
+//!     fold::{self, Fold, FoldContext},
+//!     parser::{parse_query, types::Selection},
+//!     *,
+//! };
+//!
+//! /// Removes the fields that are deprecated.
+//! struct RemoveDeprecatedFields;
+//!
+//! impl<'a> Fold<'a> for RemoveDeprecatedFields {
+//!     fn fold_selection(
+//!         &mut self,
+//!         ctx: &mut FoldContext<'a>,
+//!         selection: Positioned<Selection>,
+//!     ) -> Option<Positioned<Selection>> {
+//!         if let Selection::Field(field) = &selection.node
+//!             && let Some(definition) = ctx.field_definition(&field.node.name.node)
+//!             && definition.deprecation.is_deprecated()
+//!         {
+//!             return None;
+//!         }
+//!         fold::fold_selection(self, ctx, selection)
+//!     }
+//! }
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn value(&self) -> i32 {
+//!         10
+//!     }
+//!
+//!     #[graphql(deprecation = "use `value`")]
+//!     async fn old_value(&self) -> i32 {
+//!         10
+//!     }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! let doc = parse_query("{ value oldValue }").unwrap();
+//! let mut ctx = FoldContext::new(schema.registry());
+//! let doc = RemoveDeprecatedFields.fold_document(&mut ctx, doc);
+//! assert_eq!(parser::print_query(&doc, Default::default()), "{\n  value\n}\n");
+//! ```
+
+use async_graphql_value::Value;
+
+use crate::{
+    Name, Positioned,
+    parser::types::{
+        Directive, DocumentOperations, ExecutableDocument, Field, FragmentDefinition,
+        FragmentSpread, InlineFragment, OperationDefinition, OperationType, Selection,
+        SelectionSet, VariableDefinition,
+    },
+    registry::{MetaField, MetaType, MetaTypeName, Registry},
+};
+
+/// The context of a [`Fold`], with the type information of the folded nodes.
+pub struct FoldContext<'a> {
+    registry: &'a Registry,
+    type_stack: Vec<Option<&'a MetaType>>,
+    input_type: Vec<Option<MetaTypeName<'a>>>,
+}
+
+impl<'a> FoldContext<'a> {
+    /// Create a context for folding a document with the types of a registry.
+    pub fn new(registry: &'a Registry) -> Self {
+        Self {
+            registry,
+            type_stack: Vec::new(),
+            input_type: Vec::new(),
+        }
+    }
+
+    /// Returns the registry of the schema.
+    pub fn registry(&self) -> &'a Registry {
+        self.registry
+    }
+
+    /// Returns the type of the parent of the current node, such as the type
+    /// that defines the current field.
+    pub fn parent_type(&self) -> Option<&'a MetaType> {
+        if self.type_stack.len() >= 2 {
+            self.type_stack
+                .get(self.type_stack.len() - 2)
+                .copied()
+                .flatten()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type of the current node, such as the type of the current
+    /// field, or the type condition of the current fragment.
+    pub fn current_type(&self) -> Option<&'a MetaType> {
+        self.type_stack.last().copied().flatten()
+    }
+
+    /// Returns the definition of a field of the current type, such as the
+    /// definition of a field in [`Fold::fold_selection`], or the definition of
+    /// a field of the parent type in [`Fold::fold_field`].
+    pub fn field_definition(&self, name: &str) -> Option<&'a MetaField> {
+        self.current_type()?.field_by_name(name)
+    }
+
+    /// Returns the type of the current argument.
+    pub fn input_type(&self) -> Option<MetaTypeName<'a>> {
+        self.input_type.last().copied().flatten()
+    }
+
+    fn with_type<T>(&mut self, ty: Option<&'a MetaType>, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.type_stack.push(ty);
+        let res = f(self);
+        self.type_stack.pop();
+        res
+    }
+
+    fn with_input_type<T>(
+        &mut self,
+        ty: Option<MetaTypeName<'a>>,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        self.input_type.push(ty);
+        let res = f(self);
+        self.input_type.pop();
+        res
+    }
+}
+
+/// A transformation of an [`ExecutableDocument`], see the [module
+/// documentation](self).
+pub trait Fold<'a> {
+    /// Folds the document.
+    fn fold_document(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        doc: ExecutableDocument,
+    ) -> ExecutableDocument {
+        fold_document(self, ctx, doc)
+    }
+
+    /// Folds an operation definition.
+    fn fold_operation_definition(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        name: Option<&Name>,
+        operation_definition: Positioned<OperationDefinition>,
+    ) -> Positioned<OperationDefinition> {
+        fold_operation_definition(self, ctx, name, operation_definition)
+    }
+
+    /// Folds a fragment definition.
+    fn fold_fragment_definition(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        name: &Name,
+        fragment_definition: Positioned<FragmentDefinition>,
+    ) -> Positioned<FragmentDefinition> {
+        fold_fragment_definition(self, ctx, name, fragment_definition)
+    }
+
+    /// Folds a variable definition, which is removed if `None` is returned.
+    fn fold_variable_definition(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        variable_definition: Positioned<VariableDefinition>,
+    ) -> Option<Positioned<VariableDefinition>> {
+        fold_variable_definition(self, ctx, variable_definition)
+    }
+
+    /// Folds a directive, which is removed if `None` is returned.
+    fn fold_directive(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        directive: Positioned<Directive>,
+    ) -> Option<Positioned<Directive>> {
+        fold_directive(self, ctx, directive)
+    }
+
+    /// Folds an argument of a field or a directive, which is removed if `None`
+    /// is returned.
+    fn fold_argument(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        name: Positioned<Name>,
+        value: Positioned<Value>,
+    ) -> Option<(Positioned<Name>, Positioned<Value>)> {
+        fold_argument(self, ctx, name, value)
+    }
+
+    /// Folds a selection set.
+    fn fold_selection_set(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        selection_set: Positioned<SelectionSet>,
+    ) -> Positioned<SelectionSet> {
+        fold_selection_set(self, ctx, selection_set)
+    }
+
+    /// Folds a selection, which is removed if `None` is returned.
+    fn fold_selection(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        selection: Positioned<Selection>,
+    ) -> Option<Positioned<Selection>> {
+        fold_selection(self, ctx, selection)
+    }
+
+    /// Folds a field.
+    fn fold_field(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        field: Positioned<Field>,
+    ) -> Positioned<Field> {
+        fold_field(self, ctx, field)
+    }
+
+    /// Folds a fragment spread.
+    fn fold_fragment_spread(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        fragment_spread: Positioned<FragmentSpread>,
+    ) -> Positioned<FragmentSpread> {
+        fold_fragment_spread(self, ctx, fragment_spread)
+    }
+
+    /// Folds an inline fragment.
+    fn fold_inline_fragment(
+        &mut self,
+        ctx: &mut FoldContext<'a>,
+        inline_fragment: Positioned<InlineFragment>,
+    ) -> Positioned<InlineFragment> {
+        fold_inline_fragment(self, ctx, inline_fragment)
+    }
+}
+
+/// Folds the fragments and the operations of a document.
+pub fn fold_document<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    doc: ExecutableDocument,
+) -> ExecutableDocument {
+    let fragments = doc
+        .fragments
+        .into_iter()
+        .map(|(name, fragment)| {
+            let ty = ctx
+                .registry
+                .types
+                .get(fragment.node.type_condition.node.on.node.as_str());
+            let fragment =
+                ctx.with_type(ty, |ctx| f.fold_fragment_definition(ctx, &name, fragment));
+            (name, fragment)
+        })
+        .collect();
+
+    let operations = match doc.operations {
+        DocumentOperations::Single(operation) => {
+            DocumentOperations::Single(f.fold_operation_definition(ctx, None, operation))
+        }
+        DocumentOperations::Multiple(operations) => DocumentOperations::Multiple(
+            operations
+                .into_iter()
+                .map(|(name, operation)| {
+                    let operation = f.fold_operation_definition(ctx, Some(&name), operation);
+                    (name, operation)
+                })
+                .collect(),
+        ),
+    };
+
+    ExecutableDocument {
+        operations,
+        fragments,
+    }
+}
+
+/// Folds the variable definitions, the directives and the selection set of an
+/// operation definition.
+pub fn fold_operation_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    _name: Option<&Name>,
+    mut operation_definition: Positioned<OperationDefinition>,
+) -> Positioned<OperationDefinition> {
+    let root_name = match &operation_definition.node.ty {
+        OperationType::Query => Some(&*ctx.registry.query_type),
+        OperationType::Mutation => ctx.registry.mutation_type.as_deref(),
+        OperationType::Subscription => ctx.registry.subscription_type.as_deref(),
+    };
+    let ty = root_name.and_then(|name| ctx.registry.types.get(name));
+    ctx.with_type(ty, |ctx| {
+        let operation = &mut operation_definition.node;
+        operation.variable_definitions = std::mem::take(&mut operation.variable_definitions)
+            .into_iter()
+            .filter_map(|definition| f.fold_variable_definition(ctx, definition))
+            .collect();
+        operation.directives = fold_directives(f, ctx, std::mem::take(&mut operation.directives));
+        operation.selection_set =
+            f.fold_selection_set(ctx, std::mem::take(&mut operation.selection_set));
+    });
+    operation_definition
+}
+
+/// Folds the directives and the selection set of a fragment definition.
+pub fn fold_fragment_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    _name: &Name,
+    mut fragment_definition: Positioned<FragmentDefinition>,
+) -> Positioned<FragmentDefinition> {
+    let fragment = &mut fragment_definition.node;
+    fragment.directives = fold_directives(f, ctx, std::mem::take(&mut fragment.directives));
+    fragment.selection_set = f.fold_selection_set(ctx, std::mem::take(&mut fragment.selection_set));
+    fragment_definition
+}
+
+/// Folds the directives of a variable definition.
+pub fn fold_variable_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    mut variable_definition: Positioned<VariableDefinition>,
+) -> Option<Positioned<VariableDefinition>> {
+    let definition = &mut variable_definition.node;
+    definition.directives = fold_directives(f, ctx, std::mem::take(&mut definition.directives));
+    Some(variable_definition)
+}
+
+/// Folds the arguments of a directive.
+pub fn fold_directive<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    mut directive: Positioned<Directive>,
+) -> Option<Positioned<Directive>> {
+    let schema_directive = ctx
+        .registry
+        .directives
+        .get(directive.node.name.node.as_str());
+    directive.node.arguments = std::mem::take(&mut directive.node.arguments)
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let expected_ty = schema_directive
+                .and_then(|schema_directive| schema_directive.args.get(&*name.node))
+                .map(|input_ty| MetaTypeName::create(&input_ty.ty));
+            ctx.with_input_type(expected_ty, |ctx| f.fold_argument(ctx, name, value))
+        })
+        .collect();
+    Some(directive)
+}
+
+/// Returns the argument unchanged.
+pub fn fold_argument<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    _ctx: &mut FoldContext<'a>,
+    name: Positioned<Name>,
+    value: Positioned<Value>,
+) -> Option<(Positioned<Name>, Positioned<Value>)> {
+    Some((name, value))
+}
+
+/// Folds the selections of a selection set.
+pub fn fold_selection_set<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    mut selection_set: Positioned<SelectionSet>,
+) -> Positioned<SelectionSet> {
+    selection_set.node.items = std::mem::take(&mut selection_set.node.items)
+        .into_iter()
+        .filter_map(|selection| f.fold_selection(ctx, selection))
+        .collect();
+    selection_set
+}
+
+/// Folds the field or the fragment of a selection.
+pub fn fold_selection<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    selection: Positioned<Selection>,
+) -> Option<Positioned<Selection>> {
+    Some(selection.map(|selection| match selection {
+        Selection::Field(field) => {
+            let ty = ctx
+                .field_definition(&field.node.name.node)
+                .and_then(|schema_field| ctx.registry.concrete_type_by_name(&schema_field.ty));
+            Selection::Field(ctx.with_type(ty, |ctx| f.fold_field(ctx, field)))
+        }
+        Selection::FragmentSpread(fragment_spread) => {
+            Selection::FragmentSpread(f.fold_fragment_spread(ctx, fragment_spread))
+        }
+        Selection::InlineFragment(inline_fragment) => {
+            let ty = match &inline_fragment.node.type_condition {
+                Some(type_condition) => {
+                    ctx.registry.types.get(type_condition.node.on.node.as_str())
+                }
+                None => ctx.current_type(),
+            };
+            Selection::InlineFragment(
+                ctx.with_type(ty, |ctx| f.fold_inline_fragment(ctx, inline_fragment)),
+            )
+        }
+    }))
+}
+
+/// Folds the arguments, the directives and the selection set of a field.
+pub fn fold_field<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    mut field: Positioned<Field>,
+) -> Positioned<Field> {
+    let schema_field = ctx
+        .parent_type()
+        .and_then(|ty| ty.field_by_name(&field.node.name.node));
+    let node = &mut field.node;
+    node.arguments = std::mem::take(&mut node.arguments)
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let expected_ty = schema_field
+                .and_then(|schema_field| schema_field.args.get(&*name.node))
+                .map(|input_ty| MetaTypeName::create(&input_ty.ty));
+            ctx.with_input_type(expected_ty, |ctx| f.fold_argument(ctx, name, value))
+        })
+        .collect();
+    node.directives = fold_directives(f, ctx, std::mem::take(&mut node.directives));
+    node.selection_set = f.fold_selection_set(ctx, std::mem::take(&mut node.selection_set));
+    field
+}
+
+/// Folds the directives of a fragment spread.
+pub fn fold_fragment_spread<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    mut fragment_spread: Positioned<FragmentSpread>,
+) -> Positioned<FragmentSpread> {
+    let spread = &mut fragment_spread.node;
+    spread.directives = fold_directives(f, ctx, std::mem::take(&mut spread.directives));
+    fragment_spread
+}
+
+/// Folds the directives and the selection set of an inline fragment.
+pub fn fold_inline_fragment<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    mut inline_fragment: Positioned<InlineFragment>,
+) -> Positioned<InlineFragment> {
+    let fragment = &mut inline_fragment.node;
+    fragment.directives = fold_directives(f, ctx, std::mem::take(&mut fragment.directives));
+    fragment.selection_set = f.fold_selection_set(ctx, std::mem::take(&mut fragment.selection_set));
+    inline_fragment
+}
+
+fn fold_directives<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    ctx: &mut FoldContext<'a>,
+    directives: Vec<Positioned<Directive>>,
+) -> Vec<Positioned<Directive>> {
+    directives
+        .into_iter()
+        .filter_map(|directive| f.fold_directive(ctx, directive))
+        .collect()
+}
@@ -2,17 +2,24 @@
 #[macro_use]
 mod test_harness;
 
+#[cfg(feature = "validation_cache")]
+pub(crate) mod cache;
+pub mod fold;
 mod rules;
 mod suggestion;
 mod utils;
 mod visitor;
 mod visitors;
 
-pub use visitor::VisitorContext;
-use visitor::{VisitorNil, visit};
+#[cfg(feature = "validation_cache")]
+pub use cache::{
+    LruValidationCache, ValidatedDocument, ValidationCacheKey, ValidationCacheStorage,
+};
+use visitor::VisitorNil;
+pub use visitor::{VisitMode, Visitor, VisitorContext, visit};
 
 use crate::{
-    CacheControl, ServerError, Variables, parser::types::ExecutableDocument, registry::Registry,
+    CacheControl, Request, ServerError, parser::types::ExecutableDocument, registry::Registry,
 };
 
 /// Validation results.
@@ -28,6 +35,26 @@ pub struct ValidationResult {
     pub depth: usize,
 }
 
+/// A factory for a validation rule, which creates a [`Visitor`] for every
+/// validated document.
+///
+/// It is implemented for the functions returning a visitor, see
+/// [`SchemaBuilder::validation_rule`](crate::SchemaBuilder::validation_rule).
+pub trait ValidationRuleFactory: Send + Sync + 'static {
+    /// Create a rule for validating a document.
+    fn create<'a>(&self) -> Box<dyn Visitor<'a> + 'a>;
+}
+
+impl<F, V> ValidationRuleFactory for F
+where
+    F: Fn() -> V + Send + Sync + 'static,
+    V: for<'a> Visitor<'a> + 'static,
+{
+    fn create<'a>(&self) -> Box<dyn Visitor<'a> + 'a> {
+        Box::new(self())
+    }
+}
+
 /// Validation mode
 #[derive(Copy, Clone, Debug)]
 pub enum ValidationMode {
@@ -39,21 +66,29 @@ pub enum ValidationMode {
     Fast,
 }
 
+/// Validates a document, and returns whether the validation has read the
+/// variables or the batch of the request along with the result.
 pub(crate) fn check_rules(
     registry: &Registry,
     doc: &ExecutableDocument,
-    variables: Option<&Variables>,
-    operation_name: Option<&str>,
+    request: &Request,
     mode: ValidationMode,
+    rules: &[Box<dyn ValidationRuleFactory>],
     limit_complexity: Option<usize>,
     limit_depth: Option<usize>,
-) -> Result<ValidationResult, Vec<ServerError>> {
+) -> Result<(ValidationResult, bool), Vec<ServerError>> {
     let mut cache_control = CacheControl::default();
     let mut complexity = 0;
     let mut depth = 0;
 
-    let mut ctx = VisitorContext::new(registry, doc, variables, operation_name);
-    let errors = match mode {
+    let mut ctx = VisitorContext::new(
+        registry,
+        doc,
+        Some(&request.variables),
+        request.operation_name.as_deref(),
+    );
+    ctx.in_batch = request.in_batch;
+    match mode {
         ValidationMode::Strict => {
             let mut visitor = VisitorNil
                 .with(rules::ArgumentsOfCorrectType::default())
@@ -81,27 +116,27 @@ pub(crate) fn check_rules(
             visit(&mut visitor, &mut ctx, doc);
 
             let mut visitor = VisitorNil
-                .with(visitors::CacheControlCalculate {
-                    cache_control: &mut cache_control,
-                })
+                .with(visitors::CacheControlCalculate::new(&mut cache_control))
                 .with(visitors::ComplexityCalculate::new(&mut complexity))
                 .with(visitors::DepthCalculate::new(&mut depth));
             visit(&mut visitor, &mut ctx, doc);
-            ctx.errors
         }
         ValidationMode::Fast => {
             let mut visitor = VisitorNil
                 .with(rules::NoFragmentCycles::default())
                 .with(rules::UploadFile)
-                .with(visitors::CacheControlCalculate {
-                    cache_control: &mut cache_control,
-                })
+                .with(visitors::CacheControlCalculate::new(&mut cache_control))
                 .with(visitors::ComplexityCalculate::new(&mut complexity))
                 .with(visitors::DepthCalculate::new(&mut depth));
             visit(&mut visitor, &mut ctx, doc);
-            ctx.errors
         }
-    };
+    }
+
+    for rule in rules {
+        visit(rule.create().as_mut(), &mut ctx, doc);
+    }
+    let reads_request = ctx.reads_request.get();
+    let errors = ctx.errors;
 
     // check limit
     if let Some(limit_complexity) = limit_complexity
@@ -120,9 +155,40 @@ pub(crate) fn check_rules(
         return Err(errors.into_iter().map(Into::into).collect());
     }
 
-    Ok(ValidationResult {
-        cache_control,
-        complexity,
-        depth,
-    })
+    Ok((
+        ValidationResult {
+            cache_control,
+            complexity,
+            depth,
+        },
+        reads_request,
+    ))
+}
+
+/// Checks the variables of a request against a document whose validation has
+/// been cached.
+///
+/// The cached validation result does not depend on the variables, so the rules
+/// that check them have to run again for every request.
+pub(crate) fn check_variables(
+    registry: &Registry,
+    doc: &ExecutableDocument,
+    request: &Request,
+    mode: ValidationMode,
+) -> Result<(), Vec<ServerError>> {
+    if matches!(mode, ValidationMode::Fast) || request.variables.is_empty() {
+        return Ok(());
+    }
+
+    let mut ctx = VisitorContext::new(
+        registry,
+        doc,
+        Some(&request.variables),
+        request.operation_name.as_deref(),
+    );
+    visit(&mut rules::ArgumentsOfCorrectType::default(), &mut ctx, doc);
+    if !ctx.errors.is_empty() {
+        return Err(ctx.into_errors());
+    }
+    Ok(())
 }
@@ -68,6 +68,9 @@ impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
                 .into_const_with(|var_name| {
                     // Don't check variables if we're in an unselected operation, since the
                     // variables don't apply to this operation.
+                    // The variables are read without `VisitorContext::variables`, so that the
+                    // result of the validation can be cached regardless of the variables. This
+                    // rule runs again with the variables of every request that hits the cache.
                     (!self.in_unselected_operation)
                         .then_some(ctx.variables)
                         .flatten()
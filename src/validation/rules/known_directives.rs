@@ -3,7 +3,7 @@ use crate::{
     model::__DirectiveLocation,
     parser::types::{
         Directive, Field, FragmentDefinition, FragmentSpread, InlineFragment, OperationDefinition,
-        OperationType,
+        OperationType, VariableDefinition,
     },
     validation::visitor::{Visitor, VisitorContext},
 };
@@ -85,6 +85,23 @@ impl<'a> Visitor<'a> for KnownDirectives {
         }
     }
 
+    fn enter_variable_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        self.location_stack
+            .push(__DirectiveLocation::VARIABLE_DEFINITION);
+    }
+
+    fn exit_variable_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        self.location_stack.pop();
+    }
+
     fn enter_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {
         self.location_stack.push(__DirectiveLocation::FIELD);
     }
@@ -1,9 +1,10 @@
 use std::{
+    cell::Cell,
     collections::HashMap,
     fmt::{self, Display, Formatter},
 };
 
-use async_graphql_value::Value;
+use async_graphql_value::{ConstValue, Value};
 
 use crate::{
     InputType, Name, Pos, Positioned, ServerError, ServerResult, Variables,
@@ -12,14 +13,19 @@ use crate::{
         OperationDefinition, OperationType, Selection, SelectionSet, TypeCondition,
         VariableDefinition,
     },
-    registry::{self, MetaType, MetaTypeName},
+    registry::{self, MetaField, MetaType, MetaTypeName},
 };
 
-#[doc(hidden)]
+/// The context of a [`Visitor`], with the type information of the visited
+/// nodes.
 pub struct VisitorContext<'a> {
     pub(crate) registry: &'a registry::Registry,
     pub(crate) variables: Option<&'a Variables>,
     pub(crate) operation_name: Option<&'a str>,
+    pub(crate) in_batch: bool,
+    /// Whether the variables or the batch of the request have been read, in
+    /// which case the validation result cannot be reused by other requests.
+    pub(crate) reads_request: Cell<bool>,
     pub(crate) errors: Vec<RuleError>,
     type_stack: Vec<Option<&'a registry::MetaType>>,
     input_type: Vec<Option<MetaTypeName<'a>>>,
@@ -27,7 +33,8 @@ pub struct VisitorContext<'a> {
 }
 
 impl<'a> VisitorContext<'a> {
-    pub(crate) fn new(
+    /// Create a context for visiting a document with [`visit`].
+    pub fn new(
         registry: &'a registry::Registry,
         doc: &'a ExecutableDocument,
         variables: Option<&'a Variables>,
@@ -37,6 +44,8 @@ impl<'a> VisitorContext<'a> {
             registry,
             variables,
             operation_name,
+            in_batch: false,
+            reads_request: Cell::new(false),
             errors: Default::default(),
             type_stack: Default::default(),
             input_type: Default::default(),
@@ -44,10 +53,39 @@ impl<'a> VisitorContext<'a> {
         }
     }
 
-    pub(crate) fn report_error<T: Into<String>>(&mut self, locations: Vec<Pos>, msg: T) {
+    /// Returns the registry of the schema.
+    pub fn registry(&self) -> &'a registry::Registry {
+        self.registry
+    }
+
+    /// Returns the variables of the request, which are not available when
+    /// validating a document without executing it.
+    pub fn variables(&self) -> Option<&'a Variables> {
+        self.reads_request.set(true);
+        self.variables
+    }
+
+    /// Returns the name of the operation to execute.
+    pub fn operation_name(&self) -> Option<&'a str> {
+        self.operation_name
+    }
+
+    /// Returns `true` if the request is one of the requests of a batch.
+    pub fn is_batch_request(&self) -> bool {
+        self.reads_request.set(true);
+        self.in_batch
+    }
+
+    /// Reports a validation error at the locations.
+    pub fn report_error<T: Into<String>>(&mut self, locations: Vec<Pos>, msg: T) {
         self.errors.push(RuleError::new(locations, msg));
     }
 
+    /// Converts the reported errors into server errors.
+    pub fn into_errors(self) -> Vec<ServerError> {
+        self.errors.into_iter().map(Into::into).collect()
+    }
+
     pub(crate) fn append_errors(&mut self, errors: Vec<RuleError>) {
         self.errors.extend(errors);
     }
@@ -72,7 +110,9 @@ impl<'a> VisitorContext<'a> {
         self.input_type.pop();
     }
 
-    pub(crate) fn parent_type(&self) -> Option<&'a registry::MetaType> {
+    /// Returns the type of the parent of the current node, such as the type
+    /// that defines the current field.
+    pub fn parent_type(&self) -> Option<&'a registry::MetaType> {
         if self.type_stack.len() >= 2 {
             self.type_stack
                 .get(self.type_stack.len() - 2)
@@ -83,15 +123,30 @@ impl<'a> VisitorContext<'a> {
         }
     }
 
-    pub(crate) fn current_type(&self) -> Option<&'a registry::MetaType> {
+    /// Returns the type of the current node, such as the type of the current
+    /// field, or the type condition of the current fragment.
+    pub fn current_type(&self) -> Option<&'a registry::MetaType> {
         self.type_stack.last().copied().flatten()
     }
 
-    pub(crate) fn is_known_fragment(&self, name: &str) -> bool {
+    /// Returns the definition of a field of the parent type, such as the
+    /// definition of the current field in [`Visitor::enter_field`].
+    pub fn field_definition(&self, name: &str) -> Option<&'a MetaField> {
+        self.parent_type()?.field_by_name(name)
+    }
+
+    /// Returns the type of the current argument.
+    pub fn input_type(&self) -> Option<MetaTypeName<'a>> {
+        self.input_type.last().copied().flatten()
+    }
+
+    /// Returns `true` if the document defines the fragment.
+    pub fn is_known_fragment(&self, name: &str) -> bool {
         self.fragments.contains_key(name)
     }
 
-    pub(crate) fn fragment(&self, name: &str) -> Option<&'a Positioned<FragmentDefinition>> {
+    /// Returns a fragment definition of the document.
+    pub fn fragment(&self, name: &str) -> Option<&'a Positioned<FragmentDefinition>> {
         self.fragments.get(name)
     }
 
@@ -112,194 +167,258 @@ impl<'a> VisitorContext<'a> {
         }
 
         let (pos, value) = match value {
-            Some(value) => {
-                let pos = value.pos;
-                (
-                    pos,
-                    Some(value.node.into_const_with(|name| {
-                        variable_definitions
-                            .iter()
-                            .find(|def| def.node.name.node == name)
-                            .and_then(|def| {
-                                if let Some(variables) = self.variables {
-                                    variables
-                                        .get(&def.node.name.node)
-                                        .or_else(|| def.node.default_value())
-                                } else {
-                                    None
-                                }
-                            })
-                            .cloned()
-                            .ok_or_else(|| {
-                                ServerError::new(
-                                    format!("Variable {} is not defined.", name),
-                                    Some(pos),
-                                )
-                            })
-                    })?),
-                )
-            }
+            Some(value) => (
+                value.pos,
+                Some(self.resolve_value(variable_definitions, value)?),
+            ),
             None => (Pos::default(), None),
         };
 
         T::parse(value).map_err(|e| e.into_server_error(pos))
     }
+
+    /// Resolves the variables used by an argument value.
+    pub(crate) fn resolve_value(
+        &self,
+        variable_definitions: &[Positioned<VariableDefinition>],
+        value: Positioned<Value>,
+    ) -> ServerResult<ConstValue> {
+        let pos = value.pos;
+        value.node.into_const_with(|name| {
+            variable_definitions
+                .iter()
+                .find(|def| def.node.name.node == name)
+                .and_then(|def| {
+                    if let Some(variables) = self.variables() {
+                        variables
+                            .get(&def.node.name.node)
+                            .or_else(|| def.node.default_value())
+                    } else {
+                        None
+                    }
+                })
+                .cloned()
+                .ok_or_else(|| {
+                    ServerError::new(format!("Variable {} is not defined.", name), Some(pos))
+                })
+        })
+    }
 }
 
+/// How the fragments are visited.
 #[derive(Copy, Clone, Eq, PartialEq)]
-pub(crate) enum VisitMode {
+pub enum VisitMode {
+    /// The fragment definitions are visited once, and the fragment spreads do
+    /// not visit the fragments.
     Normal,
+    /// The fragment definitions are not visited, and the selection set of a
+    /// fragment is visited at each of its spreads.
     Inline,
 }
 
-pub(crate) trait Visitor<'a> {
+/// A visitor of an [`ExecutableDocument`], which is called when entering and
+/// exiting every node of the document.
+///
+/// The [`VisitorContext`] gives the type information of the visited nodes, and
+/// collects the errors reported by the visitor. A visitor can be run with
+/// [`visit`], or added to the validation rules of a schema with
+/// [`SchemaBuilder::validation_rule`](crate::SchemaBuilder::validation_rule).
+///
+/// ```
+/// use async_graphql::{parser::types::Field, *};
+///
+/// /// Forbids the fields that are deprecated.
+/// struct NoDeprecatedFields;
+///
+/// impl<'a> Visitor<'a> for NoDeprecatedFields {
+///     fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+///         if let Some(definition) = ctx.field_definition(&field.node.name.node)
+///             && definition.deprecation.is_deprecated()
+///         {
+///             ctx.report_error(
+///                 vec![field.pos],
+///                 format!("Field \"{}\" is deprecated.", field.node.name.node),
+///             );
+///         }
+///     }
+/// }
+/// ```
+#[allow(unused_variables)]
+pub trait Visitor<'a> {
+    /// Returns how the fragments are visited.
     fn mode(&self) -> VisitMode {
         VisitMode::Normal
     }
 
-    fn enter_document(&mut self, _ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {}
-    fn exit_document(&mut self, _ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {}
+    /// Called when entering the document.
+    fn enter_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {}
+    /// Called when exiting the document.
+    fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {}
 
+    /// Called when entering an operation definition.
     fn enter_operation_definition(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _name: Option<&'a Name>,
-        _operation_definition: &'a Positioned<OperationDefinition>,
+        ctx: &mut VisitorContext<'a>,
+        name: Option<&'a Name>,
+        operation_definition: &'a Positioned<OperationDefinition>,
     ) {
     }
+    /// Called when exiting an operation definition.
     fn exit_operation_definition(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _name: Option<&'a Name>,
-        _operation_definition: &'a Positioned<OperationDefinition>,
+        ctx: &mut VisitorContext<'a>,
+        name: Option<&'a Name>,
+        operation_definition: &'a Positioned<OperationDefinition>,
     ) {
     }
 
+    /// Called when entering a fragment definition.
     fn enter_fragment_definition(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _name: &'a Name,
-        _fragment_definition: &'a Positioned<FragmentDefinition>,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Name,
+        fragment_definition: &'a Positioned<FragmentDefinition>,
     ) {
     }
+    /// Called when exiting a fragment definition.
     fn exit_fragment_definition(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _name: &'a Name,
-        _fragment_definition: &'a Positioned<FragmentDefinition>,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Name,
+        fragment_definition: &'a Positioned<FragmentDefinition>,
     ) {
     }
 
+    /// Called when entering a variable definition.
     fn enter_variable_definition(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _variable_definition: &'a Positioned<VariableDefinition>,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
     ) {
     }
+    /// Called when exiting a variable definition.
     fn exit_variable_definition(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _variable_definition: &'a Positioned<VariableDefinition>,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
     ) {
     }
 
+    /// Called when entering a directive.
     fn enter_directive(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _directive: &'a Positioned<Directive>,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
     ) {
     }
+    /// Called when exiting a directive.
     fn exit_directive(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _directive: &'a Positioned<Directive>,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
     ) {
     }
 
+    /// Called when entering an argument of a field or a directive.
     fn enter_argument(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _name: &'a Positioned<Name>,
-        _value: &'a Positioned<Value>,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Positioned<Name>,
+        value: &'a Positioned<Value>,
     ) {
     }
+    /// Called when exiting an argument of a field or a directive.
     fn exit_argument(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _name: &'a Positioned<Name>,
-        _value: &'a Positioned<Value>,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Positioned<Name>,
+        value: &'a Positioned<Value>,
     ) {
     }
 
+    /// Called when entering a non-empty selection set.
     fn enter_selection_set(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _selection_set: &'a Positioned<SelectionSet>,
+        ctx: &mut VisitorContext<'a>,
+        selection_set: &'a Positioned<SelectionSet>,
     ) {
     }
+    /// Called when exiting a non-empty selection set.
     fn exit_selection_set(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _selection_set: &'a Positioned<SelectionSet>,
+        ctx: &mut VisitorContext<'a>,
+        selection_set: &'a Positioned<SelectionSet>,
     ) {
     }
 
+    /// Called when entering a selection, before the field or the fragment.
     fn enter_selection(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _selection: &'a Positioned<Selection>,
+        ctx: &mut VisitorContext<'a>,
+        selection: &'a Positioned<Selection>,
     ) {
     }
+    /// Called when exiting a selection, after the field or the fragment.
     fn exit_selection(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _selection: &'a Positioned<Selection>,
+        ctx: &mut VisitorContext<'a>,
+        selection: &'a Positioned<Selection>,
     ) {
     }
 
-    fn enter_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {}
-    fn exit_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {}
+    /// Called when entering a field, other than `__typename`.
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {}
+    /// Called when exiting a field, other than `__typename`.
+    fn exit_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {}
 
+    /// Called when entering a fragment spread.
     fn enter_fragment_spread(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _fragment_spread: &'a Positioned<FragmentSpread>,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
     ) {
     }
+    /// Called when exiting a fragment spread.
     fn exit_fragment_spread(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _fragment_spread: &'a Positioned<FragmentSpread>,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
     ) {
     }
 
+    /// Called when entering an inline fragment.
     fn enter_inline_fragment(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _inline_fragment: &'a Positioned<InlineFragment>,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
     ) {
     }
+    /// Called when exiting an inline fragment.
     fn exit_inline_fragment(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _inline_fragment: &'a Positioned<InlineFragment>,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
     ) {
     }
 
+    /// Called when entering a value of an argument, or an item of a list or an
+    /// input object in the value, with the expected type.
     fn enter_input_value(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _pos: Pos,
-        _expected_type: &Option<MetaTypeName<'a>>,
-        _value: &'a Value,
+        ctx: &mut VisitorContext<'a>,
+        pos: Pos,
+        expected_type: &Option<MetaTypeName<'a>>,
+        value: &'a Value,
     ) {
     }
+    /// Called when exiting a value of an argument, or an item of a list or an
+    /// input object in the value, with the expected type.
     fn exit_input_value(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
-        _pos: Pos,
-        _expected_type: &Option<MetaTypeName<'a>>,
-        _value: &Value,
+        ctx: &mut VisitorContext<'a>,
+        pos: Pos,
+        expected_type: &Option<MetaTypeName<'a>>,
+        value: &Value,
     ) {
     }
 }
@@ -528,7 +647,8 @@ where
     }
 }
 
-pub(crate) fn visit<'a, V: Visitor<'a>>(
+/// Visits a document, calling the visitor for every node.
+pub fn visit<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     doc: &'a ExecutableDocument,
@@ -551,7 +671,7 @@ pub(crate) fn visit<'a, V: Visitor<'a>>(
     v.exit_document(ctx, doc);
 }
 
-fn visit_operation_definition<'a, V: Visitor<'a>>(
+fn visit_operation_definition<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     name: Option<&'a Name>,
@@ -579,7 +699,7 @@ fn visit_operation_definition<'a, V: Visitor<'a>>(
     v.exit_operation_definition(ctx, name, operation);
 }
 
-fn visit_selection_set<'a, V: Visitor<'a>>(
+fn visit_selection_set<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     selection_set: &'a Positioned<SelectionSet>,
@@ -593,7 +713,7 @@ fn visit_selection_set<'a, V: Visitor<'a>>(
     }
 }
 
-fn visit_selection<'a, V: Visitor<'a>>(
+fn visit_selection<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     selection: &'a Positioned<Selection>,
@@ -646,7 +766,7 @@ fn visit_selection<'a, V: Visitor<'a>>(
     v.exit_selection(ctx, selection);
 }
 
-fn visit_field<'a, V: Visitor<'a>>(
+fn visit_field<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     field: &'a Positioned<Field>,
@@ -671,7 +791,7 @@ fn visit_field<'a, V: Visitor<'a>>(
     v.exit_field(ctx, field);
 }
 
-fn visit_input_value<'a, V: Visitor<'a>>(
+fn visit_input_value<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     pos: Pos,
@@ -726,18 +846,19 @@ fn visit_input_value<'a, V: Visitor<'a>>(
     v.exit_input_value(ctx, pos, &expected_ty, value);
 }
 
-fn visit_variable_definitions<'a, V: Visitor<'a>>(
+fn visit_variable_definitions<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     variable_definitions: &'a [Positioned<VariableDefinition>],
 ) {
     for d in variable_definitions {
         v.enter_variable_definition(ctx, d);
+        visit_directives(v, ctx, &d.node.directives);
         v.exit_variable_definition(ctx, d);
     }
 }
 
-fn visit_directives<'a, V: Visitor<'a>>(
+fn visit_directives<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     directives: &'a [Positioned<Directive>],
@@ -762,7 +883,7 @@ fn visit_directives<'a, V: Visitor<'a>>(
     }
 }
 
-fn visit_fragment_definition<'a, V: Visitor<'a>>(
+fn visit_fragment_definition<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     name: &'a Name,
@@ -776,7 +897,7 @@ fn visit_fragment_definition<'a, V: Visitor<'a>>(
     }
 }
 
-fn visit_fragment_spread<'a, V: Visitor<'a>>(
+fn visit_fragment_spread<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     fragment_spread: &'a Positioned<FragmentSpread>,
@@ -793,7 +914,7 @@ fn visit_fragment_spread<'a, V: Visitor<'a>>(
     v.exit_fragment_spread(ctx, fragment_spread);
 }
 
-fn visit_inline_fragment<'a, V: Visitor<'a>>(
+fn visit_inline_fragment<'a, V: Visitor<'a> + ?Sized>(
     v: &mut V,
     ctx: &mut VisitorContext<'a>,
     inline_fragment: &'a Positioned<InlineFragment>,
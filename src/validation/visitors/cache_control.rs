@@ -1,35 +1,94 @@
 use crate::{
     CacheControl, Positioned,
-    parser::types::{Field, SelectionSet},
+    parser::types::{ExecutableDocument, Field, SelectionSet},
     registry::MetaType,
     validation::visitor::{VisitMode, Visitor, VisitorContext},
 };
 
 pub struct CacheControlCalculate<'a> {
-    pub cache_control: &'a mut CacheControl,
+    cache_control: &'a mut CacheControl,
+    uncacheable: bool,
 }
 
-impl Visitor<'_> for CacheControlCalculate<'_> {
+impl<'a> CacheControlCalculate<'a> {
+    pub fn new(cache_control: &'a mut CacheControl) -> Self {
+        Self {
+            cache_control,
+            uncacheable: false,
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for CacheControlCalculate<'_> {
     fn mode(&self) -> VisitMode {
         VisitMode::Inline
     }
 
+    fn exit_document(&mut self, _ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
+        if self.uncacheable && self.cache_control.max_age != -1 {
+            *self.cache_control = CacheControl {
+                public: self.cache_control.public,
+                no_store: self.cache_control.no_store,
+                ..Default::default()
+            };
+        }
+    }
+
     fn enter_selection_set(
         &mut self,
         ctx: &mut VisitorContext<'_>,
         _selection_set: &Positioned<SelectionSet>,
     ) {
+        if ctx.registry.apollo_cache_control {
+            // the hints of the types are applied to the fields returning them
+            return;
+        }
+
         if let Some(MetaType::Object { cache_control, .. }) = ctx.current_type() {
             *self.cache_control = self.cache_control.merge(cache_control);
         }
     }
 
     fn enter_field(&mut self, ctx: &mut VisitorContext<'_>, field: &Positioned<Field>) {
-        if let Some(registry_field) = ctx
-            .parent_type()
-            .and_then(|parent| parent.field_by_name(&field.node.name.node))
-        {
+        let Some(parent_type) = ctx.parent_type() else {
+            return;
+        };
+        let Some(registry_field) = parent_type.field_by_name(&field.node.name.node) else {
+            return;
+        };
+
+        if !ctx.registry.apollo_cache_control {
             *self.cache_control = self.cache_control.merge(&registry_field.cache_control);
+            return;
+        }
+
+        let field_hint = &registry_field.cache_control;
+        let (type_hint, is_composite) = match ctx.current_type() {
+            Some(MetaType::Object { cache_control, .. }) => (*cache_control, true),
+            Some(MetaType::Interface { .. } | MetaType::Union { .. }) => {
+                (CacheControl::default(), true)
+            }
+            _ => (CacheControl::default(), false),
+        };
+        let is_root = [
+            Some(ctx.registry.query_type.as_str()),
+            ctx.registry.mutation_type.as_deref(),
+            ctx.registry.subscription_type.as_deref(),
+        ]
+        .contains(&Some(parent_type.name()));
+
+        // the max age of the field takes precedence over the max age of its type
+        let mut hint = field_hint.merge(&type_hint);
+        hint.max_age = if field_hint.max_age != 0 || field_hint.inherit_max_age {
+            field_hint.max_age
+        } else {
+            type_hint.max_age
+        };
+        let inherit_max_age = field_hint.inherit_max_age || type_hint.inherit_max_age;
+
+        if hint.max_age == 0 && !inherit_max_age && (is_root || is_composite) {
+            self.uncacheable = true;
         }
+        *self.cache_control = self.cache_control.merge(&hint);
     }
 }
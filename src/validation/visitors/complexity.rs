@@ -53,7 +53,8 @@ impl<'ctx> Visitor<'ctx> for ComplexityCalculate<'ctx, '_> {
     fn exit_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
         let children_complex = self.complexity_stack.pop().unwrap();
 
-        if let Some(MetaType::Object { fields, .. }) = ctx.parent_type()
+        if let Some(MetaType::Object { fields, .. } | MetaType::Interface { fields, .. }) =
+            ctx.parent_type()
             && let Some(meta_field) = fields.get(MetaTypeName::concrete_typename(
                 field.node.name.node.as_str(),
             ))
@@ -0,0 +1,33 @@
+use zxcvbn::zxcvbn;
+
+use crate::{InputType, InputValueError};
+
+pub fn min_password_strength<T: AsRef<str> + InputType>(
+    value: &T,
+    min_score: u8,
+) -> Result<(), InputValueError<T>> {
+    let score = u8::from(zxcvbn(value.as_ref(), &[]).score());
+    if score >= min_score {
+        Ok(())
+    } else {
+        Err(format!(
+            "the password strength score is {}, must be greater than or equal to {}",
+            score, min_score
+        )
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_password_strength() {
+        assert!(min_password_strength(&"password".to_string(), 3).is_err());
+        assert!(min_password_strength(&"P@ssw0rd1".to_string(), 3).is_err());
+        assert!(min_password_strength(&"Koxiaet".to_string(), 3).is_err());
+        assert!(min_password_strength(&"correct horse battery staple".to_string(), 3).is_ok());
+        assert!(min_password_strength(&"dDLqV8!#pz3*5U".to_string(), 4).is_ok());
+    }
+}
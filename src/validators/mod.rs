@@ -8,10 +8,13 @@ mod max_length;
 mod maximum;
 mod min_items;
 mod min_length;
+#[cfg(feature = "password-strength-validator")]
+mod min_password_strength;
 mod minimum;
 mod multiple_of;
 mod regex;
 mod url;
+mod uuid;
 
 pub use chars_max_length::chars_max_length;
 pub use chars_min_length::chars_min_length;
@@ -23,11 +26,16 @@ pub use max_length::max_length;
 pub use maximum::maximum;
 pub use min_items::min_items;
 pub use min_length::min_length;
+#[cfg(feature = "password-strength-validator")]
+pub use min_password_strength::min_password_strength;
 pub use minimum::minimum;
 pub use multiple_of::multiple_of;
 
-pub use self::{regex::regex, url::url};
-use crate::{InputType, InputValueError};
+#[cfg(not(feature = "boxed-trait"))]
+use std::future::Future;
+
+pub use self::{regex::regex, url::url, uuid::uuid};
+use crate::{Context, InputType, InputValueError};
 
 /// Represents a custom input value validator.
 pub trait CustomValidator<T: InputType> {
@@ -46,3 +54,23 @@ where
         (self)(value).map_err(Into::into)
     }
 }
+
+/// Represents a custom input value validator that runs asynchronously and has
+/// access to the [`Context`](crate::Context) of the field.
+///
+/// Use it with `#[graphql(validator(custom_async = "..."))]` on arguments and
+/// input object fields.
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+pub trait AsyncCustomValidator<T: InputType>: Send + Sync {
+    /// Check the value is valid.
+    #[cfg(feature = "boxed-trait")]
+    async fn check(&self, ctx: &Context<'_>, value: &T) -> Result<(), InputValueError<T>>;
+
+    /// Check the value is valid.
+    #[cfg(not(feature = "boxed-trait"))]
+    fn check(
+        &self,
+        ctx: &Context<'_>,
+        value: &T,
+    ) -> impl Future<Output = Result<(), InputValueError<T>>> + Send;
+}
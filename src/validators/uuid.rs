@@ -0,0 +1,54 @@
+use crate::{InputType, InputValueError};
+
+/// Parses a UUID in the hyphenated (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`)
+/// or simple (32 hex digits) form and returns its version number.
+fn parse_uuid_version(s: &str) -> Option<usize> {
+    let digits = match s.len() {
+        36 => {
+            let bytes = s.as_bytes();
+            if [8, 13, 18, 23].iter().any(|idx| bytes[*idx] != b'-') {
+                return None;
+            }
+            s.split('-').collect::<String>()
+        }
+        32 => s.to_string(),
+        _ => return None,
+    };
+    if digits.len() != 32 || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    (digits.as_bytes()[12] as char)
+        .to_digit(16)
+        .map(|version| version as usize)
+}
+
+pub fn uuid<T: AsRef<str> + InputType>(
+    value: &T,
+    version: Option<usize>,
+) -> Result<(), InputValueError<T>> {
+    match (parse_uuid_version(value.as_ref()), version) {
+        (None, _) => Err("invalid uuid".into()),
+        (Some(actual), Some(expected)) if actual != expected => {
+            Err(format!("the uuid version is {}, must be {}", actual, expected).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid() {
+        assert!(uuid(&"67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(), None).is_ok());
+        assert!(uuid(&"67e5504410b1426f9247bb680e5fe0c8".to_string(), None).is_ok());
+        assert!(uuid(&"67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(), Some(4)).is_ok());
+        assert!(uuid(&"67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(), Some(7)).is_err());
+        assert!(uuid(&"017f22e2-79b0-7cc3-98c4-dc0c0c07398f".to_string(), Some(7)).is_ok());
+        assert!(uuid(&"67e55044-10b1-426f-9247-bb680e5fe0c".to_string(), None).is_err());
+        assert!(uuid(&"67e55044-10b1-426f-9247_bb680e5fe0c8".to_string(), None).is_err());
+        assert!(uuid(&"g7e55044-10b1-426f-9247-bb680e5fe0c8".to_string(), None).is_err());
+        assert!(uuid(&"67e55044-10b1-426f-9247-bb680e5fe0c8-".to_string(), None).is_err());
+    }
+}
@@ -31,3 +31,41 @@ pub async fn test_batch_request() {
         ])
     );
 }
+
+#[tokio::test]
+pub async fn test_limit_batch_size() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_batch_size(2)
+        .finish();
+
+    let batch: BatchRequest = vec![Request::new("{ value }"), Request::new("{ value }")].into();
+    let resp = schema.execute_batch(batch).await;
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!([{"data": { "value": 10 }}, {"data": { "value": 10 }}])
+    );
+
+    let batch: BatchRequest = (0..3)
+        .map(|_| Request::new("{ value }"))
+        .collect::<Vec<_>>()
+        .into();
+    let resp = Executor::execute_batch(&schema, batch).await;
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!({
+            "data": null,
+            "errors": [{
+                "message": "The number of requests in a batch cannot be greater than `2`",
+            }]
+        })
+    );
+}
@@ -0,0 +1,203 @@
+use async_graphql::*;
+
+#[tokio::test]
+pub async fn test_cache_control_directives() {
+    #[derive(SimpleObject)]
+    #[graphql(cache_control(max_age = 60, s_max_age = 300, immutable))]
+    struct Image {
+        url: String,
+        #[graphql(cache_control(max_age = 30, stale_while_revalidate = 10))]
+        views: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn image(&self) -> Image {
+            Image {
+                url: "a.png".to_string(),
+                views: 1,
+            }
+        }
+
+        #[graphql(cache_control(no_store))]
+        async fn token(&self) -> &str {
+            "abc"
+        }
+
+        #[graphql(cache_control(max_age = 60, stale_if_error = 600, private))]
+        async fn me(&self) -> i32 {
+            1
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute("{ image { url } }")
+            .await
+            .cache_control
+            .value(),
+        Some("max-age=60, s-maxage=300, immutable".to_string())
+    );
+    assert_eq!(
+        schema
+            .execute("{ image { url views } }")
+            .await
+            .cache_control
+            .value(),
+        Some("max-age=30, s-maxage=300, stale-while-revalidate=10".to_string())
+    );
+    assert_eq!(
+        schema.execute("{ me }").await.cache_control.value(),
+        Some("max-age=60, stale-if-error=600, private".to_string())
+    );
+    assert_eq!(
+        schema
+            .execute("{ image { url } token }")
+            .await
+            .cache_control
+            .value(),
+        Some("no-store".to_string())
+    );
+}
+
+#[tokio::test]
+pub async fn test_cache_control_dynamic_hints() {
+    struct Query;
+
+    #[Object(cache_control(max_age = 60))]
+    impl Query {
+        async fn value(&self, ctx: &Context<'_>, max_age: Option<i32>) -> i32 {
+            if let Some(max_age) = max_age {
+                ctx.add_cache_hint(CacheControl {
+                    max_age,
+                    ..Default::default()
+                });
+            }
+            10
+        }
+
+        async fn viewer(&self, ctx: &Context<'_>) -> i32 {
+            ctx.add_cache_hint(CacheControl {
+                public: false,
+                ..Default::default()
+            });
+            20
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema.execute("{ value }").await.cache_control.value(),
+        Some("max-age=60".to_string())
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(maxAge: 10) }")
+            .await
+            .cache_control
+            .value(),
+        Some("max-age=10".to_string())
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(maxAge: 120) }")
+            .await
+            .cache_control
+            .value(),
+        Some("max-age=60".to_string())
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(maxAge: -1) viewer }")
+            .await
+            .cache_control
+            .value(),
+        Some("no-cache, private".to_string())
+    );
+}
+
+#[tokio::test]
+pub async fn test_cache_control_apollo_rules() {
+    #[derive(SimpleObject)]
+    #[graphql(cache_control(max_age = 60))]
+    struct Post {
+        title: String,
+        #[graphql(cache_control(max_age = 30))]
+        votes: i32,
+        author: Author,
+        #[graphql(cache_control(inherit_max_age))]
+        editor: Author,
+    }
+
+    #[derive(SimpleObject)]
+    struct Author {
+        name: String,
+    }
+
+    fn post() -> Post {
+        Post {
+            title: "a".to_string(),
+            votes: 1,
+            author: Author {
+                name: "b".to_string(),
+            },
+            editor: Author {
+                name: "c".to_string(),
+            },
+        }
+    }
+
+    struct Query;
+
+    #[Object(cache_control(max_age = 100))]
+    impl Query {
+        async fn post(&self) -> Post {
+            post()
+        }
+
+        #[graphql(cache_control(max_age = 10))]
+        async fn cached_post(&self) -> Post {
+            post()
+        }
+
+        async fn version(&self) -> i32 {
+            1
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_apollo_cache_control()
+        .finish();
+    let max_age = |query: &'static str| {
+        let schema = schema.clone();
+        async move { schema.execute(query).await.cache_control.max_age }
+    };
+
+    // the hint of the type applies to the fields returning it
+    assert_eq!(max_age("{ post { title } }").await, 60);
+    assert_eq!(max_age("{ post { title votes } }").await, 30);
+    // the hint of the field takes precedence over the hint of its type
+    assert_eq!(max_age("{ cachedPost { title } }").await, 10);
+    // root fields and composite fields default to zero
+    assert_eq!(max_age("{ version }").await, 0);
+    assert_eq!(max_age("{ post { author { name } } }").await, 0);
+    assert_eq!(max_age("{ post { title } version }").await, 0);
+    // unless they inherit the max age of their parent
+    assert_eq!(max_age("{ post { editor { name } } }").await, 60);
+
+    // without the Apollo rules, unset hints are ignored
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ post { author { name } } version }")
+            .await
+            .cache_control
+            .max_age,
+        60
+    );
+}
@@ -0,0 +1,189 @@
+#![cfg(feature = "validation_cache")]
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_graphql::*;
+
+#[derive(Clone, Default)]
+struct MemoryStorage(Arc<Mutex<HashMap<ValidationCacheKey, ValidatedDocument>>>);
+
+#[async_trait::async_trait]
+impl ValidationCacheStorage for MemoryStorage {
+    async fn get(&self, key: &ValidationCacheKey) -> Option<ValidatedDocument> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+
+    async fn set(&self, key: ValidationCacheKey, document: ValidatedDocument) {
+        self.0.lock().unwrap().insert(key, document);
+    }
+}
+
+impl MemoryStorage {
+    /// Returns whether the only stored document has an execution plan, and
+    /// clears the storage.
+    fn take_plan(&self) -> bool {
+        let mut documents = self.0.lock().unwrap();
+        let has_plan = documents.len() == 1 && documents.values().all(|doc| doc.plan.is_some());
+        documents.clear();
+        has_plan
+    }
+}
+
+#[derive(SimpleObject)]
+struct Dog {
+    name: String,
+    barks: bool,
+}
+
+#[derive(SimpleObject)]
+struct Cat {
+    name: String,
+    lives: i32,
+}
+
+#[derive(Interface)]
+#[graphql(field(name = "name", ty = "&String"))]
+enum Pet {
+    Dog(Dog),
+    Cat(Cat),
+}
+
+#[derive(Union)]
+enum Animal {
+    Dog(Dog),
+    Cat(Cat),
+}
+
+fn dog() -> Dog {
+    Dog {
+        name: "dog".to_string(),
+        barks: true,
+    }
+}
+
+fn cat() -> Cat {
+    Cat {
+        name: "cat".to_string(),
+        lives: 9,
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn add(&self, a: i32, #[graphql(default = 1)] b: i32) -> i32 {
+        a + b
+    }
+
+    async fn pets(&self) -> Vec<Pet> {
+        vec![dog().into(), cat().into()]
+    }
+
+    async fn animals(&self) -> Vec<Animal> {
+        vec![Animal::Dog(dog()), Animal::Cat(cat())]
+    }
+
+    async fn dog(&self) -> Dog {
+        dog()
+    }
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn double(&self, value: i32) -> i32 {
+        value * 2
+    }
+}
+
+#[tokio::test]
+pub async fn test_compiled_operations() {
+    let storage = MemoryStorage::default();
+    let compiled = Schema::build(Query, Mutation, EmptySubscription)
+        .validation_cache(storage.clone())
+        .enable_compiled_operations()
+        .finish();
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let queries = [
+        "{ a: add(a: 1, b: 2) b: add(a: 2) __typename }",
+        "{ pets { __typename name ... on Dog { barks } ... on Cat { lives name } } }",
+        "{ pets { ...PetFields } } fragment PetFields on Pet { name ... on Cat { lives } ...DogFields } fragment DogFields on Dog { barks }",
+        "{ animals { __typename ... on Pet { name } ... on Dog { barks } ... { ... on Cat { lives } } } }",
+        "{ dog { ... on Dog { name } ...@skip(if: true) { barks } } add(a: 1) @include(if: false) }",
+        "{ dog { ... { name @skip(if: false) } } }",
+        "mutation { a: double(value: 1) b: double(value: 2) }",
+        "query A { add(a: 1) } query B { dog { name } }",
+    ];
+
+    for query in queries {
+        let request = || match query.starts_with("query A") {
+            true => Request::new(query).operation_name("B"),
+            false => Request::new(query),
+        };
+        let expected = schema.execute(request()).await.into_result().unwrap().data;
+        for _ in 0..2 {
+            assert_eq!(
+                compiled
+                    .execute(request())
+                    .await
+                    .into_result()
+                    .unwrap()
+                    .data,
+                expected,
+                "{}",
+                query
+            );
+        }
+        assert!(storage.take_plan(), "{}", query);
+    }
+}
+
+#[tokio::test]
+pub async fn test_compiled_operations_with_variables() {
+    let storage = MemoryStorage::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_cache(storage.clone())
+        .enable_compiled_operations()
+        .finish();
+
+    // the variables are resolved for each request
+    let query = "query($a: Int!) { add(a: $a, b: 10) }";
+    for a in [1, 2] {
+        assert_eq!(
+            schema
+                .execute(Request::new(query).variables(Variables::from_value(value!({ "a": a }))))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "add": a + 10 })
+        );
+    }
+    assert!(storage.take_plan());
+
+    // the operations with conditions on the variables are not compiled
+    let query = "query($skip: Boolean!) { dog { name barks @skip(if: $skip) } }";
+    for (skip, data) in [
+        (true, value!({ "dog": { "name": "dog" } })),
+        (false, value!({ "dog": { "name": "dog", "barks": true } })),
+    ] {
+        assert_eq!(
+            schema
+                .execute(
+                    Request::new(query).variables(Variables::from_value(value!({ "skip": skip })))
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            data
+        );
+    }
+    assert!(!storage.take_plan());
+}
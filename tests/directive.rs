@@ -129,6 +129,293 @@ pub async fn test_custom_directive() {
     );
 }
 
+#[cfg(feature = "dynamic-schema")]
+#[tokio::test]
+pub async fn test_custom_directive_dynamic_schema() {
+    use async_graphql::dynamic;
+
+    struct Wrap {
+        prefix: String,
+    }
+
+    #[async_trait::async_trait]
+    impl CustomDirective for Wrap {
+        async fn resolve_field(
+            &self,
+            _ctx: &Context<'_>,
+            resolve: ResolveFut<'_>,
+        ) -> ServerResult<Option<Value>> {
+            resolve.await.map(|value| {
+                value.map(|value| match value {
+                    Value::String(str) => Value::String(self.prefix.clone() + &str),
+                    _ => value,
+                })
+            })
+        }
+    }
+
+    #[Directive(location = "Field")]
+    fn wrap(prefix: String) -> impl CustomDirective {
+        Wrap { prefix }
+    }
+
+    let query = dynamic::Object::new("Query").field(dynamic::Field::new(
+        "value",
+        dynamic::TypeRef::named_nn(dynamic::TypeRef::STRING),
+        |_| dynamic::FieldFuture::new(async move { Ok(Some(Value::from("abc"))) }),
+    ));
+    let schema = dynamic::Schema::build("Query", None, None)
+        .register(query)
+        .directive(wrap)
+        .finish()
+        .unwrap();
+    assert!(
+        schema
+            .sdl()
+            .contains("directive @wrap(prefix: String!) on FIELD")
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ value @wrap(prefix: "&") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": "&abc" })
+    );
+}
+
+#[tokio::test]
+pub async fn test_custom_directive_on_fragments() {
+    struct EnabledFlags(Vec<&'static str>);
+
+    struct FeatureFlag {
+        name: String,
+    }
+
+    #[async_trait::async_trait]
+    impl CustomDirective for FeatureFlag {
+        fn transform_selection(
+            &self,
+            ctx: &ContextDirective<'_>,
+            selection: Positioned<parser::types::Selection>,
+        ) -> ServerResult<Option<Positioned<parser::types::Selection>>> {
+            let enabled = ctx.data_unchecked::<EnabledFlags>();
+            Ok(enabled.0.contains(&self.name.as_str()).then_some(selection))
+        }
+    }
+
+    #[Directive(location = "FragmentSpread", location = "InlineFragment")]
+    fn feature_flag(name: String) -> impl CustomDirective {
+        FeatureFlag { name }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        pub async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(EnabledFlags(vec!["a"]))
+        .directive(feature_flag)
+        .finish();
+    assert!(
+        schema.sdl().contains(
+            "directive @feature_flag(name: String!) on FRAGMENT_SPREAD | INLINE_FRAGMENT"
+        )
+    );
+    assert_eq!(
+        schema
+            .execute(
+                r#"
+                fragment A on Query {
+                    value3: value
+                    ... @feature_flag(name: "b") {
+                        value4: value
+                    }
+                }
+
+                fragment B on Query {
+                    value5: value
+                }
+
+                {
+                    ... @feature_flag(name: "a") {
+                        value1: value
+                    }
+                    ... @feature_flag(name: "b") {
+                        value2: value
+                    }
+                    ...A @feature_flag(name: "a")
+                    ...B @feature_flag(name: "b")
+                }
+                "#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value1": 10, "value3": 10 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ value @feature_flag(name: "a") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Directive "feature_flag" may not be used on "FIELD""#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 9,
+                ..Default::default()
+            }],
+            path: vec![],
+            extensions: None,
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_custom_directive_on_operation() {
+    #[derive(Default)]
+    struct Cache(std::sync::Mutex<std::collections::HashMap<String, Value>>);
+
+    struct Cached {
+        key: String,
+    }
+
+    #[async_trait::async_trait]
+    impl CustomDirective for Cached {
+        async fn execute_operation(
+            &self,
+            ctx: &ContextSelectionSet<'_>,
+            execute: ExecuteOperationFut<'_>,
+        ) -> Response {
+            let cache = ctx.data_unchecked::<Cache>();
+            if let Some(data) = cache.0.lock().unwrap().get(&self.key) {
+                return Response::new(data.clone());
+            }
+            let resp = execute.await;
+            if resp.is_ok() {
+                cache
+                    .0
+                    .lock()
+                    .unwrap()
+                    .insert(self.key.clone(), resp.data.clone());
+            }
+            resp
+        }
+    }
+
+    #[Directive(location = "Query")]
+    fn cached(key: String) -> impl CustomDirective {
+        Cached { key }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        pub async fn value(&self, ctx: &Context<'_>) -> i32 {
+            let counter = ctx.data_unchecked::<std::sync::atomic::AtomicI32>();
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Cache::default())
+        .data(std::sync::atomic::AtomicI32::new(0))
+        .directive(cached)
+        .finish();
+
+    for _ in 0..2 {
+        assert_eq!(
+            schema
+                .execute(r#"query @cached(key: "a") { value }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": 1 })
+        );
+    }
+    assert_eq!(
+        schema
+            .execute(r#"query @cached(key: "b") { value }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 2 })
+    );
+    assert_eq!(
+        schema
+            .execute("{ value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 3 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_custom_directive_on_variable_definition() {
+    struct Trim;
+
+    #[async_trait::async_trait]
+    impl CustomDirective for Trim {
+        fn transform_variable(
+            &self,
+            _ctx: &ContextDirective<'_>,
+            _name: &str,
+            value: Option<Value>,
+        ) -> ServerResult<Option<Value>> {
+            Ok(value.map(|value| match value {
+                Value::String(s) => Value::String(s.trim().to_string()),
+                value => value,
+            }))
+        }
+    }
+
+    #[Directive(location = "VariableDefinition")]
+    fn trim() -> impl CustomDirective {
+        Trim
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        pub async fn value(&self, input: String) -> String {
+            input
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .directive(trim)
+        .finish();
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(r#"query ($a: String! @trim, $b: String! @trim = "  b ") { a: value(input: $a) b: value(input: $b) }"#)
+                    .variables(Variables::from_json(serde_json::json!({ "a": " a  " })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "a": "a", "b": "b" })
+    );
+}
+
 #[tokio::test]
 pub async fn test_no_unused_directives() {
     struct Query;
@@ -42,3 +42,67 @@ async fn test_spaces() {
     let expected = include_str!("schemas/test_space_schema.graphql");
     assert_eq!(sdl, expected);
 }
+
+#[tokio::test]
+async fn test_cache_control() {
+    #[derive(SimpleObject)]
+    #[graphql(cache_control(max_age = 60))]
+    struct A {
+        a: i32,
+        #[graphql(cache_control(max_age = 30, private))]
+        b: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn a(&self) -> A {
+            A { a: 100, b: 200 }
+        }
+
+        #[graphql(cache_control(inherit_max_age))]
+        async fn b(&self) -> Option<A> {
+            None
+        }
+
+        #[graphql(cache_control(no_cache))]
+        async fn c(&self) -> i32 {
+            300
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl_with_options(SDLExportOptions::new().include_cache_control());
+
+    let expected = r#"type A @cacheControl(maxAge: 60) {
+	a: Int!
+	b: Int! @cacheControl(maxAge: 30, scope: PRIVATE)
+}
+
+type Query {
+	a: A!
+	b: A @cacheControl(inheritMaxAge: true)
+	c: Int! @cacheControl(maxAge: 0)
+}
+
+"""
+Directs the executor to include this field or fragment only when the `if` argument is true.
+"""
+directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+"""
+Directs the executor to skip this field or fragment when the `if` argument is true.
+"""
+directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+enum CacheControlScope {
+	PUBLIC
+	PRIVATE
+}
+
+directive @cacheControl(maxAge: Int, scope: CacheControlScope, inheritMaxAge: Boolean) on FIELD_DEFINITION | OBJECT | INTERFACE | UNION
+schema {
+	query: Query
+}
+"#;
+    assert_eq!(sdl, expected);
+}
@@ -533,3 +533,83 @@ pub async fn subscription_execute_with_data() {
         );
     }
 }
+
+#[cfg(feature = "operation_signature")]
+#[tokio::test]
+pub async fn test_extension_operation_signature() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn add(&self, a: i32, b: i32) -> i32 {
+            a + b
+        }
+    }
+
+    #[derive(Default)]
+    struct MyExtensionImpl {
+        document: Mutex<Option<ExecutableDocument>>,
+        signatures: Arc<Mutex<Vec<OperationSignature>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Extension for MyExtensionImpl {
+        async fn parse_query(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            query: &str,
+            variables: &Variables,
+            next: NextParseQuery<'_>,
+        ) -> ServerResult<ExecutableDocument> {
+            let document = next.run(ctx, query, variables).await?;
+            *self.document.lock().await = Some(document.clone());
+            Ok(document)
+        }
+
+        async fn execute(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            operation_name: Option<&str>,
+            next: NextExecute<'_>,
+        ) -> Response {
+            if let Some(document) = &*self.document.lock().await {
+                let signature = ctx.operation_signature(document, operation_name).unwrap();
+                self.signatures.lock().await.push(signature);
+            }
+            next.run(ctx, operation_name).await
+        }
+    }
+
+    struct MyExtension(Arc<Mutex<Vec<OperationSignature>>>);
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(MyExtensionImpl {
+                document: Default::default(),
+                signatures: self.0.clone(),
+            })
+        }
+    }
+
+    let signatures = Arc::new(Mutex::new(Vec::new()));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(MyExtension(signatures.clone()))
+        .finish();
+
+    // the operation name is missing, so the operation is not executed
+    schema
+        .execute("query A { x: add(a: 1, b: 2) } query B { add(b: 3, a: 4) }")
+        .await;
+    schema
+        .execute(
+            Request::new("query A { x: add(a: 1, b: 2) } query B { add(b: 3, a: 4) }")
+                .operation_name("B"),
+        )
+        .await;
+    schema.execute("query B {\n  add(a: 10, b: 20)\n}").await;
+
+    let signatures = signatures.lock().await;
+    assert_eq!(signatures.len(), 2);
+    assert_eq!(signatures[0].signature, "query B{add(a:0,b:0)}");
+    assert_eq!(signatures[0], signatures[1]);
+}
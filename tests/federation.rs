@@ -255,7 +255,8 @@ pub async fn test_find_entity_with_context() {
             source: None,
             locations: vec![Pos {
                 line: 2,
-                column: 13
+                column: 13,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("_entities".to_owned())],
             extensions: None,
@@ -67,7 +67,11 @@ pub async fn test_field_features() {
             message: r#"Unknown field "valueAbc" on type "Query". Did you mean "value"?"#
                 .to_owned(),
             source: None,
-            locations: vec![Pos { column: 3, line: 1 }],
+            locations: vec![Pos {
+                column: 3,
+                line: 1,
+                ..Default::default()
+            }],
             path: Vec::new(),
             extensions: None,
         }]
@@ -88,7 +92,11 @@ pub async fn test_field_features() {
             message: r#"Unknown field "valueAbc" on type "MyObj". Did you mean "value"?"#
                 .to_owned(),
             source: None,
-            locations: vec![Pos { column: 9, line: 1 }],
+            locations: vec![Pos {
+                column: 9,
+                line: 1,
+                ..Default::default()
+            }],
             path: Vec::new(),
             extensions: None,
         }]
@@ -119,7 +127,8 @@ pub async fn test_field_features() {
             source: None,
             locations: vec![Pos {
                 column: 16,
-                line: 1
+                line: 1,
+                ..Default::default()
             }],
             path: Vec::new(),
             extensions: None,
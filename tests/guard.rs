@@ -90,7 +90,11 @@ pub async fn test_guard_simple_rule() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -121,7 +125,8 @@ pub async fn test_guard_simple_rule() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 16
+                column: 16,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("values".to_owned())],
             extensions: None,
@@ -166,7 +171,11 @@ pub async fn test_guard_and_operator() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -186,7 +195,11 @@ pub async fn test_guard_and_operator() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -206,7 +219,11 @@ pub async fn test_guard_and_operator() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -276,7 +293,11 @@ pub async fn test_guard_or_operator() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -338,7 +359,11 @@ pub async fn test_guard_use_params() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("get".to_owned())],
             extensions: None,
         }]
@@ -374,7 +399,11 @@ pub async fn test_guard_on_simple_object() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -411,7 +440,11 @@ pub async fn test_guard_on_simple_object_field() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -450,7 +483,11 @@ pub async fn test_guard_on_object() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -490,7 +527,11 @@ pub async fn test_guard_on_object_field() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -533,7 +574,11 @@ pub async fn test_guard_on_complex_object() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value2".to_owned())],
             extensions: None,
         }]
@@ -577,7 +622,11 @@ pub async fn test_guard_on_complex_object_field() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value2".to_owned())],
             extensions: None,
         }]
@@ -620,7 +669,11 @@ pub async fn test_guard_with_fn() {
         vec![ServerError {
             message: "Forbidden".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("value".to_owned())],
             extensions: None,
         }]
@@ -835,6 +835,8 @@ pub async fn test_input_object_validator() {
         value!({ "a": 500 })
     );
 
+    let mut error_extensions = ErrorExtensionValues::default();
+    error_extensions.set("inputPath", value!(["input"]));
     assert_eq!(
         schema
             .execute("{ a(input: { a: 100, b: 25 }) }")
@@ -846,10 +848,154 @@ pub async fn test_input_object_validator() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 12
+                column: 12,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("a".to_string())],
-            extensions: None
+            extensions: Some(error_extensions)
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_input_object_cross_field_validator() {
+    fn check_range(range: &DateRange) -> Result<(), InputValueError<DateRange>> {
+        if range.end < range.start {
+            Err(InputValueError::custom("end must not be before start").with_field("end"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_length(range: &DateRange) -> Result<(), &'static str> {
+        if range.end - range.start > 100 {
+            Err("range is too long")
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(InputObject)]
+    #[graphql(validator(custom = "check_range", custom = "check_length"))]
+    struct DateRange {
+        start: i32,
+        end: i32,
+    }
+
+    #[derive(InputObject)]
+    struct Event {
+        name: String,
+        range: DateRange,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn duration(&self, range: DateRange) -> i32 {
+            range.end - range.start
+        }
+
+        async fn event(&self, event: Event) -> String {
+            event.name
+        }
+
+        async fn count(&self, ranges: Vec<DateRange>) -> usize {
+            ranges.len()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute("{ duration(range: { start: 10, end: 30 }) }")
+            .await
+            .data,
+        value!({ "duration": 20 })
+    );
+
+    let mut error_extensions = ErrorExtensionValues::default();
+    error_extensions.set("inputPath", value!(["range", "end"]));
+    assert_eq!(
+        schema
+            .execute("{ duration(range: { start: 30, end: 10 }) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "DateRange": end must not be before start"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 19,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("duration".to_string())],
+            extensions: Some(error_extensions)
+        }]
+    );
+
+    let mut error_extensions = ErrorExtensionValues::default();
+    error_extensions.set("inputPath", value!(["range"]));
+    assert_eq!(
+        schema
+            .execute("{ duration(range: { start: 0, end: 200 }) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "DateRange": range is too long"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 19,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("duration".to_string())],
+            extensions: Some(error_extensions)
+        }]
+    );
+
+    let mut error_extensions = ErrorExtensionValues::default();
+    error_extensions.set("inputPath", value!(["event", "range", "end"]));
+    assert_eq!(
+        schema
+            .execute(r#"{ event(event: { name: "a", range: { start: 30, end: 10 } }) }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "DateRange": end must not be before start (occurred while parsing "Event")"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 16,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("event".to_string())],
+            extensions: Some(error_extensions)
+        }]
+    );
+
+    let mut error_extensions = ErrorExtensionValues::default();
+    error_extensions.set("inputPath", value!(["ranges", 1, "end"]));
+    assert_eq!(
+        schema
+            .execute("{ count(ranges: [{ start: 10, end: 30 }, { start: 30, end: 10 }]) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "DateRange": end must not be before start (occurred while parsing "[DateRange!]")"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 17,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("count".to_string())],
+            extensions: Some(error_extensions)
         }]
     );
 }
@@ -918,7 +1064,8 @@ pub async fn test_custom_validator_with_extensions_input() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 12
+                column: 12,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: Some(error_extensions)
@@ -24,6 +24,7 @@ pub async fn test_input_value_custom_error() {
             locations: vec![Pos {
                 line: 1,
                 column: 14,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("parseInt".to_owned())],
             extensions: None,
@@ -10,7 +10,7 @@ struct ObjA<'a> {
     value: &'a i32,
 }
 
-#[expect(dead_code)]
+#[allow(dead_code)]
 struct ObjB<'a>(PhantomData<&'a i32>);
 
 #[Object]
@@ -22,14 +22,14 @@ impl<'a> ObjB<'a> {
 }
 
 #[derive(Union)]
-#[expect(dead_code)]
+#[allow(dead_code)]
 enum MyUnion1<'a> {
     ObjA(ObjA<'a>),
 }
 
 #[derive(Interface)]
 #[graphql(field(name = "value", ty = "&&'a i32"))]
-#[expect(dead_code)]
+#[allow(dead_code)]
 enum MyInterface<'a> {
     ObjA(ObjA<'a>),
 }
@@ -176,6 +176,7 @@ pub async fn test_array_type() {
             locations: vec![Pos {
                 line: 1,
                 column: 22,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("arrayInput".to_owned())],
             extensions: None,
@@ -50,7 +50,7 @@ pub async fn test_scalar() {
     macro_rules! test_data {
         ($test_name:ident) => {
             #[derive(Debug, Clone)]
-            #[expect(dead_code)]
+            #[allow(dead_code)]
             pub struct $test_name(i64);
 
             #[async_graphql::Scalar]
@@ -79,7 +79,7 @@ pub async fn test_oneof_object_type() {
     macro_rules! test_data {
         ($test_name:ident, $type1:ty, $type2:ty) => {
             #[derive(async_graphql::OneofObject)]
-            #[expect(dead_code)]
+            #[allow(dead_code)]
             enum $test_name {
                 Type1($type1),
                 Type2($type2),
@@ -0,0 +1,132 @@
+use std::sync::{Arc, Mutex};
+
+use async_graphql::*;
+
+#[derive(SimpleObject, Default)]
+#[graphql(projection)]
+struct Address {
+    city: String,
+    #[graphql(projection(column = "zip_code"))]
+    zip: String,
+}
+
+#[derive(SimpleObject, Default)]
+#[graphql(projection)]
+struct Audit {
+    created_at: i32,
+    #[graphql(projection(skip))]
+    revision: i32,
+}
+
+#[derive(SimpleObject, Default)]
+#[graphql(
+    complex,
+    projection(computed(field = "displayName", requires = "first_name, last_name"))
+)]
+struct User {
+    id: i32,
+    #[graphql(derived(owned, name = "id_wide", into = "i64"))]
+    #[graphql(projection(column = "user_id"))]
+    user_id: i32,
+    #[graphql(skip)]
+    first_name: String,
+    #[graphql(skip)]
+    last_name: String,
+    #[graphql(projection(join = "addresses"))]
+    address: Option<Address>,
+    #[graphql(flatten)]
+    audit: Audit,
+}
+
+#[ComplexObject]
+impl User {
+    async fn display_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self, ctx: &Context<'_>) -> User {
+        let projection = Projection::from_field::<User>(ctx.field());
+        *ctx.data_unchecked::<Arc<Mutex<Projection>>>()
+            .lock()
+            .unwrap() = projection;
+        User::default()
+    }
+}
+
+async fn project(query: &str) -> Projection {
+    let projection = Arc::new(Mutex::new(Projection::new()));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(projection.clone())
+        .finish();
+    let res = schema.execute(query).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    projection.lock().unwrap().clone()
+}
+
+#[tokio::test]
+pub async fn test_projection_columns() {
+    let projection = project("{ user { id a: id displayName idWide } }").await;
+    assert_eq!(
+        projection.columns().collect::<Vec<_>>(),
+        vec!["id", "first_name", "last_name", "user_id"]
+    );
+    assert!(projection.contains_column("first_name"));
+    assert!(!projection.contains_column("last_name_"));
+    assert_eq!(projection.joins().count(), 0);
+}
+
+#[tokio::test]
+pub async fn test_projection_join() {
+    let projection = project("{ user { address { city zip } } }").await;
+    assert_eq!(projection.columns().count(), 0);
+    assert_eq!(
+        projection.joins().map(|(name, _)| name).collect::<Vec<_>>(),
+        vec!["addresses"]
+    );
+    assert_eq!(
+        projection
+            .join("addresses")
+            .unwrap()
+            .columns()
+            .collect::<Vec<_>>(),
+        vec!["city", "zip_code"]
+    );
+}
+
+#[tokio::test]
+pub async fn test_projection_flatten_and_skip() {
+    let projection = project("{ user { createdAt revision } }").await;
+    assert_eq!(projection.columns().collect::<Vec<_>>(), vec!["created_at"]);
+}
+
+#[tokio::test]
+pub async fn test_projection_fragments() {
+    let projection = project(
+        r#"{
+            user {
+                ... on User { id }
+                ...UserFields
+            }
+        }
+
+        fragment UserFields on User {
+            address { city }
+        }"#,
+    )
+    .await;
+    assert_eq!(projection.columns().collect::<Vec<_>>(), vec!["id"]);
+    assert_eq!(
+        projection
+            .join("addresses")
+            .unwrap()
+            .columns()
+            .collect::<Vec<_>>(),
+        vec!["city"]
+    );
+    assert!(!projection.is_empty());
+}
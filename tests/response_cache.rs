@@ -0,0 +1,328 @@
+#![cfg(feature = "response_cache")]
+
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use async_graphql::{
+    extensions::response_cache::{LruCacheStorage, ResponseCache},
+    *,
+};
+
+#[derive(Default)]
+struct Counter(AtomicI32);
+
+impl Counter {
+    fn next(&self) -> i32 {
+        self.0.fetch_add(1, Ordering::SeqCst)
+    }
+}
+
+struct CurrentUser(&'static str);
+
+fn admin(ctx: &Context<'_>) -> Result<()> {
+    match ctx.data_opt::<CurrentUser>() {
+        Some(CurrentUser("admin")) => Ok(()),
+        _ => Err("forbidden".into()),
+    }
+}
+
+struct User {
+    id: i32,
+}
+
+#[Object]
+impl User {
+    async fn id(&self) -> i32 {
+        self.id
+    }
+
+    #[graphql(cache_control(max_age = 60))]
+    async fn score(&self, ctx: &Context<'_>) -> i32 {
+        self.id * 100 + ctx.data_unchecked::<Counter>().next()
+    }
+
+    #[graphql(cache_control(max_age = 60), guard = "admin")]
+    async fn secret(&self, ctx: &Context<'_>) -> i32 {
+        self.id * 100 + ctx.data_unchecked::<Counter>().next()
+    }
+}
+
+struct Item;
+
+#[Object]
+impl Item {
+    async fn value(&self, ctx: &Context<'_>) -> Result<i32> {
+        match ctx.data_unchecked::<Counter>().next() {
+            0 => Err("flaky".into()),
+            n => Ok(n),
+        }
+    }
+}
+
+struct Flaky;
+
+#[Object]
+impl Flaky {
+    async fn item(&self) -> Option<Item> {
+        Some(Item)
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    #[graphql(cache_control(max_age = 60))]
+    async fn value(&self, ctx: &Context<'_>, n: i32) -> i32 {
+        n * 100 + ctx.data_unchecked::<Counter>().next()
+    }
+
+    #[graphql(cache_control(max_age = 60), guard = "admin")]
+    async fn secret(&self, ctx: &Context<'_>) -> i32 {
+        ctx.data_unchecked::<Counter>().next()
+    }
+
+    async fn uncached(&self, ctx: &Context<'_>) -> i32 {
+        ctx.data_unchecked::<Counter>().next()
+    }
+
+    #[graphql(cache_control(max_age = 60))]
+    async fn lowered(&self, ctx: &Context<'_>) -> i32 {
+        ctx.add_cache_hint(CacheControl {
+            max_age: 10,
+            ..Default::default()
+        });
+        ctx.data_unchecked::<Counter>().next()
+    }
+
+    async fn users(&self, ctx: &Context<'_>, #[graphql(default)] reverse: bool) -> Vec<User> {
+        let mut users = vec![User { id: 1 }, User { id: 2 }];
+        if reverse {
+            users.reverse();
+        }
+        ctx.set_cache_keys(users.iter().map(|user| format!("User:{}", user.id)));
+        users
+    }
+
+    async fn first_user(&self) -> User {
+        User { id: 1 }
+    }
+
+    #[graphql(cache_control(private))]
+    async fn viewer(&self, ctx: &Context<'_>) -> User {
+        let id = match ctx.data_opt::<CurrentUser>() {
+            Some(CurrentUser("a")) => 1,
+            Some(_) => 2,
+            None => 0,
+        };
+        ctx.set_cache_key(format!("User:{id}"));
+        User { id }
+    }
+
+    #[graphql(cache_control(max_age = 60))]
+    async fn flaky(&self) -> Flaky {
+        Flaky
+    }
+
+    #[graphql(cache_control(max_age = 60, private))]
+    async fn me(&self, ctx: &Context<'_>) -> String {
+        format!(
+            "{}{}",
+            ctx.data_opt::<CurrentUser>()
+                .map(|user| user.0)
+                .unwrap_or_default(),
+            ctx.data_unchecked::<Counter>().next()
+        )
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Counter::default())
+        .extension(
+            ResponseCache::new(LruCacheStorage::new(64))
+                .user(|ctx| ctx.data_opt::<CurrentUser>().map(|user| user.0.to_string())),
+        )
+        .finish()
+}
+
+async fn execute(
+    schema: &Schema<Query, EmptyMutation, EmptySubscription>,
+    request: impl Into<Request>,
+) -> Value {
+    schema.execute(request).await.into_result().unwrap().data
+}
+
+#[tokio::test]
+pub async fn test_response_cache_field() {
+    let schema = schema();
+
+    assert_eq!(
+        execute(&schema, "{ value(n: 1) uncached }").await,
+        value!({ "value": 100, "uncached": 1 })
+    );
+    assert_eq!(
+        execute(&schema, "{ a: value(n: 1) uncached }").await,
+        value!({ "a": 100, "uncached": 2 })
+    );
+    assert_eq!(
+        execute(
+            &schema,
+            Request::new("query ($n: Int!) { value(n: $n) uncached }")
+                .variables(Variables::from_json(serde_json::json!({ "n": 2 })))
+        )
+        .await,
+        value!({ "value": 203, "uncached": 4 })
+    );
+    assert_eq!(
+        execute(&schema, "{ value(n: 2) }").await,
+        value!({ "value": 203 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_response_cache_nested_fields() {
+    let schema = schema();
+
+    assert_eq!(
+        execute(&schema, "{ users { id score } }").await,
+        value!({ "users": [{ "id": 1, "score": 100 }, { "id": 2, "score": 201 }] })
+    );
+    assert_eq!(
+        execute(&schema, "{ users(reverse: true) { id score } }").await,
+        value!({ "users": [{ "id": 2, "score": 201 }, { "id": 1, "score": 100 }] })
+    );
+    assert_eq!(
+        execute(&schema, "{ users { score } uncached }").await,
+        value!({ "users": [{ "score": 100 }, { "score": 201 }], "uncached": 2 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_response_cache_object_without_key() {
+    let schema = schema();
+
+    assert_eq!(
+        execute(&schema, "{ firstUser { score } }").await,
+        value!({ "firstUser": { "score": 100 } })
+    );
+    assert_eq!(
+        execute(&schema, "{ firstUser { id score } }").await,
+        value!({ "firstUser": { "id": 1, "score": 101 } })
+    );
+}
+
+#[tokio::test]
+pub async fn test_response_cache_private_parent() {
+    let schema = schema();
+
+    // without a user the fields below a private field are not cached
+    assert_eq!(
+        execute(&schema, "{ viewer { score } }").await,
+        value!({ "viewer": { "score": 0 } })
+    );
+    assert_eq!(
+        execute(&schema, "{ viewer { score } }").await,
+        value!({ "viewer": { "score": 1 } })
+    );
+
+    let request = || Request::new("{ viewer { score } }");
+    assert_eq!(
+        execute(&schema, request().data(CurrentUser("a"))).await,
+        value!({ "viewer": { "score": 102 } })
+    );
+    assert_eq!(
+        execute(&schema, request().data(CurrentUser("b"))).await,
+        value!({ "viewer": { "score": 203 } })
+    );
+    assert_eq!(
+        execute(&schema, request().data(CurrentUser("a"))).await,
+        value!({ "viewer": { "score": 102 } })
+    );
+}
+
+#[tokio::test]
+pub async fn test_response_cache_errors() {
+    let schema = schema();
+
+    let resp = schema.execute("{ flaky { item { value } } }").await;
+    assert_eq!(resp.data, value!({ "flaky": { "item": null } }));
+    assert_eq!(resp.errors.len(), 1);
+
+    // the value with an error has not been cached
+    for _ in 0..2 {
+        assert_eq!(
+            execute(&schema, "{ flaky { item { value } } }").await,
+            value!({ "flaky": { "item": { "value": 1 } } })
+        );
+    }
+}
+
+#[tokio::test]
+pub async fn test_response_cache_operation() {
+    let schema = schema();
+
+    assert_eq!(
+        execute(&schema, "{ value(n: 1) }").await,
+        value!({ "value": 100 })
+    );
+    assert_eq!(
+        execute(&schema, "query Q { ... on Query { value(n: 1) } }").await,
+        value!({ "value": 100 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_response_cache_lowered_hint() {
+    let schema = schema();
+
+    // neither the operation nor the field are cached
+    for (lowered, uncached) in [(0, 1), (2, 3)] {
+        let resp = schema.execute("{ lowered uncached }").await;
+        assert_eq!(
+            resp.data,
+            value!({ "lowered": lowered, "uncached": uncached })
+        );
+        assert_eq!(resp.cache_control.value(), Some("max-age=10".to_string()));
+    }
+}
+
+#[tokio::test]
+pub async fn test_response_cache_private() {
+    let schema = schema();
+
+    assert_eq!(execute(&schema, "{ me }").await, value!({ "me": "0" }));
+
+    let request = || Request::new("{ me }");
+    assert_eq!(
+        execute(&schema, request().data(CurrentUser("a"))).await,
+        value!({ "me": "a1" })
+    );
+    assert_eq!(
+        execute(&schema, request().data(CurrentUser("b"))).await,
+        value!({ "me": "b2" })
+    );
+    assert_eq!(
+        execute(&schema, request().data(CurrentUser("a"))).await,
+        value!({ "me": "a1" })
+    );
+}
+
+#[tokio::test]
+pub async fn test_response_cache_guard() {
+    let schema = schema();
+
+    for query in ["{ secret }", "{ users { secret } }"] {
+        let request = || Request::new(query);
+        assert!(
+            schema
+                .execute(request().data(CurrentUser("admin")))
+                .await
+                .is_ok()
+        );
+
+        // the guard runs again instead of serving the cached value
+        let resp = schema.execute(request().data(CurrentUser("b"))).await;
+        assert!(!resp.errors.is_empty());
+        assert!(resp.errors.iter().all(|err| err.message == "forbidden"));
+    }
+}
@@ -38,7 +38,11 @@ pub async fn test_fieldresult() {
             ServerError {
                 message: "TestError".to_string(),
                 source: None,
-                locations: vec![Pos { line: 1, column: 3 }],
+                locations: vec![Pos {
+                    line: 1,
+                    column: 3,
+                    ..Default::default()
+                }],
                 path: vec![PathSegment::Field("error1".to_owned())],
                 extensions: None,
             },
@@ -48,6 +52,7 @@ pub async fn test_fieldresult() {
                 locations: vec![Pos {
                     line: 1,
                     column: 19,
+                    ..Default::default()
                 }],
                 path: vec![PathSegment::Field("error2".to_owned())],
                 extensions: None,
@@ -62,7 +67,11 @@ pub async fn test_fieldresult() {
         vec![ServerError {
             message: "TestError".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("optError".to_owned())],
             extensions: None,
         }]
@@ -82,7 +91,11 @@ pub async fn test_fieldresult() {
         vec![ServerError {
             message: "TestError".to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 3 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 3,
+                ..Default::default()
+            }],
             path: vec![
                 PathSegment::Field("vecError".to_owned()),
                 PathSegment::Index(1)
@@ -118,7 +131,7 @@ pub async fn test_custom_error() {
 
     #[derive(Interface)]
     #[graphql(field(name = "value2", ty = "i32"))]
-    #[expect(dead_code)]
+    #[allow(dead_code)]
     enum MyInterface {
         MyObj(MyObj),
     }
@@ -133,7 +146,7 @@ pub async fn test_custom_error() {
         }
     }
 
-    #[expect(dead_code)]
+    #[allow(dead_code)]
     struct Subscription;
 
     #[Subscription]
@@ -204,6 +217,7 @@ pub async fn test_error_propagation() {
             locations: vec![Pos {
                 line: 1,
                 column: 20,
+                ..Default::default()
             }],
             path: vec![
                 PathSegment::Field("parent".to_owned()),
@@ -224,6 +238,7 @@ pub async fn test_error_propagation() {
             locations: vec![Pos {
                 line: 1,
                 column: 23,
+                ..Default::default()
             }],
             path: vec![
                 PathSegment::Field("parent".to_owned()),
@@ -244,6 +259,7 @@ pub async fn test_error_propagation() {
             locations: vec![Pos {
                 line: 1,
                 column: 23,
+                ..Default::default()
             }],
             path: vec![
                 PathSegment::Field("parentOpt".to_owned()),
@@ -267,6 +283,7 @@ pub async fn test_error_propagation() {
             locations: vec![Pos {
                 line: 1,
                 column: 23,
+                ..Default::default()
             }],
             path: vec![
                 PathSegment::Field("parentOpt".to_owned()),
@@ -317,6 +334,7 @@ pub async fn test_error_propagation() {
                 locations: vec![Pos {
                     line: 1,
                     column: 20,
+                    ..Default::default()
                 }],
                 path: vec![
                     PathSegment::Field("parent".to_owned()),
@@ -331,6 +349,7 @@ pub async fn test_error_propagation() {
                 locations: vec![Pos {
                     line: 1,
                     column: 43,
+                    ..Default::default()
                 }],
                 path: vec![
                     PathSegment::Field("parent".to_owned()),
@@ -49,3 +49,169 @@ pub async fn test_http_headers() {
         Some(&HeaderValue::from_static("1"))
     );
 }
+
+#[tokio::test]
+pub async fn test_parse_options() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .parse_options(
+            ParseOptions::new()
+                .max_tokens(20)
+                .max_aliases(2)
+                .max_fragments(1),
+        )
+        .finish();
+
+    assert_eq!(
+        schema.execute("{ a: value b: value }").await.into_result(),
+        Ok(Response::new(value!({ "a": 10, "b": 10 })))
+    );
+    assert_eq!(
+        schema
+            .execute("{ a: value b: value c: value }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "selection set exceeds the maximum of 2 aliases".to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 1,
+                ..Default::default()
+            }],
+            path: Default::default(),
+            extensions: None,
+        }]
+    );
+    assert_eq!(
+        schema
+            .execute(format!("{{ {}}}", "value ".repeat(20)))
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "document exceeds the maximum of 20 tokens".to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 117,
+                ..Default::default()
+            }],
+            path: Default::default(),
+            extensions: None,
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_limit_aliases() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        async fn obj(&self) -> Query {
+            Query
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_aliases(4)
+        .limit_aliases_per_selection_set(2)
+        .finish();
+
+    assert!(
+        schema
+            .execute("{ a: value b: value obj { a: value b: value } }")
+            .await
+            .is_ok()
+    );
+    assert_eq!(
+        schema
+            .execute("{ a: value obj { ... A } } fragment A on Query { b: value d: value ... { c: value } }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "The number of aliases in a selection set cannot be greater than `2`"
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12,
+                ..Default::default()
+            }],
+            path: Default::default(),
+            extensions: None,
+        }]
+    );
+    assert_eq!(
+        schema
+            .execute(
+                "{ a: obj { ...A } b: obj { ...A } } fragment A on Query { c: value d: value }"
+            )
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "The number of aliases of the query cannot be greater than `4`".to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 1,
+                ..Default::default()
+            }],
+            path: Default::default(),
+            extensions: None,
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_limit_root_fields() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_root_fields(2)
+        .finish();
+
+    assert!(schema.execute("{ value a: value }").await.is_ok());
+    assert_eq!(
+        schema
+            .execute("{ value ... { a: value b: value } }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "The number of root fields of the query cannot be greater than `2`"
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 1,
+                ..Default::default()
+            }],
+            path: Default::default(),
+            extensions: None,
+        }]
+    );
+}
@@ -352,7 +352,8 @@ pub async fn test_subscription_error() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 25
+                column: 25,
+                ..Default::default()
             }],
             path: vec![
                 PathSegment::Field("events".to_owned()),
@@ -406,7 +407,8 @@ pub async fn test_subscription_fieldresult() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 16
+                column: 16,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("values".to_owned())],
             extensions: None,
@@ -327,7 +327,7 @@ pub async fn test_subscription_ws_transport_error() {
                     "path": ["events", "value"],
                 }],
                 "data": {
-                    "events":  { "value": null }
+                    "events": null
                 },
 
             },
@@ -260,7 +260,7 @@ pub async fn test_subscription_ws_transport_error() {
                     "locations": [{"line": 1, "column": 25}],
                     "path": ["events", "value"],
                 }],
-                "data": { "events": { "value": null } },
+                "data": { "events": null },
             },
         })),
         serde_json::from_str(&stream.next().await.unwrap().unwrap_text()).unwrap()
@@ -0,0 +1,240 @@
+#![cfg(feature = "validation_cache")]
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use async_graphql::*;
+
+#[derive(Clone, Default)]
+struct MemoryStorage(Arc<Mutex<HashMap<ValidationCacheKey, ValidatedDocument>>>);
+
+#[async_trait::async_trait]
+impl ValidationCacheStorage for MemoryStorage {
+    async fn get(&self, key: &ValidationCacheKey) -> Option<ValidatedDocument> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+
+    async fn set(&self, key: ValidationCacheKey, document: ValidatedDocument) {
+        self.0.lock().unwrap().insert(key, document);
+    }
+}
+
+/// Counts the validations of the documents.
+#[derive(Clone, Default)]
+struct CountValidations(Arc<AtomicUsize>);
+
+impl<'a> Visitor<'a> for CountValidations {
+    fn enter_document(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _doc: &'a parser::types::ExecutableDocument,
+    ) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+
+    #[graphql(complexity = "count * child_complexity")]
+    async fn values(&self, count: usize) -> Vec<Query> {
+        (0..count).map(|_| Query).collect()
+    }
+}
+
+#[tokio::test]
+pub async fn test_validation_cache() {
+    let storage = MemoryStorage::default();
+    let counter = CountValidations::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_cache(storage.clone())
+        .validation_rule({
+            let counter = counter.clone();
+            move || counter.clone()
+        })
+        .finish();
+
+    for _ in 0..3 {
+        assert_eq!(
+            schema
+                .execute("{ value }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": 10 })
+        );
+    }
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+
+    // the operation name and the introspection mode are part of the key
+    let query = "query A { value } query B { a: value }";
+    for _ in 0..2 {
+        assert!(
+            schema
+                .execute(Request::new(query).operation_name("A"))
+                .await
+                .is_ok()
+        );
+        assert!(
+            schema
+                .execute(Request::new(query).operation_name("B"))
+                .await
+                .is_ok()
+        );
+        assert!(
+            schema
+                .execute(Request::new("{ value }").disable_introspection())
+                .await
+                .is_ok()
+        );
+    }
+    assert_eq!(counter.0.load(Ordering::SeqCst), 4);
+    assert_eq!(storage.0.lock().unwrap().len(), 4);
+
+    // failed validations are not cached
+    for _ in 0..2 {
+        assert!(schema.execute("{ unknown }").await.is_err());
+    }
+    assert_eq!(counter.0.load(Ordering::SeqCst), 6);
+}
+
+#[tokio::test]
+pub async fn test_validation_cache_with_variables() {
+    let counter = CountValidations::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_cache(MemoryStorage::default())
+        .validation_rule({
+            let counter = counter.clone();
+            move || counter.clone()
+        })
+        .limit_complexity(10)
+        .finish();
+
+    // the complexity is computed from the variables, so it is not cached
+    let query = "query($count: Int!) { values(count: $count) { value } }";
+    assert!(
+        schema
+            .execute(Request::new(query).variables(Variables::from_value(value!({ "count": 2 }))))
+            .await
+            .is_ok()
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new(query).variables(Variables::from_value(value!({ "count": 20 }))))
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "Query is too complex."
+    );
+    assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+
+    // the complexity of a literal argument is cached
+    for _ in 0..2 {
+        assert!(
+            schema
+                .execute("{ values(count: 2) { value } }")
+                .await
+                .is_ok()
+        );
+    }
+    assert_eq!(counter.0.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+pub async fn test_validation_cache_checks_variables() {
+    #[derive(Default)]
+    struct Counter(AtomicUsize);
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn increment(&self, ctx: &Context<'_>) -> usize {
+            ctx.data_unchecked::<Counter>()
+                .0
+                .fetch_add(1, Ordering::SeqCst)
+                + 1
+        }
+
+        async fn double(&self, value: i32) -> i32 {
+            value * 2
+        }
+    }
+
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .validation_cache(MemoryStorage::default())
+        .data(Counter::default())
+        .finish();
+
+    let query = "mutation($value: Int!) { increment double(value: $value) }";
+    // the second request hits the cache, its variable is still checked before
+    // any field is resolved
+    for (value, is_ok) in [(value!(1), true), (value!("a"), false)] {
+        let resp = schema
+            .execute(
+                Request::new(query).variables(Variables::from_value(value!({ "value": value }))),
+            )
+            .await;
+        assert_eq!(resp.is_ok(), is_ok);
+    }
+    assert_eq!(
+        schema.data::<Counter>().unwrap().0.load(Ordering::SeqCst),
+        1
+    );
+}
+
+#[tokio::test]
+pub async fn test_validation_cache_shared_by_schemas() {
+    struct OtherQuery;
+
+    #[Object]
+    impl OtherQuery {
+        async fn other(&self) -> i32 {
+            20
+        }
+    }
+
+    let storage = MemoryStorage::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_cache(storage.clone())
+        .finish();
+    let other_schema = Schema::build(OtherQuery, EmptyMutation, EmptySubscription)
+        .validation_cache(storage.clone())
+        .finish();
+
+    assert!(schema.execute("{ value }").await.is_ok());
+    assert!(other_schema.execute("{ value }").await.is_err());
+    assert!(other_schema.execute("{ other }").await.is_ok());
+    assert!(schema.execute("{ other }").await.is_err());
+    assert_eq!(storage.0.lock().unwrap().len(), 2);
+}
+
+#[cfg(feature = "validation_cache")]
+#[tokio::test]
+pub async fn test_lru_validation_cache() {
+    let counter = CountValidations::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_cache(LruValidationCache::new(1))
+        .validation_rule({
+            let counter = counter.clone();
+            move || counter.clone()
+        })
+        .finish();
+
+    for query in ["{ value }", "{ value }", "{ a: value }", "{ value }"] {
+        assert!(schema.execute(query).await.is_ok());
+    }
+    assert_eq!(counter.0.load(Ordering::SeqCst), 3);
+}
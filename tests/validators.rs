@@ -9,7 +9,7 @@ use futures_util::{Stream, StreamExt};
 
 #[tokio::test]
 pub async fn test_all_validator() {
-    #[expect(dead_code)]
+    #[allow(dead_code)]
     struct Query;
 
     #[Object]
@@ -76,6 +76,18 @@ pub async fn test_all_validator() {
         async fn list_email(&self, #[graphql(validator(list, email))] n: Vec<String>) -> i32 {
             todo!()
         }
+
+        async fn uuid(&self, #[graphql(validator(uuid))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn uuid_v4(&self, #[graphql(validator(uuid = "v4"))] n: ID) -> i32 {
+            todo!()
+        }
+
+        async fn uuid_v7(&self, #[graphql(validator(uuid = 7))] n: String) -> i32 {
+            todo!()
+        }
     }
 }
 
@@ -113,7 +125,8 @@ pub async fn test_validator_on_object_field_args() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 12
+                column: 12,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -174,7 +187,8 @@ pub async fn test_validator_on_input_object_field() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 16
+                column: 16,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -193,7 +207,8 @@ pub async fn test_validator_on_input_object_field() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 16
+                column: 16,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -239,7 +254,8 @@ pub async fn test_validator_on_complex_object_field_args() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 12
+                column: 12,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -297,7 +313,8 @@ pub async fn test_validator_on_subscription_field_args() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 25
+                column: 25,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -400,7 +417,8 @@ pub async fn test_custom_validator() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 12
+                column: 12,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -429,7 +447,8 @@ pub async fn test_custom_validator() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 16
+                column: 16,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("input".to_string())],
             extensions: None
@@ -461,7 +480,8 @@ pub async fn test_custom_validator() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 25
+                column: 25,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -479,7 +499,8 @@ pub async fn test_custom_validator() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 18
+                column: 18,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value2".to_string())],
             extensions: None
@@ -497,7 +518,8 @@ pub async fn test_custom_validator() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 18
+                column: 18,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value3".to_string())],
             extensions: None
@@ -558,7 +580,8 @@ pub async fn test_custom_validator_with_fn() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 12
+                column: 12,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -627,7 +650,8 @@ pub async fn test_custom_validator_with_extensions() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 12
+                column: 12,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: Some(error_extensions)
@@ -669,7 +693,8 @@ pub async fn test_list_validator() {
             source: None,
             locations: vec![Pos {
                 line: 1,
-                column: 12
+                column: 12,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
@@ -756,7 +781,11 @@ pub async fn test_validate_wrapper_types() {
             message: r#"Failed to parse "Int": the value is 20, must be less than or equal to 10"#
                 .to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 7 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 7,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("a".to_string())],
             extensions: None
         }]
@@ -772,7 +801,11 @@ pub async fn test_validate_wrapper_types() {
             message: r#"Failed to parse "Int": the value is 20, must be less than or equal to 10"#
                 .to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 7 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 7,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("b".to_string())],
             extensions: None
         }]
@@ -788,7 +821,11 @@ pub async fn test_validate_wrapper_types() {
             message: r#"Failed to parse "Int": the value is 20, must be less than or equal to 10"#
                 .to_string(),
             source: None,
-            locations: vec![Pos { line: 1, column: 7 }],
+            locations: vec![Pos {
+                line: 1,
+                column: 7,
+                ..Default::default()
+            }],
             path: vec![PathSegment::Field("f".to_string())],
             extensions: None
         }]
@@ -826,7 +863,7 @@ pub async fn test_list_both_max_items_and_max_length() {
         vec![ServerError {
             message: r#"Failed to parse "[String!]": the value length is 3, must be less than or equal to 2"#.to_string(),
             source: None,
-            locations: vec![Pos { column: 17, line: 1}],
+            locations: vec![Pos { column: 17, line: 1, ..Default::default() }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
         }]
@@ -841,7 +878,7 @@ pub async fn test_list_both_max_items_and_max_length() {
         vec![ServerError {
             message: r#"Failed to parse "String": the string length is 4, must be less than or equal to 3"#.to_string(),
             source: None,
-            locations: vec![Pos { column: 17, line: 1}],
+            locations: vec![Pos { column: 17, line: 1, ..Default::default() }],
             path: vec![PathSegment::Field("value".to_string())],
             extensions: None
         }]
@@ -868,7 +905,7 @@ pub async fn test_list_both_max_items_and_max_length() {
         vec![ServerError {
             message: r#"Failed to parse "[String!]": the value length is 3, must be less than or equal to 2"#.to_string(),
             source: None,
-            locations: vec![Pos { column: 18, line: 1}],
+            locations: vec![Pos { column: 18, line: 1, ..Default::default() }],
             path: vec![PathSegment::Field("value2".to_string())],
             extensions: None
         }]
@@ -883,7 +920,7 @@ pub async fn test_list_both_max_items_and_max_length() {
         vec![ServerError {
             message: r#"Failed to parse "String": the string length is 4, must be less than or equal to 3"#.to_string(),
             source: None,
-            locations: vec![Pos { column: 18, line: 1}],
+            locations: vec![Pos { column: 18, line: 1, ..Default::default() }],
             path: vec![PathSegment::Field("value2".to_string())],
             extensions: None
         }]
@@ -898,7 +935,7 @@ pub async fn test_list_both_max_items_and_max_length() {
         vec![ServerError {
             message: r#"Failed to parse "[String!]": the value length is 3, must be less than or equal to 2"#.to_string(),
             source: None,
-            locations: vec![Pos { column: 18, line: 1}],
+            locations: vec![Pos { column: 18, line: 1, ..Default::default() }],
             path: vec![PathSegment::Field("value2".to_string())],
             extensions: None
         }]
@@ -976,7 +1013,7 @@ pub async fn test_issue_1164() {
         vec![ServerError {
             message: r#"Failed to parse "String": the string length is 3, must be greater than or equal to 6"#.to_string(),
             source: None,
-            locations: vec![Pos { column: 12, line: 1}],
+            locations: vec![Pos { column: 12, line: 1, ..Default::default() }],
             path: vec![PathSegment::Field("a".to_string())],
             extensions: None
         }]
@@ -991,7 +1028,7 @@ pub async fn test_issue_1164() {
         vec![ServerError {
             message: r#"Failed to parse "String": the string length is 18, must be less than or equal to 16"#.to_string(),
             source: None,
-            locations: vec![Pos { column: 12, line: 1}],
+            locations: vec![Pos { column: 12, line: 1, ..Default::default() }],
             path: vec![PathSegment::Field("a".to_string())],
             extensions: None
         }]
@@ -1008,10 +1045,257 @@ pub async fn test_issue_1164() {
             source: None,
             locations: vec![Pos {
                 column: 12,
-                line: 1
+                line: 1,
+                ..Default::default()
             }],
             path: vec![PathSegment::Field("a".to_string())],
             extensions: None
         }]
     );
 }
+
+#[tokio::test]
+pub async fn test_uuid_validator() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn any(&self, #[graphql(validator(uuid))] id: ID) -> ID {
+            id
+        }
+
+        async fn v4(&self, #[graphql(validator(uuid = "v4"))] id: String) -> String {
+            id
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    any(id: "017f22e2-79b0-7cc3-98c4-dc0c0c07398f")
+                    v4(id: "67e55044-10b1-426f-9247-bb680e5fe0c8")
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "any": "017f22e2-79b0-7cc3-98c4-dc0c0c07398f",
+            "v4": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ any(id: "not-a-uuid") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "ID": invalid uuid"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 11,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("any".to_string())],
+            extensions: None
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ v4(id: "017f22e2-79b0-7cc3-98c4-dc0c0c07398f") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "String": the uuid version is 7, must be 4"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 10,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("v4".to_string())],
+            extensions: None
+        }]
+    );
+}
+
+#[cfg(feature = "password-strength-validator")]
+#[tokio::test]
+pub async fn test_min_password_strength_validator() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn login(
+            &self,
+            #[graphql(validator(min_password_strength = 3))] password: String,
+        ) -> bool {
+            !password.is_empty()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ login(password: "correct horse battery staple") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "login": true })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ login(password: "password") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "String": the password strength score is 0, must be greater than or equal to 3"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 19,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("login".to_string())],
+            extensions: None
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_custom_async_validator() {
+    struct TakenNames(Vec<&'static str>);
+
+    struct UniqueName;
+
+    #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+    impl AsyncCustomValidator<String> for UniqueName {
+        async fn check(
+            &self,
+            ctx: &Context<'_>,
+            value: &String,
+        ) -> Result<(), InputValueError<String>> {
+            tokio::task::yield_now().await;
+            if ctx
+                .data_unchecked::<TakenNames>()
+                .0
+                .contains(&value.as_str())
+            {
+                Err(InputValueError::custom(format!(
+                    "name \"{}\" is already taken",
+                    value
+                )))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[derive(InputObject)]
+    struct NewUser {
+        #[graphql(validator(custom_async = "UniqueName", max_length = 10))]
+        name: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn rename(
+            &self,
+            #[graphql(validator(custom_async = "UniqueName"))] name: String,
+        ) -> String {
+            name
+        }
+
+        async fn create(&self, user: NewUser) -> String {
+            user.name
+        }
+
+        async fn create_many(&self, users: Vec<NewUser>) -> usize {
+            users.len()
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(TakenNames(vec!["alice", "bob"]))
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(r#"{ rename(name: "carol") create(user: { name: "dave" }) }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "rename": "carol", "create": "dave" })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ rename(name: "alice") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "String": name "alice" is already taken"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 16,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("rename".to_string())],
+            extensions: None
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ create(user: { name: "bob" }) }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "String": name "bob" is already taken (occurred while parsing "NewUser")"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 16,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("create".to_string())],
+            extensions: None
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ createMany(users: [{ name: "carol" }, { name: "alice" }]) }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "String": name "alice" is already taken (occurred while parsing "NewUser") (occurred while parsing "[NewUser!]")"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 21,
+                ..Default::default()
+            }],
+            path: vec![PathSegment::Field("createMany".to_string())],
+            extensions: None
+        }]
+    );
+}
@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+
+use async_graphql::{
+    fold::{self, Fold, FoldContext},
+    parser::{
+        parse_query,
+        types::{Field, Selection},
+    },
+    registry::MetaTypeName,
+    *,
+};
+use async_graphql_value::Value as InputValue;
+
+#[derive(SimpleObject)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self, id: i32) -> User {
+        User {
+            id,
+            name: "user".to_string(),
+        }
+    }
+
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+#[test]
+fn test_visitor_type_information() {
+    #[derive(Default)]
+    struct CollectFields {
+        fields: Vec<String>,
+        arguments: Vec<String>,
+    }
+
+    impl<'a> Visitor<'a> for CollectFields {
+        fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+            let definition = ctx.field_definition(&field.node.name.node).unwrap();
+            self.fields.push(format!(
+                "{}.{}: {}",
+                ctx.parent_type().unwrap().name(),
+                definition.name,
+                ctx.current_type().unwrap().name(),
+            ));
+        }
+
+        fn enter_argument(
+            &mut self,
+            _ctx: &mut VisitorContext<'a>,
+            name: &'a Positioned<Name>,
+            _value: &'a Positioned<InputValue>,
+        ) {
+            self.arguments.push(name.node.to_string());
+        }
+
+        fn enter_input_value(
+            &mut self,
+            ctx: &mut VisitorContext<'a>,
+            _pos: Pos,
+            expected_type: &Option<MetaTypeName<'a>>,
+            _value: &'a InputValue,
+        ) {
+            assert_eq!(ctx.input_type(), *expected_type);
+            assert_eq!(*expected_type, Some(MetaTypeName::NonNull("Int")));
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let doc = parse_query(
+        "{ value user(id: 1) { ... on User { id } ...A } } fragment A on User { name }",
+    )
+    .unwrap();
+    let mut ctx = VisitorContext::new(schema.registry(), &doc, None, None);
+    let mut visitor = CollectFields::default();
+    visit(&mut visitor, &mut ctx, &doc);
+    assert!(ctx.into_errors().is_empty());
+
+    visitor.fields.sort();
+    assert_eq!(
+        visitor.fields,
+        vec![
+            "Query.user: User",
+            "Query.value: Int",
+            "User.id: Int",
+            "User.name: String",
+        ]
+    );
+    assert_eq!(visitor.arguments, vec!["id"]);
+}
+
+#[test]
+fn test_visitor_inline_mode() {
+    #[derive(Default)]
+    struct CountFields(usize);
+
+    impl<'a> Visitor<'a> for CountFields {
+        fn mode(&self) -> VisitMode {
+            VisitMode::Inline
+        }
+
+        fn enter_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {
+            self.0 += 1;
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let doc = parse_query(
+        "{ a: user(id: 1) { ...A } b: user(id: 2) { ...A } } fragment A on User { id name }",
+    )
+    .unwrap();
+    let mut ctx = VisitorContext::new(schema.registry(), &doc, None, None);
+    let mut visitor = CountFields::default();
+    visit(&mut visitor, &mut ctx, &doc);
+    assert_eq!(visitor.0, 6);
+}
+
+#[tokio::test]
+async fn test_validation_rule() {
+    struct NoUserName;
+
+    impl<'a> Visitor<'a> for NoUserName {
+        fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+            if ctx.parent_type().map(|ty| ty.name()) == Some("User")
+                && field.node.name.node == "name"
+            {
+                ctx.report_error(vec![field.pos], "Field \"name\" is forbidden.");
+            }
+        }
+    }
+
+    for mode in [ValidationMode::Strict, ValidationMode::Fast] {
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .validation_mode(mode)
+            .validation_rule(|| NoUserName)
+            .finish();
+
+        assert_eq!(
+            schema
+                .execute("{ user(id: 1) { id } }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "user": { "id": 1 } })
+        );
+        assert_eq!(
+            schema
+                .execute("{ user(id: 1) { id name } }")
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: "Field \"name\" is forbidden.".to_string(),
+                source: None,
+                locations: vec![Pos {
+                    line: 1,
+                    column: 20,
+                    ..Default::default()
+                }],
+                path: Vec::new(),
+                extensions: None,
+            }]
+        );
+    }
+}
+
+#[test]
+fn test_fold() {
+    /// Renames the `name` fields of users to `id`, removes the `value` fields
+    /// and replaces the `id` arguments with `2`.
+    struct Rewrite;
+
+    impl<'a> Fold<'a> for Rewrite {
+        fn fold_selection(
+            &mut self,
+            ctx: &mut FoldContext<'a>,
+            selection: Positioned<Selection>,
+        ) -> Option<Positioned<Selection>> {
+            if let Selection::Field(field) = &selection.node
+                && ctx.current_type().map(|ty| ty.name()) == Some("Query")
+                && field.node.name.node == "value"
+            {
+                return None;
+            }
+            fold::fold_selection(self, ctx, selection)
+        }
+
+        fn fold_field(
+            &mut self,
+            ctx: &mut FoldContext<'a>,
+            mut field: Positioned<Field>,
+        ) -> Positioned<Field> {
+            if ctx.parent_type().map(|ty| ty.name()) == Some("User")
+                && field.node.name.node == "name"
+            {
+                field.node.name.node = Name::new("id");
+            }
+            fold::fold_field(self, ctx, field)
+        }
+
+        fn fold_argument(
+            &mut self,
+            ctx: &mut FoldContext<'a>,
+            name: Positioned<Name>,
+            value: Positioned<InputValue>,
+        ) -> Option<(Positioned<Name>, Positioned<InputValue>)> {
+            assert_eq!(ctx.input_type(), Some(MetaTypeName::NonNull("Int")));
+            Some((name, value.map(|_| InputValue::Number(2.into()))))
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let doc = parse_query("query { value user(id: 1) { name ...A } } fragment A on User { name }")
+        .unwrap();
+    let mut ctx = FoldContext::new(schema.registry());
+    let doc = Rewrite.fold_document(&mut ctx, doc);
+    assert_eq!(
+        parser::print_query(&doc, Default::default()),
+        "{\n  user(id: 2) {\n    id\n    ...A\n  }\n}\n\nfragment A on User {\n  id\n}\n"
+    );
+}
+
+#[tokio::test]
+async fn test_validation_rule_introspection_allow_list() {
+    struct AllowListedIntrospection;
+
+    impl<'a> Visitor<'a> for AllowListedIntrospection {
+        fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+            if matches!(field.node.name.node.as_str(), "__schema" | "__type")
+                && ctx.operation_name() != Some("IntrospectionQuery")
+            {
+                ctx.report_error(vec![field.pos], "Introspection is disabled.");
+            }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_rule(|| AllowListedIntrospection)
+        .finish();
+
+    assert!(
+        schema
+            .execute(
+                Request::new("query IntrospectionQuery { __schema { queryType { name } } }")
+                    .operation_name("IntrospectionQuery")
+            )
+            .await
+            .is_ok()
+    );
+    assert_eq!(
+        schema
+            .execute("{ __type(name: \"User\") { name } }")
+            .await
+            .errors
+            .into_iter()
+            .map(|err| err.message)
+            .collect::<Vec<_>>(),
+        vec!["Introspection is disabled."]
+    );
+}
+
+#[tokio::test]
+async fn test_validation_rule_same_field_aliases() {
+    /// Forbids selecting a field more than twice in a document.
+    #[derive(Default)]
+    struct MaxSameFieldAliases(HashMap<String, usize>);
+
+    impl<'a> Visitor<'a> for MaxSameFieldAliases {
+        fn mode(&self) -> VisitMode {
+            VisitMode::Inline
+        }
+
+        fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+            let Some(parent_type) = ctx.parent_type() else {
+                return;
+            };
+            let name = format!("{}.{}", parent_type.name(), field.node.name.node);
+            let count = self.0.entry(name).or_default();
+            *count += 1;
+            if *count == 3 {
+                ctx.report_error(
+                    vec![field.pos],
+                    format!(
+                        "Field \"{}\" is selected too many times.",
+                        field.node.name.node
+                    ),
+                );
+            }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_rule(MaxSameFieldAliases::default)
+        .finish();
+
+    assert!(schema.execute("{ a: value b: value }").await.is_ok());
+    assert_eq!(
+        schema
+            .execute("{ a: value b: value ... { c: value } }")
+            .await
+            .errors
+            .into_iter()
+            .map(|err| err.message)
+            .collect::<Vec<_>>(),
+        vec!["Field \"value\" is selected too many times."]
+    );
+}
+
+#[tokio::test]
+async fn test_validation_rule_batched_mutation() {
+    struct NoBatchedMutations;
+
+    impl<'a> Visitor<'a> for NoBatchedMutations {
+        fn enter_operation_definition(
+            &mut self,
+            ctx: &mut VisitorContext<'a>,
+            _name: Option<&'a Name>,
+            operation_definition: &'a Positioned<parser::types::OperationDefinition>,
+        ) {
+            if ctx.is_batch_request()
+                && operation_definition.node.ty == parser::types::OperationType::Mutation
+            {
+                ctx.report_error(
+                    vec![operation_definition.pos],
+                    "Mutations are not allowed in batches.",
+                );
+            }
+        }
+    }
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn action(&self) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .validation_rule(|| NoBatchedMutations)
+        .finish();
+
+    assert!(schema.execute("mutation { action }").await.is_ok());
+    let resp = schema
+        .execute_batch(BatchRequest::Batch(vec![
+            Request::new("{ value }"),
+            Request::new("mutation { action }"),
+        ]))
+        .await;
+    let BatchResponse::Batch(resp) = resp else {
+        unreachable!()
+    };
+    assert!(resp[0].is_ok());
+    assert_eq!(
+        resp[1].errors[0].message,
+        "Mutations are not allowed in batches."
+    );
+}